@@ -3,8 +3,6 @@ use criterion::{Criterion, black_box, criterion_group, criterion_main};
 // Simple template performance benchmark comparing Askama vs Minijinja
 // This focuses on core rendering performance with realistic data
 
-use serde_json;
-
 // Test data structure
 #[derive(serde::Serialize, Clone)]
 struct TestIssue {
@@ -11,8 +11,6 @@ struct Issue {
 /// Generate test issues with hierarchical IDs (e.g., nacre-1, nacre-1.1, nacre-1.1.1)
 fn generate_issues(count: usize) -> Vec<Issue> {
     let mut issues = Vec::with_capacity(count);
-    let mut id_counter = 0;
-
     // Create ~20% as root issues, rest as children
     let root_count = count / 5;
 
@@ -22,7 +20,7 @@ fn generate_issues(count: usize) -> Vec<Issue> {
             status: (i % 4) as u8,
         });
     }
-    id_counter = root_count;
+    let mut id_counter = root_count;
 
     // Add children with dot notation
     let mut depth_1_count = 0;
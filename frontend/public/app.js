@@ -1,4 +1,4 @@
-var fJ=["nacre-light","catppuccin-latte"];(function(){let G=localStorage.getItem("theme"),J=window.matchMedia("(prefers-color-scheme: dark)").matches,q=G||(J?"nacre-dark":"nacre-light");document.documentElement.setAttribute("data-theme",q);let H=fJ.includes(q)?"light":"dark";document.documentElement.setAttribute("data-syntax",H)})();function wJ(){let G=document.getElementById("theme-select");if(G){let J=document.documentElement.getAttribute("data-theme")||"nacre-dark";G.value=J,G.addEventListener("change",()=>{let q=G.value;document.documentElement.setAttribute("data-theme",q),localStorage.setItem("theme",q);let H=fJ.includes(q)?"light":"dark";document.documentElement.setAttribute("data-syntax",H)})}}function TJ(){let G=document.getElementById("filter-input");if(G)G.addEventListener("input",(J)=>{let q=J.target.value.toLowerCase();document.querySelectorAll("[data-filter-text]").forEach((Z)=>{let Q=Z.getAttribute("data-filter-text"),K=Q&&Q.includes(q);if(Z instanceof HTMLElement)if(K)Z.style.display="";else Z.style.display="none"})})}function yJ(){document.addEventListener("click",(G)=>{let q=G.target.closest(".toggle-children");if(!q)return;let H=q.closest(".epic-item");if(!H)return;let Z=H.querySelector(".epic-children");if(!Z)return;let Q=Z.classList.contains("collapsed");if(Z.classList.toggle("collapsed"),q.classList.toggle("expanded"),Q)Z.style.maxHeight=Z.scrollHeight+"px",Z.style.opacity="1";else Z.style.maxHeight="0",Z.style.opacity="0"})}class hJ{container=null;activeToasts=new Set;initialized=!1;constructor(){if(document.readyState==="loading")document.addEventListener("DOMContentLoaded",()=>this.init());else this.init()}init(){if(this.initialized)return;this.container=document.createElement("div"),this.container.id="toast-container",this.container.className="toast-container",document.body.appendChild(this.container),this.initialized=!0}createToast(G){let J=document.createElement("div");J.className=`toast toast-${G.type||"info"}`;let q=document.createElement("div");q.className="toast-message",q.textContent=G.message;let H=document.createElement("div");if(H.className="toast-actions",G.retryAction){let Q=document.createElement("button");Q.className="toast-retry",Q.textContent="Retry",Q.addEventListener("click",async()=>{Q.disabled=!0,Q.textContent="Retrying...";try{await G.retryAction(),this.remove(J),this.show({message:"Success!",type:"success",duration:2000})}catch{Q.disabled=!1,Q.textContent="Retry"}}),H.appendChild(Q)}let Z=document.createElement("button");return Z.className="toast-close",Z.textContent="×",Z.addEventListener("click",()=>this.remove(J)),H.appendChild(Z),J.appendChild(q),J.appendChild(H),J}show(G){if(!this.initialized)return;if(!this.container)return;let J=this.createToast(G);if(this.container.appendChild(J),this.activeToasts.add(J),setTimeout(()=>{J.classList.add("toast-show")},10),G.duration&&G.duration>0)setTimeout(()=>{this.remove(J)},G.duration)}remove(G){G.classList.remove("toast-show"),setTimeout(()=>{if(G.parentNode)G.parentNode.removeChild(G);this.activeToasts.delete(G)},300)}clear(){this.activeToasts.forEach((G)=>this.remove(G))}}var _J=new hJ;function C1(G,J,q){let H=G instanceof Error?G.message:"Unknown error occurred";console.error(`[${J}] ${H}`,G),_J.show({message:`${J}: ${H}`,type:"error",duration:q?0:5000,retryAction:q})}function O1(G,J,q){let H=`HTTP ${G.status}: ${G.statusText}`;console.error(`[${J}] ${H}`,G),_J.show({message:`${J}: ${H}`,type:"error",duration:q?0:5000,retryAction:q})}function vJ(){document.addEventListener("click",(J)=>{let q=J.target;if(q.classList.contains("issue-title")&&q.closest(".issue-item"))G(q)});function G(J){let q=J.innerText,H=document.createElement("input");H.type="text",H.value=q,H.classList.add("edit-input"),H.addEventListener("click",($)=>$.stopPropagation()),J.replaceWith(H),H.focus();let Z=!1,Q=async()=>{if(Z)return;Z=!0;let $=H.value.trim(),L=H.closest(".issue-item"),X=L?L.getAttribute("data-id"):null;if($&&$!==q&&X){let W=async()=>{if(!(await fetch(`/api/issues/${X}`,{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({title:$})})).ok)throw Error("Update failed")};try{await W();let Y=document.createElement("div");Y.classList.add("issue-title"),Y.innerText=$,H.replaceWith(Y)}catch(Y){if(Y instanceof Error&&Y.message==="Update failed")O1(new Response(null,{status:500,statusText:"Update failed"}),"Failed to update title",W);else C1(Y,"Failed to update title",W)}}else K()},K=()=>{let $=document.createElement("div");$.classList.add("issue-title"),$.innerText=q,H.replaceWith($)};H.addEventListener("blur",Q),H.addEventListener("keydown",($)=>{if($.key==="Enter")H.blur();else if($.key==="Escape")K(),Z=!0})}}var E0={OPEN:"open",IN_PROGRESS:"in_progress",BLOCKED:"blocked",CLOSED:"closed",DEFERRED:"deferred"},_1={[E0.OPEN]:0,[E0.IN_PROGRESS]:1,[E0.BLOCKED]:2,[E0.CLOSED]:3,[E0.DEFERRED]:4},S0={EPIC:"epic",FEATURE:"feature",BUG:"bug",TASK:"task",CHORE:"chore"},v1={[S0.EPIC]:0,[S0.FEATURE]:1,[S0.BUG]:2,[S0.TASK]:3,[S0.CHORE]:4};var J9=Object.values(E0);function gJ(G){return J9.includes(G)}function mJ(){let G=document.getElementById("columns-toggle"),J=document.getElementById("columns-dropdown");if(G&&J){let W=function(z,M){let U=document.querySelector(`.board-column[data-status="${z}"]`);if(U)U.style.display=M?"":"none"},Y=function(){let z={};X().forEach((M)=>{let U=M.getAttribute("data-status");if(U&&gJ(U))z[U]=M.checked}),localStorage.setItem("board-column-visibility",JSON.stringify(z))},$=localStorage.getItem("board-column-visibility"),L=$?JSON.parse($):null,X=()=>J.querySelectorAll('input[type="checkbox"]');X().forEach((z)=>{let M=z.getAttribute("data-status");if(!M||!gJ(M))return;if(L===null)z.checked=M!==E0.DEFERRED;else z.checked=L[M]!==!1;W(M,z.checked)}),J.addEventListener("change",(z)=>{let M=z.target;if(M.type!=="checkbox")return;let U=M.getAttribute("data-status");if(!U)return;W(U,M.checked),Y()}),G.addEventListener("click",(z)=>{z.stopPropagation(),J.classList.toggle("show")}),document.addEventListener("click",(z)=>{if(!J.contains(z.target)&&z.target!==G)J.classList.remove("show")}),J.addEventListener("click",(z)=>{z.stopPropagation()})}let q=()=>{let $=document.querySelectorAll(".type-filter");if($.length===0)return;let L=new Set(Array.from($).filter((W)=>W.checked).map((W)=>W.value));document.querySelectorAll(".issue-card").forEach((W)=>{let Y=!1;for(let z of L)if(W.classList.contains(`issue-type-${z}`)){Y=!0;break}W.classList.toggle("hidden-by-type",!Y)})},H=()=>{let $=document.querySelectorAll(".priority-filter");if($.length===0)return;let L=new Set(Array.from($).filter((W)=>W.checked).map((W)=>W.value));document.querySelectorAll(".issue-card").forEach((W)=>{let Y=W.getAttribute("data-priority")||"0";W.classList.toggle("hidden-by-priority",!L.has(Y))})},Z=()=>{let $=document.querySelectorAll(".assignee-filter");if($.length===0)return;let L=new Set(Array.from($).filter((W)=>W.checked).map((W)=>W.value));document.querySelectorAll(".issue-card").forEach((W)=>{let Y=W.getAttribute("data-assignee")||"";W.classList.toggle("hidden-by-assignee",!L.has(Y))})},Q=($)=>{document.querySelectorAll(".column-content").forEach((X)=>{let W=Array.from(X.querySelectorAll(".issue-card"));W.sort((Y,z)=>{if($==="priority"){let M=parseInt(Y.getAttribute("data-priority")||"0"),U=parseInt(z.getAttribute("data-priority")||"0");return M-U}else if($==="created"){let M=parseInt(Y.getAttribute("data-created")||"0");return parseInt(z.getAttribute("data-created")||"0")-M}else{let M=Y.querySelector(".issue-title")?.textContent?.trim()||"",U=z.querySelector(".issue-title")?.textContent?.trim()||"";return M.localeCompare(U)}}),W.forEach((Y)=>X.appendChild(Y))})};document.addEventListener("change",($)=>{let L=$.target;if(L.classList.contains("type-filter"))q();else if(L.classList.contains("priority-filter"))H();else if(L.classList.contains("assignee-filter"))Z()});let K=document.getElementById("board-sort");if(K)K.addEventListener("change",()=>{Q(K.value)});q(),H(),Z(),Q("priority")}function uJ(){let G=document.querySelectorAll('.issue-card[draggable="true"]'),J=document.querySelectorAll(".column-content");if(G.length>0&&J.length>0)G.forEach((H)=>{H.addEventListener("dragstart",()=>{H.classList.add("dragging"),H.style.opacity="0.5"}),H.addEventListener("dragend",()=>{H.classList.remove("dragging"),H.style.opacity="1"})}),J.forEach((H)=>{H.addEventListener("dragover",(Z)=>{Z.preventDefault();let Q=document.querySelector(".dragging");if(Q){let K=q(H,Z.clientY);if(K==null)H.appendChild(Q);else H.insertBefore(Q,K)}}),H.addEventListener("drop",async()=>{let Z=document.querySelector(".dragging");if(!Z)return;let Q=H.getAttribute("data-status"),K=Z.getAttribute("data-id");if(K&&Q){let $=async()=>{if(!(await fetch(`/api/issues/${K}`,{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({status:Q})})).ok)throw Error("Update failed")};try{await $()}catch(L){if(L instanceof Error&&L.message==="Update failed")O1(new Response(null,{status:500,statusText:"Update failed"}),"Failed to update status",$);else C1(L,"Failed to update status",$)}}})});function q(H,Z){return[...H.querySelectorAll(".issue-card:not(.dragging)")].reduce(($,L)=>{let X=L.getBoundingClientRect(),W=Z-X.top-X.height/2;if(W<0&&W>$.offset)return{offset:W,element:L};else return $},{offset:Number.NEGATIVE_INFINITY,element:null}).element}}function dJ(){let G=-1,J=0,q=0,H=document.querySelector(".board")!==null,Z=document.querySelector(".issue-list")!==null;if(H)X();document.addEventListener("keydown",(W)=>{let Y=W.target;if(Y.tagName==="INPUT"||Y.tagName==="TEXTAREA")return;if(W.key==="Backspace"){W.preventDefault(),window.history.back();return}if(Z)Q(W);else if(H)K(W)});function Q(W){let Y=Array.from(document.querySelectorAll('.issue-item:not([style*="display: none"])'));if(Y.length===0)return;let z=document.querySelector(".issue-item.selected");if(z)G=Y.indexOf(z);if(W.key==="j"||W.key==="ArrowDown"){G=Math.min(G+1,Y.length-1);let M=Y.at(G);if(M)L(M);W.preventDefault()}else if(W.key==="k"||W.key==="ArrowUp"){G=Math.max(G-1,0);let M=Y.at(G);if(M)L(M);W.preventDefault()}else if(W.key==="Enter"||W.key==="o"){if(z){let M=z.querySelector(".issue-meta a");if(M)M.click()}}}function K(W){let Y=Array.from(document.querySelectorAll('.board-column:not([style*="display: none"])'));if(Y.length===0)return;if(W.key==="j"||W.key==="ArrowDown"){let z=Y.at(J);if(!z)return;let M=$(z);if(M.length>0)q=Math.min(q+1,M.length-1),X(),W.preventDefault()}else if(W.key==="k"||W.key==="ArrowUp")q=Math.max(q-1,0),X(),W.preventDefault();else if(W.key==="h"||W.key==="ArrowLeft"){J=Math.max(J-1,0);let z=Y.at(J);if(!z)return;let M=$(z);q=Math.min(q,Math.max(0,M.length-1)),X(),W.preventDefault()}else if(W.key==="l"||W.key==="ArrowRight"){J=Math.min(J+1,Y.length-1);let z=Y.at(J);if(!z)return;let M=$(z);q=Math.min(q,Math.max(0,M.length-1)),X(),W.preventDefault()}else if(W.key==="Enter"||W.key==="o"){let z=document.querySelector(".issue-card.selected");if(z){let M=z.querySelector("a");if(M)M.click()}}}function $(W){if(!W)return[];return Array.from(W.querySelectorAll('.issue-card:not([style*="display: none"])'))}function L(W){if(document.querySelectorAll(".issue-item.selected").forEach((Y)=>Y.classList.remove("selected")),W)W.classList.add("selected"),W.scrollIntoView({behavior:"smooth",block:"nearest"})}function X(){let W=Array.from(document.querySelectorAll('.board-column:not([style*="display: none"])'));if(W.length===0)return;J=Math.max(0,Math.min(J,W.length-1));let Y=W.at(J);if(!Y)return;let z=$(Y);if(document.querySelectorAll(".issue-card.selected").forEach((M)=>M.classList.remove("selected")),z.length>0){q=Math.max(0,Math.min(q,z.length-1));let M=z.at(q);if(M)M.classList.add("selected"),M.scrollIntoView({behavior:"smooth",block:"nearest"})}}}function F0(G){if(G===void 0)throw ReferenceError("this hasn't been initialised - super() hasn't been called");return G}function aJ(G,J){G.prototype=Object.create(J.prototype),G.prototype.constructor=G,G.__proto__=J}/*!
+function Lp0(){return document.querySelector('meta[name="nacre-base"]')?.content??""}var fJ=["nacre-light","catppuccin-latte"];(function(){let G=localStorage.getItem("theme"),Y=document.querySelector('meta[name="nacre-theme"]')?.content,J=window.matchMedia("(prefers-color-scheme: dark)").matches,q=G||Y||(J?"nacre-dark":"nacre-light");document.documentElement.setAttribute("data-theme",q);let H=fJ.includes(q)?"light":"dark";document.documentElement.setAttribute("data-syntax",H)})();function wJ(){let G=document.getElementById("theme-select");if(G){let J=document.documentElement.getAttribute("data-theme")||"nacre-dark";G.value=J,G.addEventListener("change",()=>{let q=G.value;document.documentElement.setAttribute("data-theme",q),localStorage.setItem("theme",q);let H=fJ.includes(q)?"light":"dark";document.documentElement.setAttribute("data-syntax",H)})}}function TJ(){let G=document.getElementById("filter-input");if(G)G.addEventListener("input",(J)=>{let q=J.target.value.toLowerCase();document.querySelectorAll("[data-filter-text]").forEach((Z)=>{let Q=Z.getAttribute("data-filter-text"),K=Q&&Q.includes(q);if(Z instanceof HTMLElement)if(K)Z.style.display="";else Z.style.display="none"})})}function yJ(){document.addEventListener("click",(G)=>{let q=G.target.closest(".toggle-children");if(!q)return;let H=q.closest(".epic-item");if(!H)return;let Z=H.querySelector(".epic-children");if(!Z)return;let Q=Z.classList.contains("collapsed");if(Z.classList.toggle("collapsed"),q.classList.toggle("expanded"),Q)Z.style.maxHeight=Z.scrollHeight+"px",Z.style.opacity="1";else Z.style.maxHeight="0",Z.style.opacity="0"})}class hJ{container=null;activeToasts=new Set;initialized=!1;constructor(){if(document.readyState==="loading")document.addEventListener("DOMContentLoaded",()=>this.init());else this.init()}init(){if(this.initialized)return;this.container=document.createElement("div"),this.container.id="toast-container",this.container.className="toast-container",document.body.appendChild(this.container),this.initialized=!0}createToast(G){let J=document.createElement("div");J.className=`toast toast-${G.type||"info"}`;let q=document.createElement("div");q.className="toast-message",q.textContent=G.message;let H=document.createElement("div");if(H.className="toast-actions",G.retryAction){let Q=document.createElement("button");Q.className="toast-retry",Q.textContent="Retry",Q.addEventListener("click",async()=>{Q.disabled=!0,Q.textContent="Retrying...";try{await G.retryAction(),this.remove(J),this.show({message:"Success!",type:"success",duration:2000})}catch{Q.disabled=!1,Q.textContent="Retry"}}),H.appendChild(Q)}let Z=document.createElement("button");return Z.className="toast-close",Z.textContent="×",Z.addEventListener("click",()=>this.remove(J)),H.appendChild(Z),J.appendChild(q),J.appendChild(H),J}show(G){if(!this.initialized)return;if(!this.container)return;let J=this.createToast(G);if(this.container.appendChild(J),this.activeToasts.add(J),setTimeout(()=>{J.classList.add("toast-show")},10),G.duration&&G.duration>0)setTimeout(()=>{this.remove(J)},G.duration)}remove(G){G.classList.remove("toast-show"),setTimeout(()=>{if(G.parentNode)G.parentNode.removeChild(G);this.activeToasts.delete(G)},300)}clear(){this.activeToasts.forEach((G)=>this.remove(G))}}var _J=new hJ;function C1(G,J,q){let H=G instanceof Error?G.message:"Unknown error occurred";console.error(`[${J}] ${H}`,G),_J.show({message:`${J}: ${H}`,type:"error",duration:q?0:5000,retryAction:q})}function O1(G,J,q){let H=`HTTP ${G.status}: ${G.statusText}`;console.error(`[${J}] ${H}`,G),_J.show({message:`${J}: ${H}`,type:"error",duration:q?0:5000,retryAction:q})}function vJ(){document.addEventListener("click",(J)=>{let q=J.target;if(q.classList.contains("issue-title")&&q.closest(".issue-item"))G(q)});function G(J){let q=J.innerText,H=document.createElement("input");H.type="text",H.value=q,H.classList.add("edit-input"),H.addEventListener("click",($)=>$.stopPropagation()),J.replaceWith(H),H.focus();let Z=!1,Q=async()=>{if(Z)return;Z=!0;let $=H.value.trim(),L=H.closest(".issue-item"),X=L?L.getAttribute("data-id"):null;if($&&$!==q&&X){let W=async()=>{if(!(await fetch(`${Lp0()}/api/issues/${X}`,{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({title:$})})).ok)throw Error("Update failed")};try{await W();let Y=document.createElement("div");Y.classList.add("issue-title"),Y.innerText=$,H.replaceWith(Y)}catch(Y){if(Y instanceof Error&&Y.message==="Update failed")O1(new Response(null,{status:500,statusText:"Update failed"}),"Failed to update title",W);else C1(Y,"Failed to update title",W)}}else K()},K=()=>{let $=document.createElement("div");$.classList.add("issue-title"),$.innerText=q,H.replaceWith($)};H.addEventListener("blur",Q),H.addEventListener("keydown",($)=>{if($.key==="Enter")H.blur();else if($.key==="Escape")K(),Z=!0})}}var E0={OPEN:"open",IN_PROGRESS:"in_progress",BLOCKED:"blocked",CLOSED:"closed",DEFERRED:"deferred"},_1={[E0.OPEN]:0,[E0.IN_PROGRESS]:1,[E0.BLOCKED]:2,[E0.CLOSED]:3,[E0.DEFERRED]:4},S0={EPIC:"epic",FEATURE:"feature",BUG:"bug",TASK:"task",CHORE:"chore"},v1={[S0.EPIC]:0,[S0.FEATURE]:1,[S0.BUG]:2,[S0.TASK]:3,[S0.CHORE]:4};var J9=Object.values(E0);function gJ(G){return J9.includes(G)}function mJ(){let G=document.getElementById("columns-toggle"),J=document.getElementById("columns-dropdown");if(G&&J){let W=function(z,M){let U=document.querySelector(`.board-column[data-status="${z}"]`);if(U)U.style.display=M?"":"none"},Y=function(){let z={};X().forEach((M)=>{let U=M.getAttribute("data-status");if(U&&gJ(U))z[U]=M.checked}),localStorage.setItem("board-column-visibility",JSON.stringify(z))},$=localStorage.getItem("board-column-visibility"),L=$?JSON.parse($):null,X=()=>J.querySelectorAll('input[type="checkbox"]');X().forEach((z)=>{let M=z.getAttribute("data-status");if(!M||!gJ(M))return;if(L===null)z.checked=M!==E0.DEFERRED;else z.checked=L[M]!==!1;W(M,z.checked)}),J.addEventListener("change",(z)=>{let M=z.target;if(M.type!=="checkbox")return;let U=M.getAttribute("data-status");if(!U)return;W(U,M.checked),Y()}),G.addEventListener("click",(z)=>{z.stopPropagation(),J.classList.toggle("show")}),document.addEventListener("click",(z)=>{if(!J.contains(z.target)&&z.target!==G)J.classList.remove("show")}),J.addEventListener("click",(z)=>{z.stopPropagation()})}let q=()=>{let $=document.querySelectorAll(".type-filter");if($.length===0)return;let L=new Set(Array.from($).filter((W)=>W.checked).map((W)=>W.value));document.querySelectorAll(".issue-card").forEach((W)=>{let Y=!1;for(let z of L)if(W.classList.contains(`issue-type-${z}`)){Y=!0;break}W.classList.toggle("hidden-by-type",!Y)})},H=()=>{let $=document.querySelectorAll(".priority-filter");if($.length===0)return;let L=new Set(Array.from($).filter((W)=>W.checked).map((W)=>W.value));document.querySelectorAll(".issue-card").forEach((W)=>{let Y=W.getAttribute("data-priority")||"0";W.classList.toggle("hidden-by-priority",!L.has(Y))})},Z=()=>{let $=document.querySelectorAll(".assignee-filter");if($.length===0)return;let L=new Set(Array.from($).filter((W)=>W.checked).map((W)=>W.value));document.querySelectorAll(".issue-card").forEach((W)=>{let Y=W.getAttribute("data-assignee")||"";W.classList.toggle("hidden-by-assignee",!L.has(Y))})},Q=($)=>{document.querySelectorAll(".column-content").forEach((X)=>{let W=Array.from(X.querySelectorAll(".issue-card"));W.sort((Y,z)=>{if($==="priority"){let M=parseInt(Y.getAttribute("data-priority")||"0"),U=parseInt(z.getAttribute("data-priority")||"0");return M-U}else if($==="created"){let M=parseInt(Y.getAttribute("data-created")||"0");return parseInt(z.getAttribute("data-created")||"0")-M}else{let M=Y.querySelector(".issue-title")?.textContent?.trim()||"",U=z.querySelector(".issue-title")?.textContent?.trim()||"";return M.localeCompare(U)}}),W.forEach((Y)=>X.appendChild(Y))})};document.addEventListener("change",($)=>{let L=$.target;if(L.classList.contains("type-filter"))q();else if(L.classList.contains("priority-filter"))H();else if(L.classList.contains("assignee-filter"))Z()});let K=document.getElementById("board-sort");if(K)K.addEventListener("change",()=>{Q(K.value)});q(),H(),Z(),Q("priority")}function uJ(){let G=document.querySelectorAll('.issue-card[draggable="true"]'),J=document.querySelectorAll(".column-content");if(G.length>0&&J.length>0)G.forEach((H)=>{H.addEventListener("dragstart",()=>{H.classList.add("dragging"),H.style.opacity="0.5"}),H.addEventListener("dragend",()=>{H.classList.remove("dragging"),H.style.opacity="1"})}),J.forEach((H)=>{H.addEventListener("dragover",(Z)=>{Z.preventDefault();let Q=document.querySelector(".dragging");if(Q){let K=q(H,Z.clientY);if(K==null)H.appendChild(Q);else H.insertBefore(Q,K)}}),H.addEventListener("drop",async()=>{let Z=document.querySelector(".dragging");if(!Z)return;let Q=H.getAttribute("data-status"),K=Z.getAttribute("data-id");if(K&&Q){let $=async()=>{if(!(await fetch(`${Lp0()}/api/issues/${K}`,{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({status:Q})})).ok)throw Error("Update failed")};try{await $()}catch(L){if(L instanceof Error&&L.message==="Update failed")O1(new Response(null,{status:500,statusText:"Update failed"}),"Failed to update status",$);else C1(L,"Failed to update status",$)}}})});function q(H,Z){return[...H.querySelectorAll(".issue-card:not(.dragging)")].reduce(($,L)=>{let X=L.getBoundingClientRect(),W=Z-X.top-X.height/2;if(W<0&&W>$.offset)return{offset:W,element:L};else return $},{offset:Number.NEGATIVE_INFINITY,element:null}).element}}function dJ(){let G=-1,J=0,q=0,H=document.querySelector(".board")!==null,Z=document.querySelector(".issue-list")!==null;if(H)X();document.addEventListener("keydown",(W)=>{let Y=W.target;if(Y.tagName==="INPUT"||Y.tagName==="TEXTAREA")return;if(W.key==="Backspace"){W.preventDefault(),window.history.back();return}if(Z)Q(W);else if(H)K(W)});function Q(W){let Y=Array.from(document.querySelectorAll('.issue-item:not([style*="display: none"])'));if(Y.length===0)return;let z=document.querySelector(".issue-item.selected");if(z)G=Y.indexOf(z);if(W.key==="j"||W.key==="ArrowDown"){G=Math.min(G+1,Y.length-1);let M=Y.at(G);if(M)L(M);W.preventDefault()}else if(W.key==="k"||W.key==="ArrowUp"){G=Math.max(G-1,0);let M=Y.at(G);if(M)L(M);W.preventDefault()}else if(W.key==="Enter"||W.key==="o"){if(z){let M=z.querySelector(".issue-meta a");if(M)M.click()}}}function K(W){let Y=Array.from(document.querySelectorAll('.board-column:not([style*="display: none"])'));if(Y.length===0)return;if(W.key==="j"||W.key==="ArrowDown"){let z=Y.at(J);if(!z)return;let M=$(z);if(M.length>0)q=Math.min(q+1,M.length-1),X(),W.preventDefault()}else if(W.key==="k"||W.key==="ArrowUp")q=Math.max(q-1,0),X(),W.preventDefault();else if(W.key==="h"||W.key==="ArrowLeft"){J=Math.max(J-1,0);let z=Y.at(J);if(!z)return;let M=$(z);q=Math.min(q,Math.max(0,M.length-1)),X(),W.preventDefault()}else if(W.key==="l"||W.key==="ArrowRight"){J=Math.min(J+1,Y.length-1);let z=Y.at(J);if(!z)return;let M=$(z);q=Math.min(q,Math.max(0,M.length-1)),X(),W.preventDefault()}else if(W.key==="Enter"||W.key==="o"){let z=document.querySelector(".issue-card.selected");if(z){let M=z.querySelector("a");if(M)M.click()}}}function $(W){if(!W)return[];return Array.from(W.querySelectorAll('.issue-card:not([style*="display: none"])'))}function L(W){if(document.querySelectorAll(".issue-item.selected").forEach((Y)=>Y.classList.remove("selected")),W)W.classList.add("selected"),W.scrollIntoView({behavior:"smooth",block:"nearest"})}function X(){let W=Array.from(document.querySelectorAll('.board-column:not([style*="display: none"])'));if(W.length===0)return;J=Math.max(0,Math.min(J,W.length-1));let Y=W.at(J);if(!Y)return;let z=$(Y);if(document.querySelectorAll(".issue-card.selected").forEach((M)=>M.classList.remove("selected")),z.length>0){q=Math.max(0,Math.min(q,z.length-1));let M=z.at(q);if(M)M.classList.add("selected"),M.scrollIntoView({behavior:"smooth",block:"nearest"})}}}function F0(G){if(G===void 0)throw ReferenceError("this hasn't been initialised - super() hasn't been called");return G}function aJ(G,J){G.prototype=Object.create(J.prototype),G.prototype.constructor=G,G.__proto__=J}/*!
  * GSAP 3.14.2
  * https://gsap.com
  *
@@ -12,4 +12,4 @@ var fJ=["nacre-light","catppuccin-latte"];(function(){let G=localStorage.getItem
  * Copyright 2008-2025, GreenSock. All rights reserved.
  * Subject to the terms at https://gsap.com/standard-license
  * @author: Jack Doyle, jack@greensock.com
-*/var b8,b0,J1,NJ,p0,Yq,x8,kJ,Uq=function(){return typeof window<"u"},D0={},l0=180/Math.PI,q1=Math.PI/180,e0=Math.atan2,f8=1e8,AJ=/([A-Z])/g,Rq=/(left|right|width|margin|padding|x)/i,Bq=/[\s,\(]\S/,R0={autoAlpha:"opacity,visibility",scale:"scaleX,scaleY",alpha:"opacity"},DJ=function(J,q){return q.set(q.t,q.p,Math.round((q.s+q.c*J)*1e4)/1e4+q.u,q)},Fq=function(J,q){return q.set(q.t,q.p,J===1?q.e:Math.round((q.s+q.c*J)*1e4)/1e4+q.u,q)},Cq=function(J,q){return q.set(q.t,q.p,J?Math.round((q.s+q.c*J)*1e4)/1e4+q.u:q.b,q)},Oq=function(J,q){return q.set(q.t,q.p,J===1?q.e:J?Math.round((q.s+q.c*J)*1e4)/1e4+q.u:q.b,q)},Iq=function(J,q){var H=q.s+q.c*J;q.set(q.t,q.p,~~(H+(H<0?-0.5:0.5))+q.u,q)},m8=function(J,q){return q.set(q.t,q.p,J?q.e:q.b,q)},u8=function(J,q){return q.set(q.t,q.p,J!==1?q.b:q.e,q)},Dq=function(J,q,H){return J.style[q]=H},Eq=function(J,q,H){return J.style.setProperty(q,H)},Sq=function(J,q,H){return J._gsap[q]=H},Vq=function(J,q,H){return J._gsap.scaleX=J._gsap.scaleY=H},Nq=function(J,q,H,Z,Q){var K=J._gsap;K.scaleX=K.scaleY=H,K.renderTransform(Q,K)},kq=function(J,q,H,Z,Q){var K=J._gsap;K[q]=H,K.renderTransform(Q,K)},v="transform",K0=v+"Origin",Aq=function G(J,q){var H=this,Z=this.target,Q=Z.style,K=Z._gsap;if(J in D0&&Q){if(this.tfm=this.tfm||{},J!=="transform")J=R0[J]||J,~J.indexOf(",")?J.split(",").forEach(function($){return H.tfm[$]=I0(Z,$)}):this.tfm[J]=K.x?K[J]:I0(Z,J),J===K0&&(this.tfm.zOrigin=K.zOrigin);else return R0.transform.split(",").forEach(function($){return G.call(H,$,q)});if(this.props.indexOf(v)>=0)return;if(K.svg)this.svgo=Z.getAttribute("data-svg-origin"),this.props.push(K0,q,"");J=v}(Q||q)&&this.props.push(J,q,Q[J])},d8=function(J){if(J.translate)J.removeProperty("translate"),J.removeProperty("scale"),J.removeProperty("rotate")},Pq=function(){var J=this.props,q=this.target,H=q.style,Z=q._gsap,Q,K;for(Q=0;Q<J.length;Q+=3)if(!J[Q+1])J[Q+2]?H[J[Q]]=J[Q+2]:H.removeProperty(J[Q].substr(0,2)==="--"?J[Q]:J[Q].replace(AJ,"-$1").toLowerCase());else if(J[Q+1]===2)q[J[Q]](J[Q+2]);else q[J[Q]]=J[Q+2];if(this.tfm){for(K in this.tfm)Z[K]=this.tfm[K];if(Z.svg)Z.renderTransform(),q.setAttribute("data-svg-origin",this.svgo||"");if(Q=kJ(),(!Q||!Q.isStart)&&!H[v]){if(d8(H),Z.zOrigin&&H[K0])H[K0]+=" "+Z.zOrigin+"px",Z.zOrigin=0,Z.renderTransform();Z.uncache=1}}},c8=function(J,q){var H={target:J,props:[],revert:Pq,save:Aq};return J._gsap||i.core.getCache(J),q&&J.style&&J.nodeType&&q.split(",").forEach(function(Z){return H.save(Z)}),H},l8,EJ=function(J,q){var H=b0.createElementNS?b0.createElementNS((q||"http://www.w3.org/1999/xhtml").replace(/^https/,"http"),J):b0.createElement(J);return H&&H.style?H:b0.createElement(J)},L0=function G(J,q,H){var Z=getComputedStyle(J);return Z[q]||Z.getPropertyValue(q.replace(AJ,"-$1").toLowerCase())||Z.getPropertyValue(q)||!H&&G(J,H1(q)||q,1)||""},w8="O,Moz,ms,Ms,Webkit".split(","),H1=function(J,q,H){var Z=q||p0,Q=Z.style,K=5;if(J in Q&&!H)return J;J=J.charAt(0).toUpperCase()+J.substr(1);while(K--&&!(w8[K]+J in Q));return K<0?null:(K===3?"ms":K>=0?w8[K]:"")+J},SJ=function(){if(Uq()&&window.document)b8=window,b0=b8.document,J1=b0.documentElement,p0=EJ("div")||{style:{}},Yq=EJ("div"),v=H1(v),K0=v+"Origin",p0.style.cssText="border-width:0;line-height:0;position:absolute;padding:0",l8=!!H1("perspective"),kJ=i.core.reverting,NJ=1},T8=function(J){var q=J.ownerSVGElement,H=EJ("svg",q&&q.getAttribute("xmlns")||"http://www.w3.org/2000/svg"),Z=J.cloneNode(!0),Q;Z.style.display="block",H.appendChild(Z),J1.appendChild(H);try{Q=Z.getBBox()}catch(K){}return H.removeChild(Z),J1.removeChild(H),Q},y8=function(J,q){var H=q.length;while(H--)if(J.hasAttribute(q[H]))return J.getAttribute(q[H])},p8=function(J){var q,H;try{q=J.getBBox()}catch(Z){q=T8(J),H=1}return q&&(q.width||q.height)||H||(q=T8(J)),q&&!q.width&&!q.x&&!q.y?{x:+y8(J,["x","cx","x1"])||0,y:+y8(J,["y","cy","y1"])||0,width:0,height:0}:q},o8=function(J){return!!(J.getCTM&&(!J.parentNode||J.ownerSVGElement)&&p8(J))},f0=function(J,q){if(q){var H=J.style,Z;if(q in D0&&q!==K0)q=v;if(H.removeProperty){if(Z=q.substr(0,2),Z==="ms"||q.substr(0,6)==="webkit")q="-"+q;H.removeProperty(Z==="--"?q:q.replace(AJ,"-$1").toLowerCase())}else H.removeAttribute(q)}},x0=function(J,q,H,Z,Q,K){var $=new t(J._pt,q,H,0,1,K?u8:m8);return J._pt=$,$.b=Z,$.e=Q,J._props.push(H),$},h8={deg:1,rad:1,turn:1},bq={grid:1,flex:1},w0=function G(J,q,H,Z){var Q=parseFloat(H)||0,K=(H+"").trim().substr((Q+"").length)||"px",$=p0.style,L=Rq.test(q),X=J.tagName.toLowerCase()==="svg",W=(X?"client":"offset")+(L?"Width":"Height"),Y=100,z=Z==="px",M=Z==="%",U,j,R,B;if(Z===K||!Q||h8[Z]||h8[K])return Q;if(K!=="px"&&!z&&(Q=G(J,q,H,"px")),B=J.getCTM&&o8(J),(M||K==="%")&&(D0[q]||~q.indexOf("adius")))return U=B?J.getBBox()[L?"width":"height"]:J[W],m(M?Q/U*Y:Q/100*U);if($[L?"width":"height"]=Y+(z?K:Z),j=Z!=="rem"&&~q.indexOf("adius")||Z==="em"&&J.appendChild&&!X?J:J.parentNode,B)j=(J.ownerSVGElement||{}).parentNode;if(!j||j===b0||!j.appendChild)j=b0.body;if(R=j._gsap,R&&M&&R.width&&L&&R.time===q0.time&&!R.uncache)return m(Q/R.width*Y);else{if(M&&(q==="height"||q==="width")){var F=J.style[q];J.style[q]=Y+Z,U=J[W],F?J.style[q]=F:f0(J,q)}else(M||K==="%")&&!bq[L0(j,"display")]&&($.position=L0(J,"position")),j===J&&($.position="static"),j.appendChild(p0),U=p0[W],j.removeChild(p0),$.position="absolute";if(L&&M)R=k0(j),R.time=q0.time,R.width=j[W]}return m(z?U*Q/Y:U&&Q?Y/U*Q:0)},I0=function(J,q,H,Z){var Q;if(NJ||SJ(),q in R0&&q!=="transform"){if(q=R0[q],~q.indexOf(","))q=q.split(",")[0]}if(D0[q]&&q!=="transform")Q=F1(J,Z),Q=q!=="transformOrigin"?Q[q]:Q.svg?Q.origin:h1(L0(J,K0))+" "+Q.zOrigin+"px";else if(Q=J.style[q],!Q||Q==="auto"||Z||~(Q+"").indexOf("calc("))Q=y1[q]&&y1[q](J,q,H)||L0(J,q)||XJ(J,q)||(q==="opacity"?1:0);return H&&!~(Q+"").trim().indexOf(" ")?w0(J,q,Q,H)+H:Q},xq=function(J,q,H,Z){if(!H||H==="none"){var Q=H1(q,J,1),K=Q&&L0(J,Q,1);if(K&&K!==H)q=Q,H=K;else if(q==="borderColor")H=L0(J,"borderTopColor")}var $=new t(this._pt,J.style,q,0,1,FJ),L=0,X=0,W,Y,z,M,U,j,R,B,F,C,I,O;if($.b=H,$.e=Z,H+="",Z+="",Z.substring(0,6)==="var(--")Z=L0(J,Z.substring(4,Z.indexOf(")")));if(Z==="auto")j=J.style[q],J.style[q]=Z,Z=L0(J,q)||Z,j?J.style[q]=j:f0(J,q);if(W=[H,Z],zJ(W),H=W[0],Z=W[1],z=H.match(g0)||[],O=Z.match(g0)||[],O.length){while(Y=g0.exec(Z)){if(R=Y[0],F=Z.substring(L,Y.index),U)U=(U+1)%5;else if(F.substr(-5)==="rgba("||F.substr(-5)==="hsla(")U=1;if(R!==(j=z[X++]||"")){if(M=parseFloat(j)||0,I=j.substr((M+"").length),R.charAt(1)==="="&&(R=m0(M,R)+I),B=parseFloat(R),C=R.substr((B+"").length),L=g0.lastIndex-C.length,!C){if(C=C||Z0.units[q]||I,L===Z.length)Z+=C,$.e+=C}if(I!==C)M=w0(J,q,j,C)||0;$._pt={_next:$._pt,p:F||X===1?F:",",s:M,c:B-M,m:U&&U<4||q==="zIndex"?Math.round:0}}}$.c=L<Z.length?Z.substring(L,Z.length):""}else $.r=q==="display"&&Z==="none"?u8:m8;return KJ.test(Z)&&($.e=0),this._pt=$,$},_8={top:"0%",bottom:"100%",left:"0%",right:"100%",center:"50%"},fq=function(J){var q=J.split(" "),H=q[0],Z=q[1]||"50%";if(H==="top"||H==="bottom"||Z==="left"||Z==="right")J=H,H=Z,Z=J;return q[0]=_8[H]||H,q[1]=_8[Z]||Z,q.join(" ")},wq=function(J,q){if(q.tween&&q.tween._time===q.tween._dur){var H=q.t,Z=H.style,Q=q.u,K=H._gsap,$,L,X;if(Q==="all"||Q===!0)Z.cssText="",L=1;else{Q=Q.split(","),X=Q.length;while(--X>-1){if($=Q[X],D0[$])L=1,$=$==="transformOrigin"?K0:v;f0(H,$)}}if(L){if(f0(H,v),K)K.svg&&H.removeAttribute("transform"),Z.scale=Z.rotate=Z.translate="none",F1(H,1),K.uncache=1,d8(Z)}}},y1={clearProps:function(J,q,H,Z,Q){if(Q.data!=="isFromStart"){var K=J._pt=new t(J._pt,q,H,0,0,wq);return K.u=Z,K.pr=-10,K.tween=Q,J._props.push(H),1}}},B1=[1,0,0,1,0,0],s8={},n8=function(J){return J==="matrix(1, 0, 0, 1, 0, 0)"||J==="none"||!J},v8=function(J){var q=L0(J,v);return n8(q)?B1:q.substr(7).match(QJ).map(m)},PJ=function(J,q){var H=J._gsap||k0(J),Z=J.style,Q=v8(J),K,$,L,X;if(H.svg&&J.getAttribute("transform"))return L=J.transform.baseVal.consolidate().matrix,Q=[L.a,L.b,L.c,L.d,L.e,L.f],Q.join(",")==="1,0,0,1,0,0"?B1:Q;else if(Q===B1&&!J.offsetParent&&J!==J1&&!H.svg){if(L=Z.display,Z.display="block",K=J.parentNode,!K||!J.offsetParent&&!J.getBoundingClientRect().width)X=1,$=J.nextElementSibling,J1.appendChild(J);if(Q=v8(J),L?Z.display=L:f0(J,"display"),X)$?K.insertBefore(J,$):K?K.appendChild(J):J1.removeChild(J)}return q&&Q.length>6?[Q[0],Q[1],Q[4],Q[5],Q[12],Q[13]]:Q},VJ=function(J,q,H,Z,Q,K){var $=J._gsap,L=Q||PJ(J,!0),X=$.xOrigin||0,W=$.yOrigin||0,Y=$.xOffset||0,z=$.yOffset||0,M=L[0],U=L[1],j=L[2],R=L[3],B=L[4],F=L[5],C=q.split(" "),I=parseFloat(C[0])||0,O=parseFloat(C[1])||0,S,E,V,D;if(!H)S=p8(J),I=S.x+(~C[0].indexOf("%")?I/100*S.width:I),O=S.y+(~(C[1]||C[0]).indexOf("%")?O/100*S.height:O);else if(L!==B1&&(E=M*R-U*j))V=I*(R/E)+O*(-j/E)+(j*F-R*B)/E,D=I*(-U/E)+O*(M/E)-(M*F-U*B)/E,I=V,O=D;if(Z||Z!==!1&&$.smooth)B=I-X,F=O-W,$.xOffset=Y+(B*M+F*j)-B,$.yOffset=z+(B*U+F*R)-F;else $.xOffset=$.yOffset=0;if($.xOrigin=I,$.yOrigin=O,$.smooth=!!Z,$.origin=q,$.originIsAbsolute=!!H,J.style[K0]="0px 0px",K)x0(K,$,"xOrigin",X,I),x0(K,$,"yOrigin",W,O),x0(K,$,"xOffset",Y,$.xOffset),x0(K,$,"yOffset",z,$.yOffset);J.setAttribute("data-svg-origin",I+" "+O)},F1=function(J,q){var H=J._gsap||new MJ(J);if("x"in H&&!q&&!H.uncache)return H;var Z=J.style,Q=H.scaleX<0,K="px",$="deg",L=getComputedStyle(J),X=L0(J,K0)||"0",W,Y,z,M,U,j,R,B,F,C,I,O,S,E,V,D,N,w,A,P,c,u,y,b,e,$0,Z1,Q1,T0,xJ,B0,y0;if(W=Y=z=j=R=B=F=C=I=0,M=U=1,H.svg=!!(J.getCTM&&o8(J)),L.translate){if(L.translate!=="none"||L.scale!=="none"||L.rotate!=="none")Z[v]=(L.translate!=="none"?"translate3d("+(L.translate+" 0 0").split(" ").slice(0,3).join(", ")+") ":"")+(L.rotate!=="none"?"rotate("+L.rotate+") ":"")+(L.scale!=="none"?"scale("+L.scale.split(" ").join(",")+") ":"")+(L[v]!=="none"?L[v]:"");Z.scale=Z.rotate=Z.translate="none"}if(E=PJ(J,H.svg),H.svg){if(H.uncache)e=J.getBBox(),X=H.xOrigin-e.x+"px "+(H.yOrigin-e.y)+"px",b="";else b=!q&&J.getAttribute("data-svg-origin");VJ(J,b||X,!!b||H.originIsAbsolute,H.smooth!==!1,E)}if(O=H.xOrigin||0,S=H.yOrigin||0,E!==B1){if(w=E[0],A=E[1],P=E[2],c=E[3],W=u=E[4],Y=y=E[5],E.length===6){if(M=Math.sqrt(w*w+A*A),U=Math.sqrt(c*c+P*P),j=w||A?e0(A,w)*l0:0,F=P||c?e0(P,c)*l0+j:0,F&&(U*=Math.abs(Math.cos(F*q1))),H.svg)W-=O-(O*w+S*P),Y-=S-(O*A+S*c)}else{if(y0=E[6],xJ=E[7],Z1=E[8],Q1=E[9],T0=E[10],B0=E[11],W=E[12],Y=E[13],z=E[14],V=e0(y0,T0),R=V*l0,V)D=Math.cos(-V),N=Math.sin(-V),b=u*D+Z1*N,e=y*D+Q1*N,$0=y0*D+T0*N,Z1=u*-N+Z1*D,Q1=y*-N+Q1*D,T0=y0*-N+T0*D,B0=xJ*-N+B0*D,u=b,y=e,y0=$0;if(V=e0(-P,T0),B=V*l0,V)D=Math.cos(-V),N=Math.sin(-V),b=w*D-Z1*N,e=A*D-Q1*N,$0=P*D-T0*N,B0=c*N+B0*D,w=b,A=e,P=$0;if(V=e0(A,w),j=V*l0,V)D=Math.cos(V),N=Math.sin(V),b=w*D+A*N,e=u*D+y*N,A=A*D-w*N,y=y*D-u*N,w=b,u=e;if(R&&Math.abs(R)+Math.abs(j)>359.9)R=j=0,B=180-B;M=m(Math.sqrt(w*w+A*A+P*P)),U=m(Math.sqrt(y*y+y0*y0)),V=e0(u,y),F=Math.abs(V)>0.0002?V*l0:0,I=B0?1/(B0<0?-B0:B0):0}if(H.svg)b=J.getAttribute("transform"),H.forceCSS=J.setAttribute("transform","")||!n8(L0(J,v)),b&&J.setAttribute("transform",b)}if(Math.abs(F)>90&&Math.abs(F)<270)if(Q)M*=-1,F+=j<=0?180:-180,j+=j<=0?180:-180;else U*=-1,F+=F<=0?180:-180;if(q=q||H.uncache,H.x=W-((H.xPercent=W&&(!q&&H.xPercent||(Math.round(J.offsetWidth/2)===Math.round(-W)?-50:0)))?J.offsetWidth*H.xPercent/100:0)+K,H.y=Y-((H.yPercent=Y&&(!q&&H.yPercent||(Math.round(J.offsetHeight/2)===Math.round(-Y)?-50:0)))?J.offsetHeight*H.yPercent/100:0)+K,H.z=z+K,H.scaleX=m(M),H.scaleY=m(U),H.rotation=m(j)+$,H.rotationX=m(R)+$,H.rotationY=m(B)+$,H.skewX=F+$,H.skewY=C+$,H.transformPerspective=I+K,H.zOrigin=parseFloat(X.split(" ")[2])||!q&&H.zOrigin||0)Z[K0]=h1(X);return H.xOffset=H.yOffset=0,H.force3D=Z0.force3D,H.renderTransform=H.svg?yq:l8?i8:Tq,H.uncache=0,H},h1=function(J){return(J=J.split(" "))[0]+" "+J[1]},IJ=function(J,q,H){var Z=s(q);return m(parseFloat(q)+parseFloat(w0(J,"x",H+"px",Z)))+Z},Tq=function(J,q){q.z="0px",q.rotationY=q.rotationX="0deg",q.force3D=0,i8(J,q)},d0="0deg",R1="0px",c0=") ",i8=function(J,q){var H=q||this,Z=H.xPercent,Q=H.yPercent,K=H.x,$=H.y,L=H.z,X=H.rotation,W=H.rotationY,Y=H.rotationX,z=H.skewX,M=H.skewY,U=H.scaleX,j=H.scaleY,R=H.transformPerspective,B=H.force3D,F=H.target,C=H.zOrigin,I="",O=B==="auto"&&J&&J!==1||B===!0;if(C&&(Y!==d0||W!==d0)){var S=parseFloat(W)*q1,E=Math.sin(S),V=Math.cos(S),D;S=parseFloat(Y)*q1,D=Math.cos(S),K=IJ(F,K,E*D*-C),$=IJ(F,$,-Math.sin(S)*-C),L=IJ(F,L,V*D*-C+C)}if(R!==R1)I+="perspective("+R+c0;if(Z||Q)I+="translate("+Z+"%, "+Q+"%) ";if(O||K!==R1||$!==R1||L!==R1)I+=L!==R1||O?"translate3d("+K+", "+$+", "+L+") ":"translate("+K+", "+$+c0;if(X!==d0)I+="rotate("+X+c0;if(W!==d0)I+="rotateY("+W+c0;if(Y!==d0)I+="rotateX("+Y+c0;if(z!==d0||M!==d0)I+="skew("+z+", "+M+c0;if(U!==1||j!==1)I+="scale("+U+", "+j+c0;F.style[v]=I||"translate(0, 0)"},yq=function(J,q){var H=q||this,Z=H.xPercent,Q=H.yPercent,K=H.x,$=H.y,L=H.rotation,X=H.skewX,W=H.skewY,Y=H.scaleX,z=H.scaleY,M=H.target,U=H.xOrigin,j=H.yOrigin,R=H.xOffset,B=H.yOffset,F=H.forceCSS,C=parseFloat(K),I=parseFloat($),O,S,E,V,D;if(L=parseFloat(L),X=parseFloat(X),W=parseFloat(W),W)W=parseFloat(W),X+=W,L+=W;if(L||X){if(L*=q1,X*=q1,O=Math.cos(L)*Y,S=Math.sin(L)*Y,E=Math.sin(L-X)*-z,V=Math.cos(L-X)*z,X){if(W*=q1,D=Math.tan(X-W),D=Math.sqrt(1+D*D),E*=D,V*=D,W)D=Math.tan(W),D=Math.sqrt(1+D*D),O*=D,S*=D}O=m(O),S=m(S),E=m(E),V=m(V)}else O=Y,V=z,S=E=0;if(C&&!~(K+"").indexOf("px")||I&&!~($+"").indexOf("px"))C=w0(M,"x",K,"px"),I=w0(M,"y",$,"px");if(U||j||R||B)C=m(C+U-(U*O+j*E)+R),I=m(I+j-(U*S+j*V)+B);if(Z||Q)D=M.getBBox(),C=m(C+Z/100*D.width),I=m(I+Q/100*D.height);D="matrix("+O+","+S+","+E+","+V+","+C+","+I+")",M.setAttribute("transform",D),F&&(M.style[v]=D)},hq=function(J,q,H,Z,Q){var K=360,$=l(Q),L=parseFloat(Q)*($&&~Q.indexOf("rad")?l0:1),X=L-Z,W=Z+X+"deg",Y,z;if($){if(Y=Q.split("_")[1],Y==="short"){if(X%=K,X!==X%(K/2))X+=X<0?K:-K}if(Y==="cw"&&X<0)X=(X+K*f8)%K-~~(X/K)*K;else if(Y==="ccw"&&X>0)X=(X-K*f8)%K-~~(X/K)*K}return J._pt=z=new t(J._pt,q,H,Z,X,Fq),z.e=W,z.u="deg",J._props.push(H),z},g8=function(J,q){for(var H in q)J[H]=q[H];return J},_q=function(J,q,H){var Z=g8({},H._gsap),Q="perspective,force3D,transformOrigin,svgOrigin",K=H.style,$,L,X,W,Y,z,M,U;if(Z.svg)X=H.getAttribute("transform"),H.setAttribute("transform",""),K[v]=q,$=F1(H,1),f0(H,v),H.setAttribute("transform",X);else X=getComputedStyle(H)[v],K[v]=q,$=F1(H,1),K[v]=X;for(L in D0)if(X=Z[L],W=$[L],X!==W&&Q.indexOf(L)<0)M=s(X),U=s(W),Y=M!==U?w0(H,L,X,U):parseFloat(X),z=parseFloat(W),J._pt=new t(J._pt,$,L,Y,z-Y,DJ),J._pt.u=U||0,J._props.push(L);g8($,Z)};a("padding,margin,Width,Radius",function(G,J){var q="Top",H="Right",Z="Bottom",Q="Left",K=(J<3?[q,H,Z,Q]:[q+Q,q+H,Z+H,Z+Q]).map(function($){return J<2?G+$:"border"+$+G});y1[J>1?"border"+G:G]=function($,L,X,W,Y){var z,M;if(arguments.length<4)return z=K.map(function(U){return I0($,U,X)}),M=z.join(" "),M.split(z[0]).length===5?z[0]:M;z=(W+"").split(" "),M={},K.forEach(function(U,j){return M[U]=z[j]=z[j]||z[(j-1)/2|0]}),$.init(L,M,Y)}});var bJ={name:"css",register:SJ,targetTest:function(J){return J.style&&J.nodeType},init:function(J,q,H,Z,Q){var K=this._props,$=J.style,L=H.vars.startAt,X,W,Y,z,M,U,j,R,B,F,C,I,O,S,E,V,D;NJ||SJ(),this.styles=this.styles||c8(J),V=this.styles.props,this.tween=H;for(j in q){if(j==="autoRound")continue;if(W=q[j],J0[j]&&UJ(j,q,H,Z,J,Q))continue;if(M=typeof W,U=y1[j],M==="function")W=W.call(H,Z,J,Q),M=typeof W;if(M==="string"&&~W.indexOf("random("))W=t0(W);if(U)U(this,J,j,W,H)&&(E=1);else if(j.substr(0,2)==="--"){if(X=(getComputedStyle(J).getPropertyValue(j)+"").trim(),W+="",C0.lastIndex=0,!C0.test(X))R=s(X),B=s(W),B?R!==B&&(X=w0(J,j,X,B)+B):R&&(W+=R);this.add($,"setProperty",X,W,Z,Q,0,0,j),K.push(j),V.push(j,0,$[j])}else if(M!=="undefined"){if(L&&j in L)X=typeof L[j]==="function"?L[j].call(H,Z,J,Q):L[j],l(X)&&~X.indexOf("random(")&&(X=t0(X)),s(X+"")||X==="auto"||(X+=Z0.units[j]||s(I0(J,j))||""),(X+"").charAt(1)==="="&&(X=I0(J,j));else X=I0(J,j);if(z=parseFloat(X),F=M==="string"&&W.charAt(1)==="="&&W.substr(0,2),F&&(W=W.substr(2)),Y=parseFloat(W),j in R0){if(j==="autoAlpha"){if(z===1&&I0(J,"visibility")==="hidden"&&Y)z=0;V.push("visibility",0,$.visibility),x0(this,$,"visibility",z?"inherit":"hidden",Y?"inherit":"hidden",!Y)}if(j!=="scale"&&j!=="transform")j=R0[j],~j.indexOf(",")&&(j=j.split(",")[0])}if(C=j in D0,C){if(this.styles.save(j),D=W,M==="string"&&W.substring(0,6)==="var(--"){if(W=L0(J,W.substring(4,W.indexOf(")"))),W.substring(0,5)==="calc("){var N=J.style.perspective;J.style.perspective=W,W=L0(J,"perspective"),N?J.style.perspective=N:f0(J,"perspective")}Y=parseFloat(W)}if(!I)O=J._gsap,O.renderTransform&&!q.parseTransform||F1(J,q.parseTransform),S=q.smoothOrigin!==!1&&O.smooth,I=this._pt=new t(this._pt,$,v,0,1,O.renderTransform,O,0,-1),I.dep=1;if(j==="scale")this._pt=new t(this._pt,O,"scaleY",O.scaleY,(F?m0(O.scaleY,F+Y):Y)-O.scaleY||0,DJ),this._pt.u=0,K.push("scaleY",j),j+="X";else if(j==="transformOrigin"){if(V.push(K0,0,$[K0]),W=fq(W),O.svg)VJ(J,W,0,S,0,this);else B=parseFloat(W.split(" ")[2])||0,B!==O.zOrigin&&x0(this,O,"zOrigin",O.zOrigin,B),x0(this,$,j,h1(X),h1(W));continue}else if(j==="svgOrigin"){VJ(J,W,1,S,0,this);continue}else if(j in s8){hq(this,O,j,z,F?m0(z,F+W):W);continue}else if(j==="smoothOrigin"){x0(this,O,"smooth",O.smooth,W);continue}else if(j==="force3D"){O[j]=W;continue}else if(j==="transform"){_q(this,W,J);continue}}else if(!(j in $))j=H1(j)||j;if(C||(Y||Y===0)&&(z||z===0)&&!Bq.test(W)&&j in $){if(R=(X+"").substr((z+"").length),Y||(Y=0),B=s(W)||(j in Z0.units?Z0.units[j]:R),R!==B&&(z=w0(J,j,X,B)),this._pt=new t(this._pt,C?O:$,j,z,(F?m0(z,F+Y):Y)-z,!C&&(B==="px"||j==="zIndex")&&q.autoRound!==!1?Iq:DJ),this._pt.u=B||0,C&&D!==W)this._pt.b=X,this._pt.e=D,this._pt.r=Oq;else if(R!==B&&B!=="%")this._pt.b=X,this._pt.r=Cq}else if(!(j in $)){if(j in J)this.add(J,j,X||J[j],F?F+W:W,Z,Q);else if(j!=="parseTransform"){x1(j,W);continue}}else xq.call(this,J,j,X,F?F+W:W);C||(j in $?V.push(j,0,$[j]):typeof J[j]==="function"?V.push(j,2,J[j]()):V.push(j,1,X||J[j])),K.push(j)}}E&&OJ(this)},render:function(J,q){if(q.tween._time||!kJ()){var H=q._pt;while(H)H.r(J,H.d),H=H._next}else q.styles.revert()},get:I0,aliases:R0,getSetter:function(J,q,H){var Z=R0[q];return Z&&Z.indexOf(",")<0&&(q=Z),q in D0&&q!==K0&&(J._gsap.x||I0(J,"x"))?H&&x8===H?q==="scale"?Vq:Sq:(x8=H||{})&&(q==="scale"?Nq:kq):J.style&&!b1(J.style[q])?Dq:~q.indexOf("-")?Eq:T1(J,q)},core:{_removeProperty:f0,_getMatrix:PJ}};i.utils.checkPrefix=H1;i.core.getStyleSaver=c8;(function(G,J,q,H){var Z=a(G+","+J+","+q,function(Q){D0[Q]=1});a(J,function(Q){Z0.units[Q]="deg",s8[Q]=1}),R0[Z[13]]=G+","+J,a(H,function(Q){var K=Q.split(":");R0[K[1]]=Z[K[0]]})})("x,y,z,scale,scaleX,scaleY,xPercent,yPercent","rotation,rotationX,rotationY,skewX,skewY","transform,transformOrigin,svgOrigin,force3D,smoothOrigin,transformPerspective","0:translateX,1:translateY,2:translateZ,8:rotate,8:rotationZ,8:rotateZ,9:rotateX,10:rotateY");a("x,y,z,top,right,bottom,left,width,height,fontSize,padding,margin,perspective",function(G){Z0.units[G]="px"});i.registerPlugin(bJ);var r=i.registerPlugin(bJ)||i,MH=r.core.Tween;function vq(){let G=document.querySelector(".org-tree");if(!G)return;let J=G.querySelector(".org-tree-svg");if(!J)J=document.createElementNS("http://www.w3.org/2000/svg","svg"),J.classList.add("org-tree-svg"),J.style.position="absolute",J.style.top="0",J.style.left="0",J.style.width="100%",J.style.height="100%",J.style.pointerEvents="none",J.style.overflow="visible",G.style.position="relative",G.insertBefore(J,G.firstChild);let q=new Map,H=new Map,Z=!0,Q=[];function K(){J.querySelectorAll("path").forEach((R)=>r.killTweensOf(R)),Q.forEach((R)=>R()),Q=[],J.innerHTML="",q.clear(),H.clear();let z=getComputedStyle(document.documentElement),M=z.getPropertyValue("--connector-color").trim()||z.getPropertyValue("--border-color").trim()||"#585b70",U=[];if(G.querySelectorAll("li").forEach((R)=>{let B=R.querySelector(":scope > a.org-node, :scope > .org-node"),F=R.querySelector(":scope > ul");if(!B||!F)return;let C=F.querySelectorAll(":scope > li");if(C.length===0)return;let I=0,O=R;while(O&&O!==G){if(O.tagName==="UL")I++;O=O.parentElement}let S=B.getBoundingClientRect(),E=G.getBoundingClientRect(),V=S.left+S.width/2-E.left,D=S.bottom-E.top,N=[];C.forEach((w)=>{let A=w.querySelector(":scope > a.org-node, :scope > .org-node");if(!A)return;let P=A.getBoundingClientRect(),c=P.left+P.width/2-E.left,u=P.top-E.top,y=(D+u)/2,b=document.createElementNS("http://www.w3.org/2000/svg","path"),e=`M ${V} ${D} C ${V} ${y}, ${c} ${y}, ${c} ${u}`,$0=document.createElementNS("http://www.w3.org/2000/svg","path");$0.setAttribute("d",e),$0.setAttribute("fill","none"),$0.setAttribute("stroke","transparent"),$0.setAttribute("stroke-width","14"),$0.setAttribute("stroke-linecap","round"),$0.classList.add("connector-hit-area"),J.appendChild($0),b.setAttribute("d",e),b.setAttribute("fill","none"),b.setAttribute("stroke",M),b.setAttribute("stroke-width","2"),b.setAttribute("stroke-linecap","round"),b.style.pointerEvents="none",J.appendChild(b),N.push({path:b,childNode:A,hitArea:$0}),H.set(A,{path:b,parentNode:B}),U.push({path:b,depth:I})}),q.set(B,N)}),Z){if(Z=!1,U.length>0)$(U);L(G)}X()}function $(z){z.sort((M,U)=>M.depth-U.depth),z.forEach(({path:M},U)=>{let j=M.getTotalLength();M.setAttribute("stroke-dasharray",String(j)),M.setAttribute("stroke-dashoffset",String(j)),r.to(M,{strokeDashoffset:0,duration:0.4,delay:U*0.06,ease:"power2.out",onComplete:()=>{M.removeAttribute("stroke-dasharray"),M.removeAttribute("stroke-dashoffset")}})})}function L(z){let M=new Map;z.querySelectorAll("li").forEach((R)=>{let B=R.querySelector(":scope > a.org-node, :scope > .org-node");if(!B)return;let F=0,C=R;while(C&&C!==z){if(C.tagName==="UL")F++;C=C.parentElement}let I=M.get(F);if(I)I.push(B);else M.set(F,[B])});let U=Array.from(M.keys()).sort((R,B)=>R-B),j=0;for(let R of U){let B=M.get(R);if(B.length===0)continue;r.fromTo(B,{opacity:0,y:15,scale:0.95},{opacity:1,y:0,scale:1,duration:0.35,stagger:0.04,delay:j,ease:"power2.out",clearProps:"transform"}),j+=0.1+B.length*0.02}}function X(){let z=getComputedStyle(document.documentElement),M=z.getPropertyValue("--accent").trim()||"#fab387",U=z.getPropertyValue("--connector-color").trim()||z.getPropertyValue("--border-color").trim()||"#585b70";function j(B,F,C){B.addEventListener(F,C),Q.push(()=>B.removeEventListener(F,C))}q.forEach((B,F)=>{j(F,"mouseenter",()=>{B.forEach(({path:C,childNode:I})=>{r.to(C,{stroke:M,strokeWidth:3,duration:0.2,overwrite:!0}),I.classList.add("org-node-highlight")})}),j(F,"mouseleave",()=>{B.forEach(({path:C,childNode:I})=>{r.to(C,{stroke:U,strokeWidth:2,duration:0.2,overwrite:!0}),I.classList.remove("org-node-highlight")})})}),H.forEach(({path:B,parentNode:F},C)=>{j(C,"mouseenter",()=>{r.to(B,{stroke:M,strokeWidth:3,duration:0.2,overwrite:!0}),F.classList.add("org-node-highlight")}),j(C,"mouseleave",()=>{r.to(B,{stroke:U,strokeWidth:2,duration:0.2,overwrite:!0}),F.classList.remove("org-node-highlight")})}),G.querySelectorAll(".org-node").forEach((B)=>{j(B,"mouseenter",()=>{r.to(B,{scale:1.05,boxShadow:"0 4px 12px rgba(0,0,0,0.15)",duration:0.2,ease:"power2.out",overwrite:!0})}),j(B,"mouseleave",()=>{r.to(B,{scale:1,boxShadow:"var(--shadow-sm)",duration:0.2,ease:"power2.inOut",overwrite:!0})}),j(B,"click",()=>{r.timeline().to(B,{scale:0.97,duration:0.08,ease:"power2.in"}).to(B,{scale:1.05,duration:0.15,ease:"power2.out"})})}),q.forEach((B,F)=>{B.forEach(({path:C,childNode:I,hitArea:O})=>{j(O,"mouseenter",()=>{r.to(C,{stroke:M,strokeWidth:3,duration:0.2,overwrite:!0}),F.classList.add("org-node-highlight"),I.classList.add("org-node-highlight")}),j(O,"mouseleave",()=>{r.to(C,{stroke:U,strokeWidth:2,duration:0.2,overwrite:!0}),F.classList.remove("org-node-highlight"),I.classList.remove("org-node-highlight")}),j(O,"click",()=>{r.timeline().to(C,{strokeWidth:5,stroke:M,duration:0.1,ease:"power2.out"}).to(C,{strokeWidth:2,stroke:U,duration:0.4,ease:"power2.inOut"})})})})}K();let W;window.addEventListener("resize",()=>{clearTimeout(W),W=window.setTimeout(K,100)}),G.addEventListener("scroll",K),new MutationObserver(()=>{requestAnimationFrame(K)}).observe(G,{childList:!0,subtree:!0})}function gq(){let G=document.querySelector(".epic-selector-wrapper");if(!G)return;let J=G.querySelector(".epic-selector"),q=G.querySelector("#epic-nav-left"),H=G.querySelector("#epic-nav-right");if(!J||!q||!H)return;let Z=200,Q=()=>{let{scrollLeft:K,scrollWidth:$,clientWidth:L}=J;q.disabled=K<=0,H.disabled=K+L>=$-1};q.addEventListener("click",()=>{J.scrollBy({left:-Z,behavior:"smooth"})}),H.addEventListener("click",()=>{J.scrollBy({left:Z,behavior:"smooth"})}),J.addEventListener("scroll",Q),window.addEventListener("resize",Q),Q()}function r8(){gq(),vq();let G=document.querySelector(".tree-view");if(!G)return;let J=G.querySelector(".tree-list"),q=document.querySelector(".controls-grid")||document.querySelector(".child-expand-controls"),H=new Set,Z=()=>G.querySelectorAll(".tree-node"),Q=()=>document.querySelectorAll(".type-filter");if(G.getAttribute("data-issue-type")===S0.TASK)Z().forEach((z)=>{if(z.getAttribute("data-has-children")==="true"){let U=z.getAttribute("data-id");if(U){H.add(U);let j=z.querySelector(".tree-toggle");if(j){j.classList.add("expanded");let R=j.querySelector(".toggle-icon");if(R)R.textContent="−"}}}});function $(){let z=Q(),M=z.length>0?new Set(Array.from(z).filter((j)=>j.checked).map((j)=>j.value)):null,U=[];if(Z().forEach((j)=>{let R=j.getAttribute("data-parent")||"",B=j.getAttribute("data-type")||"",F=!1;if(!R)F=!0;else if(H.has(R))F=!0;if(F&&M&&!M.has(B))F=!1;let C=j.classList.contains("hidden");if(j.classList.toggle("hidden",!F),F&&C)U.push(j)}),U.length>0)r.fromTo(U,{opacity:0,x:-8},{opacity:1,x:0,duration:0.25,stagger:0.03,ease:"power2.out",clearProps:"transform,opacity"})}if(J)J.addEventListener("click",(z)=>{let U=z.target.closest(".tree-toggle");if(!U)return;z.preventDefault(),z.stopPropagation();let j=U.closest(".tree-node");if(!j)return;let R=j.getAttribute("data-id");if(!R)return;if(H.has(R)){H.delete(R),U.classList.remove("expanded");let B=U.querySelector(".toggle-icon");if(B)B.textContent="+"}else{H.add(R),U.classList.add("expanded");let B=U.querySelector(".toggle-icon");if(B)B.textContent="−"}$()});if(document.addEventListener("change",(z)=>{if(z.target.classList.contains("type-filter"))$()}),q)q.addEventListener("click",(z)=>{let U=z.target.closest("button");if(!U)return;let j=U.id;if(j==="expand-all"||j==="detail-expand")L();else if(j==="collapse-all"||j==="detail-collapse")X();else if(j==="expand-one-level")W();else if(j==="collapse-one-level")Y()});function L(){Z().forEach((z)=>{if(z.getAttribute("data-has-children")==="true"){let U=z.getAttribute("data-id");if(U){H.add(U);let j=z.querySelector(".tree-toggle");if(j){j.classList.add("expanded");let R=j.querySelector(".toggle-icon");if(R)R.textContent="−"}}}}),$()}function X(){H.clear(),Z().forEach((z)=>{let M=z.querySelector(".tree-toggle");if(M){M.classList.remove("expanded");let U=M.querySelector(".toggle-icon");if(U)U.textContent="+"}}),$()}function W(){let z=Z(),M=-1;z.forEach((R)=>{let B=R.getAttribute("data-id");if(B&&H.has(B)){let F=parseInt(R.getAttribute("data-depth")||"0");if(F>M)M=F}});let U=M+1,j=[];z.forEach((R)=>{let B=parseInt(R.getAttribute("data-depth")||"0"),F=R.getAttribute("data-id"),C=R.getAttribute("data-has-children")==="true",I=R.getAttribute("data-parent");if(F&&C&&!H.has(F)){if(B<=U){if(!I||H.has(I))j.push({id:F,element:R})}}}),j.forEach(({id:R,element:B})=>{H.add(R);let F=B.querySelector(".tree-toggle");if(F){F.classList.add("expanded");let C=F.querySelector(".toggle-icon");if(C)C.textContent="−"}}),$()}function Y(){let z=Z(),M=0;H.forEach((U)=>{z.forEach((j)=>{if(j.getAttribute("data-id")===U){let R=parseInt(j.getAttribute("data-depth")||"0");M=Math.max(M,R)}})}),z.forEach((U)=>{let j=parseInt(U.getAttribute("data-depth")||"0"),R=U.getAttribute("data-id"),B=U.getAttribute("data-has-children")==="true";if(R&&B&&j>M-1){H.delete(R);let F=U.querySelector(".tree-toggle");if(F){F.classList.remove("expanded");let C=F.querySelector(".toggle-icon");if(C)C.textContent="+"}}}),$()}$()}function a8(){let G=document.querySelector(".graph-tree-container"),J=document.getElementById("epic-select");if(!G)return;if(J)J.addEventListener("change",()=>{let q=J.value;if(q)window.location.href=`/graph/${q}`;else window.location.href="/graph"});G.addEventListener("click",(q)=>{let H=q.target.closest(".tree-toggle");if(!H)return;let Q=H.closest(".tree-node")?.dataset.id;if(!Q)return;let K=H.classList.toggle("expanded");t8(Q,K)})}function t8(G,J){document.querySelectorAll(`[data-parent="${G}"]`).forEach((q)=>{if(q.classList.toggle("hidden",!J),!J){let H=q.dataset.id;if(H){let Z=q.querySelector(".tree-toggle");if(Z?.classList.contains("expanded"))Z.classList.remove("expanded"),t8(H,!1)}}})}var mq=["status","type","priority"];function uq(G){return mq.includes(G)}function e8(){document.addEventListener("click",(G)=>{let q=G.target.closest(".sort-btn");if(!q)return;if(q.classList.contains("active")){dq(),q.classList.remove("active");return}let H=q.getAttribute("data-sort");if(H&&uq(H))cq(H),nq(q)})}function dq(){window.location.reload()}function cq(G){let J=document.querySelector(".tree-list");if(!J)return;let q=Array.from(J.querySelectorAll(".tree-node")),H=new Map;q.forEach((Q)=>{let K=Q.querySelector(".tree-toggle");if(K&&K.classList.contains("expanded"))H.set(Q.dataset.id,!0)}),q.sort((Q,K)=>lq(Q,K,G)),J.classList.add("sorting-active"),document.querySelectorAll("#expand-all, #collapse-all, #expand-one-level, #collapse-one-level").forEach((Q)=>{Q.disabled=!0}),J.innerHTML="",q.forEach((Q)=>{if(Q.classList.remove("hidden"),J.appendChild(Q),H.has(Q.dataset.id)){let K=Q.querySelector(".tree-toggle");if(K){K.classList.add("expanded");let $=K.querySelector(".toggle-icon");if($)$.textContent="−"}}})}function lq(G,J,q){switch(q){case"status":return pq(G.dataset.status,J.dataset.status);case"type":return oq(G.dataset.type,J.dataset.type);case"priority":return sq(G.dataset.priority,J.dataset.priority);default:return 0}}function pq(G,J){let q=_1[G]??999,H=_1[J]??999;return q-H}function oq(G,J){let q=v1[G]??999,H=v1[J]??999;return q-H}function sq(G,J){let q=parseInt(G)||999,H=parseInt(J)||999;return q-H}function nq(G){document.querySelectorAll(".sort-btn").forEach((q)=>{q.classList.remove("active")}),G.classList.add("active")}document.addEventListener("DOMContentLoaded",()=>{wJ(),TJ(),yJ(),vJ(),mJ(),uJ(),dJ(),r8(),a8(),e8()});
+*/var b8,b0,J1,NJ,p0,Yq,x8,kJ,Uq=function(){return typeof window<"u"},D0={},l0=180/Math.PI,q1=Math.PI/180,e0=Math.atan2,f8=1e8,AJ=/([A-Z])/g,Rq=/(left|right|width|margin|padding|x)/i,Bq=/[\s,\(]\S/,R0={autoAlpha:"opacity,visibility",scale:"scaleX,scaleY",alpha:"opacity"},DJ=function(J,q){return q.set(q.t,q.p,Math.round((q.s+q.c*J)*1e4)/1e4+q.u,q)},Fq=function(J,q){return q.set(q.t,q.p,J===1?q.e:Math.round((q.s+q.c*J)*1e4)/1e4+q.u,q)},Cq=function(J,q){return q.set(q.t,q.p,J?Math.round((q.s+q.c*J)*1e4)/1e4+q.u:q.b,q)},Oq=function(J,q){return q.set(q.t,q.p,J===1?q.e:J?Math.round((q.s+q.c*J)*1e4)/1e4+q.u:q.b,q)},Iq=function(J,q){var H=q.s+q.c*J;q.set(q.t,q.p,~~(H+(H<0?-0.5:0.5))+q.u,q)},m8=function(J,q){return q.set(q.t,q.p,J?q.e:q.b,q)},u8=function(J,q){return q.set(q.t,q.p,J!==1?q.b:q.e,q)},Dq=function(J,q,H){return J.style[q]=H},Eq=function(J,q,H){return J.style.setProperty(q,H)},Sq=function(J,q,H){return J._gsap[q]=H},Vq=function(J,q,H){return J._gsap.scaleX=J._gsap.scaleY=H},Nq=function(J,q,H,Z,Q){var K=J._gsap;K.scaleX=K.scaleY=H,K.renderTransform(Q,K)},kq=function(J,q,H,Z,Q){var K=J._gsap;K[q]=H,K.renderTransform(Q,K)},v="transform",K0=v+"Origin",Aq=function G(J,q){var H=this,Z=this.target,Q=Z.style,K=Z._gsap;if(J in D0&&Q){if(this.tfm=this.tfm||{},J!=="transform")J=R0[J]||J,~J.indexOf(",")?J.split(",").forEach(function($){return H.tfm[$]=I0(Z,$)}):this.tfm[J]=K.x?K[J]:I0(Z,J),J===K0&&(this.tfm.zOrigin=K.zOrigin);else return R0.transform.split(",").forEach(function($){return G.call(H,$,q)});if(this.props.indexOf(v)>=0)return;if(K.svg)this.svgo=Z.getAttribute("data-svg-origin"),this.props.push(K0,q,"");J=v}(Q||q)&&this.props.push(J,q,Q[J])},d8=function(J){if(J.translate)J.removeProperty("translate"),J.removeProperty("scale"),J.removeProperty("rotate")},Pq=function(){var J=this.props,q=this.target,H=q.style,Z=q._gsap,Q,K;for(Q=0;Q<J.length;Q+=3)if(!J[Q+1])J[Q+2]?H[J[Q]]=J[Q+2]:H.removeProperty(J[Q].substr(0,2)==="--"?J[Q]:J[Q].replace(AJ,"-$1").toLowerCase());else if(J[Q+1]===2)q[J[Q]](J[Q+2]);else q[J[Q]]=J[Q+2];if(this.tfm){for(K in this.tfm)Z[K]=this.tfm[K];if(Z.svg)Z.renderTransform(),q.setAttribute("data-svg-origin",this.svgo||"");if(Q=kJ(),(!Q||!Q.isStart)&&!H[v]){if(d8(H),Z.zOrigin&&H[K0])H[K0]+=" "+Z.zOrigin+"px",Z.zOrigin=0,Z.renderTransform();Z.uncache=1}}},c8=function(J,q){var H={target:J,props:[],revert:Pq,save:Aq};return J._gsap||i.core.getCache(J),q&&J.style&&J.nodeType&&q.split(",").forEach(function(Z){return H.save(Z)}),H},l8,EJ=function(J,q){var H=b0.createElementNS?b0.createElementNS((q||"http://www.w3.org/1999/xhtml").replace(/^https/,"http"),J):b0.createElement(J);return H&&H.style?H:b0.createElement(J)},L0=function G(J,q,H){var Z=getComputedStyle(J);return Z[q]||Z.getPropertyValue(q.replace(AJ,"-$1").toLowerCase())||Z.getPropertyValue(q)||!H&&G(J,H1(q)||q,1)||""},w8="O,Moz,ms,Ms,Webkit".split(","),H1=function(J,q,H){var Z=q||p0,Q=Z.style,K=5;if(J in Q&&!H)return J;J=J.charAt(0).toUpperCase()+J.substr(1);while(K--&&!(w8[K]+J in Q));return K<0?null:(K===3?"ms":K>=0?w8[K]:"")+J},SJ=function(){if(Uq()&&window.document)b8=window,b0=b8.document,J1=b0.documentElement,p0=EJ("div")||{style:{}},Yq=EJ("div"),v=H1(v),K0=v+"Origin",p0.style.cssText="border-width:0;line-height:0;position:absolute;padding:0",l8=!!H1("perspective"),kJ=i.core.reverting,NJ=1},T8=function(J){var q=J.ownerSVGElement,H=EJ("svg",q&&q.getAttribute("xmlns")||"http://www.w3.org/2000/svg"),Z=J.cloneNode(!0),Q;Z.style.display="block",H.appendChild(Z),J1.appendChild(H);try{Q=Z.getBBox()}catch(K){}return H.removeChild(Z),J1.removeChild(H),Q},y8=function(J,q){var H=q.length;while(H--)if(J.hasAttribute(q[H]))return J.getAttribute(q[H])},p8=function(J){var q,H;try{q=J.getBBox()}catch(Z){q=T8(J),H=1}return q&&(q.width||q.height)||H||(q=T8(J)),q&&!q.width&&!q.x&&!q.y?{x:+y8(J,["x","cx","x1"])||0,y:+y8(J,["y","cy","y1"])||0,width:0,height:0}:q},o8=function(J){return!!(J.getCTM&&(!J.parentNode||J.ownerSVGElement)&&p8(J))},f0=function(J,q){if(q){var H=J.style,Z;if(q in D0&&q!==K0)q=v;if(H.removeProperty){if(Z=q.substr(0,2),Z==="ms"||q.substr(0,6)==="webkit")q="-"+q;H.removeProperty(Z==="--"?q:q.replace(AJ,"-$1").toLowerCase())}else H.removeAttribute(q)}},x0=function(J,q,H,Z,Q,K){var $=new t(J._pt,q,H,0,1,K?u8:m8);return J._pt=$,$.b=Z,$.e=Q,J._props.push(H),$},h8={deg:1,rad:1,turn:1},bq={grid:1,flex:1},w0=function G(J,q,H,Z){var Q=parseFloat(H)||0,K=(H+"").trim().substr((Q+"").length)||"px",$=p0.style,L=Rq.test(q),X=J.tagName.toLowerCase()==="svg",W=(X?"client":"offset")+(L?"Width":"Height"),Y=100,z=Z==="px",M=Z==="%",U,j,R,B;if(Z===K||!Q||h8[Z]||h8[K])return Q;if(K!=="px"&&!z&&(Q=G(J,q,H,"px")),B=J.getCTM&&o8(J),(M||K==="%")&&(D0[q]||~q.indexOf("adius")))return U=B?J.getBBox()[L?"width":"height"]:J[W],m(M?Q/U*Y:Q/100*U);if($[L?"width":"height"]=Y+(z?K:Z),j=Z!=="rem"&&~q.indexOf("adius")||Z==="em"&&J.appendChild&&!X?J:J.parentNode,B)j=(J.ownerSVGElement||{}).parentNode;if(!j||j===b0||!j.appendChild)j=b0.body;if(R=j._gsap,R&&M&&R.width&&L&&R.time===q0.time&&!R.uncache)return m(Q/R.width*Y);else{if(M&&(q==="height"||q==="width")){var F=J.style[q];J.style[q]=Y+Z,U=J[W],F?J.style[q]=F:f0(J,q)}else(M||K==="%")&&!bq[L0(j,"display")]&&($.position=L0(J,"position")),j===J&&($.position="static"),j.appendChild(p0),U=p0[W],j.removeChild(p0),$.position="absolute";if(L&&M)R=k0(j),R.time=q0.time,R.width=j[W]}return m(z?U*Q/Y:U&&Q?Y/U*Q:0)},I0=function(J,q,H,Z){var Q;if(NJ||SJ(),q in R0&&q!=="transform"){if(q=R0[q],~q.indexOf(","))q=q.split(",")[0]}if(D0[q]&&q!=="transform")Q=F1(J,Z),Q=q!=="transformOrigin"?Q[q]:Q.svg?Q.origin:h1(L0(J,K0))+" "+Q.zOrigin+"px";else if(Q=J.style[q],!Q||Q==="auto"||Z||~(Q+"").indexOf("calc("))Q=y1[q]&&y1[q](J,q,H)||L0(J,q)||XJ(J,q)||(q==="opacity"?1:0);return H&&!~(Q+"").trim().indexOf(" ")?w0(J,q,Q,H)+H:Q},xq=function(J,q,H,Z){if(!H||H==="none"){var Q=H1(q,J,1),K=Q&&L0(J,Q,1);if(K&&K!==H)q=Q,H=K;else if(q==="borderColor")H=L0(J,"borderTopColor")}var $=new t(this._pt,J.style,q,0,1,FJ),L=0,X=0,W,Y,z,M,U,j,R,B,F,C,I,O;if($.b=H,$.e=Z,H+="",Z+="",Z.substring(0,6)==="var(--")Z=L0(J,Z.substring(4,Z.indexOf(")")));if(Z==="auto")j=J.style[q],J.style[q]=Z,Z=L0(J,q)||Z,j?J.style[q]=j:f0(J,q);if(W=[H,Z],zJ(W),H=W[0],Z=W[1],z=H.match(g0)||[],O=Z.match(g0)||[],O.length){while(Y=g0.exec(Z)){if(R=Y[0],F=Z.substring(L,Y.index),U)U=(U+1)%5;else if(F.substr(-5)==="rgba("||F.substr(-5)==="hsla(")U=1;if(R!==(j=z[X++]||"")){if(M=parseFloat(j)||0,I=j.substr((M+"").length),R.charAt(1)==="="&&(R=m0(M,R)+I),B=parseFloat(R),C=R.substr((B+"").length),L=g0.lastIndex-C.length,!C){if(C=C||Z0.units[q]||I,L===Z.length)Z+=C,$.e+=C}if(I!==C)M=w0(J,q,j,C)||0;$._pt={_next:$._pt,p:F||X===1?F:",",s:M,c:B-M,m:U&&U<4||q==="zIndex"?Math.round:0}}}$.c=L<Z.length?Z.substring(L,Z.length):""}else $.r=q==="display"&&Z==="none"?u8:m8;return KJ.test(Z)&&($.e=0),this._pt=$,$},_8={top:"0%",bottom:"100%",left:"0%",right:"100%",center:"50%"},fq=function(J){var q=J.split(" "),H=q[0],Z=q[1]||"50%";if(H==="top"||H==="bottom"||Z==="left"||Z==="right")J=H,H=Z,Z=J;return q[0]=_8[H]||H,q[1]=_8[Z]||Z,q.join(" ")},wq=function(J,q){if(q.tween&&q.tween._time===q.tween._dur){var H=q.t,Z=H.style,Q=q.u,K=H._gsap,$,L,X;if(Q==="all"||Q===!0)Z.cssText="",L=1;else{Q=Q.split(","),X=Q.length;while(--X>-1){if($=Q[X],D0[$])L=1,$=$==="transformOrigin"?K0:v;f0(H,$)}}if(L){if(f0(H,v),K)K.svg&&H.removeAttribute("transform"),Z.scale=Z.rotate=Z.translate="none",F1(H,1),K.uncache=1,d8(Z)}}},y1={clearProps:function(J,q,H,Z,Q){if(Q.data!=="isFromStart"){var K=J._pt=new t(J._pt,q,H,0,0,wq);return K.u=Z,K.pr=-10,K.tween=Q,J._props.push(H),1}}},B1=[1,0,0,1,0,0],s8={},n8=function(J){return J==="matrix(1, 0, 0, 1, 0, 0)"||J==="none"||!J},v8=function(J){var q=L0(J,v);return n8(q)?B1:q.substr(7).match(QJ).map(m)},PJ=function(J,q){var H=J._gsap||k0(J),Z=J.style,Q=v8(J),K,$,L,X;if(H.svg&&J.getAttribute("transform"))return L=J.transform.baseVal.consolidate().matrix,Q=[L.a,L.b,L.c,L.d,L.e,L.f],Q.join(",")==="1,0,0,1,0,0"?B1:Q;else if(Q===B1&&!J.offsetParent&&J!==J1&&!H.svg){if(L=Z.display,Z.display="block",K=J.parentNode,!K||!J.offsetParent&&!J.getBoundingClientRect().width)X=1,$=J.nextElementSibling,J1.appendChild(J);if(Q=v8(J),L?Z.display=L:f0(J,"display"),X)$?K.insertBefore(J,$):K?K.appendChild(J):J1.removeChild(J)}return q&&Q.length>6?[Q[0],Q[1],Q[4],Q[5],Q[12],Q[13]]:Q},VJ=function(J,q,H,Z,Q,K){var $=J._gsap,L=Q||PJ(J,!0),X=$.xOrigin||0,W=$.yOrigin||0,Y=$.xOffset||0,z=$.yOffset||0,M=L[0],U=L[1],j=L[2],R=L[3],B=L[4],F=L[5],C=q.split(" "),I=parseFloat(C[0])||0,O=parseFloat(C[1])||0,S,E,V,D;if(!H)S=p8(J),I=S.x+(~C[0].indexOf("%")?I/100*S.width:I),O=S.y+(~(C[1]||C[0]).indexOf("%")?O/100*S.height:O);else if(L!==B1&&(E=M*R-U*j))V=I*(R/E)+O*(-j/E)+(j*F-R*B)/E,D=I*(-U/E)+O*(M/E)-(M*F-U*B)/E,I=V,O=D;if(Z||Z!==!1&&$.smooth)B=I-X,F=O-W,$.xOffset=Y+(B*M+F*j)-B,$.yOffset=z+(B*U+F*R)-F;else $.xOffset=$.yOffset=0;if($.xOrigin=I,$.yOrigin=O,$.smooth=!!Z,$.origin=q,$.originIsAbsolute=!!H,J.style[K0]="0px 0px",K)x0(K,$,"xOrigin",X,I),x0(K,$,"yOrigin",W,O),x0(K,$,"xOffset",Y,$.xOffset),x0(K,$,"yOffset",z,$.yOffset);J.setAttribute("data-svg-origin",I+" "+O)},F1=function(J,q){var H=J._gsap||new MJ(J);if("x"in H&&!q&&!H.uncache)return H;var Z=J.style,Q=H.scaleX<0,K="px",$="deg",L=getComputedStyle(J),X=L0(J,K0)||"0",W,Y,z,M,U,j,R,B,F,C,I,O,S,E,V,D,N,w,A,P,c,u,y,b,e,$0,Z1,Q1,T0,xJ,B0,y0;if(W=Y=z=j=R=B=F=C=I=0,M=U=1,H.svg=!!(J.getCTM&&o8(J)),L.translate){if(L.translate!=="none"||L.scale!=="none"||L.rotate!=="none")Z[v]=(L.translate!=="none"?"translate3d("+(L.translate+" 0 0").split(" ").slice(0,3).join(", ")+") ":"")+(L.rotate!=="none"?"rotate("+L.rotate+") ":"")+(L.scale!=="none"?"scale("+L.scale.split(" ").join(",")+") ":"")+(L[v]!=="none"?L[v]:"");Z.scale=Z.rotate=Z.translate="none"}if(E=PJ(J,H.svg),H.svg){if(H.uncache)e=J.getBBox(),X=H.xOrigin-e.x+"px "+(H.yOrigin-e.y)+"px",b="";else b=!q&&J.getAttribute("data-svg-origin");VJ(J,b||X,!!b||H.originIsAbsolute,H.smooth!==!1,E)}if(O=H.xOrigin||0,S=H.yOrigin||0,E!==B1){if(w=E[0],A=E[1],P=E[2],c=E[3],W=u=E[4],Y=y=E[5],E.length===6){if(M=Math.sqrt(w*w+A*A),U=Math.sqrt(c*c+P*P),j=w||A?e0(A,w)*l0:0,F=P||c?e0(P,c)*l0+j:0,F&&(U*=Math.abs(Math.cos(F*q1))),H.svg)W-=O-(O*w+S*P),Y-=S-(O*A+S*c)}else{if(y0=E[6],xJ=E[7],Z1=E[8],Q1=E[9],T0=E[10],B0=E[11],W=E[12],Y=E[13],z=E[14],V=e0(y0,T0),R=V*l0,V)D=Math.cos(-V),N=Math.sin(-V),b=u*D+Z1*N,e=y*D+Q1*N,$0=y0*D+T0*N,Z1=u*-N+Z1*D,Q1=y*-N+Q1*D,T0=y0*-N+T0*D,B0=xJ*-N+B0*D,u=b,y=e,y0=$0;if(V=e0(-P,T0),B=V*l0,V)D=Math.cos(-V),N=Math.sin(-V),b=w*D-Z1*N,e=A*D-Q1*N,$0=P*D-T0*N,B0=c*N+B0*D,w=b,A=e,P=$0;if(V=e0(A,w),j=V*l0,V)D=Math.cos(V),N=Math.sin(V),b=w*D+A*N,e=u*D+y*N,A=A*D-w*N,y=y*D-u*N,w=b,u=e;if(R&&Math.abs(R)+Math.abs(j)>359.9)R=j=0,B=180-B;M=m(Math.sqrt(w*w+A*A+P*P)),U=m(Math.sqrt(y*y+y0*y0)),V=e0(u,y),F=Math.abs(V)>0.0002?V*l0:0,I=B0?1/(B0<0?-B0:B0):0}if(H.svg)b=J.getAttribute("transform"),H.forceCSS=J.setAttribute("transform","")||!n8(L0(J,v)),b&&J.setAttribute("transform",b)}if(Math.abs(F)>90&&Math.abs(F)<270)if(Q)M*=-1,F+=j<=0?180:-180,j+=j<=0?180:-180;else U*=-1,F+=F<=0?180:-180;if(q=q||H.uncache,H.x=W-((H.xPercent=W&&(!q&&H.xPercent||(Math.round(J.offsetWidth/2)===Math.round(-W)?-50:0)))?J.offsetWidth*H.xPercent/100:0)+K,H.y=Y-((H.yPercent=Y&&(!q&&H.yPercent||(Math.round(J.offsetHeight/2)===Math.round(-Y)?-50:0)))?J.offsetHeight*H.yPercent/100:0)+K,H.z=z+K,H.scaleX=m(M),H.scaleY=m(U),H.rotation=m(j)+$,H.rotationX=m(R)+$,H.rotationY=m(B)+$,H.skewX=F+$,H.skewY=C+$,H.transformPerspective=I+K,H.zOrigin=parseFloat(X.split(" ")[2])||!q&&H.zOrigin||0)Z[K0]=h1(X);return H.xOffset=H.yOffset=0,H.force3D=Z0.force3D,H.renderTransform=H.svg?yq:l8?i8:Tq,H.uncache=0,H},h1=function(J){return(J=J.split(" "))[0]+" "+J[1]},IJ=function(J,q,H){var Z=s(q);return m(parseFloat(q)+parseFloat(w0(J,"x",H+"px",Z)))+Z},Tq=function(J,q){q.z="0px",q.rotationY=q.rotationX="0deg",q.force3D=0,i8(J,q)},d0="0deg",R1="0px",c0=") ",i8=function(J,q){var H=q||this,Z=H.xPercent,Q=H.yPercent,K=H.x,$=H.y,L=H.z,X=H.rotation,W=H.rotationY,Y=H.rotationX,z=H.skewX,M=H.skewY,U=H.scaleX,j=H.scaleY,R=H.transformPerspective,B=H.force3D,F=H.target,C=H.zOrigin,I="",O=B==="auto"&&J&&J!==1||B===!0;if(C&&(Y!==d0||W!==d0)){var S=parseFloat(W)*q1,E=Math.sin(S),V=Math.cos(S),D;S=parseFloat(Y)*q1,D=Math.cos(S),K=IJ(F,K,E*D*-C),$=IJ(F,$,-Math.sin(S)*-C),L=IJ(F,L,V*D*-C+C)}if(R!==R1)I+="perspective("+R+c0;if(Z||Q)I+="translate("+Z+"%, "+Q+"%) ";if(O||K!==R1||$!==R1||L!==R1)I+=L!==R1||O?"translate3d("+K+", "+$+", "+L+") ":"translate("+K+", "+$+c0;if(X!==d0)I+="rotate("+X+c0;if(W!==d0)I+="rotateY("+W+c0;if(Y!==d0)I+="rotateX("+Y+c0;if(z!==d0||M!==d0)I+="skew("+z+", "+M+c0;if(U!==1||j!==1)I+="scale("+U+", "+j+c0;F.style[v]=I||"translate(0, 0)"},yq=function(J,q){var H=q||this,Z=H.xPercent,Q=H.yPercent,K=H.x,$=H.y,L=H.rotation,X=H.skewX,W=H.skewY,Y=H.scaleX,z=H.scaleY,M=H.target,U=H.xOrigin,j=H.yOrigin,R=H.xOffset,B=H.yOffset,F=H.forceCSS,C=parseFloat(K),I=parseFloat($),O,S,E,V,D;if(L=parseFloat(L),X=parseFloat(X),W=parseFloat(W),W)W=parseFloat(W),X+=W,L+=W;if(L||X){if(L*=q1,X*=q1,O=Math.cos(L)*Y,S=Math.sin(L)*Y,E=Math.sin(L-X)*-z,V=Math.cos(L-X)*z,X){if(W*=q1,D=Math.tan(X-W),D=Math.sqrt(1+D*D),E*=D,V*=D,W)D=Math.tan(W),D=Math.sqrt(1+D*D),O*=D,S*=D}O=m(O),S=m(S),E=m(E),V=m(V)}else O=Y,V=z,S=E=0;if(C&&!~(K+"").indexOf("px")||I&&!~($+"").indexOf("px"))C=w0(M,"x",K,"px"),I=w0(M,"y",$,"px");if(U||j||R||B)C=m(C+U-(U*O+j*E)+R),I=m(I+j-(U*S+j*V)+B);if(Z||Q)D=M.getBBox(),C=m(C+Z/100*D.width),I=m(I+Q/100*D.height);D="matrix("+O+","+S+","+E+","+V+","+C+","+I+")",M.setAttribute("transform",D),F&&(M.style[v]=D)},hq=function(J,q,H,Z,Q){var K=360,$=l(Q),L=parseFloat(Q)*($&&~Q.indexOf("rad")?l0:1),X=L-Z,W=Z+X+"deg",Y,z;if($){if(Y=Q.split("_")[1],Y==="short"){if(X%=K,X!==X%(K/2))X+=X<0?K:-K}if(Y==="cw"&&X<0)X=(X+K*f8)%K-~~(X/K)*K;else if(Y==="ccw"&&X>0)X=(X-K*f8)%K-~~(X/K)*K}return J._pt=z=new t(J._pt,q,H,Z,X,Fq),z.e=W,z.u="deg",J._props.push(H),z},g8=function(J,q){for(var H in q)J[H]=q[H];return J},_q=function(J,q,H){var Z=g8({},H._gsap),Q="perspective,force3D,transformOrigin,svgOrigin",K=H.style,$,L,X,W,Y,z,M,U;if(Z.svg)X=H.getAttribute("transform"),H.setAttribute("transform",""),K[v]=q,$=F1(H,1),f0(H,v),H.setAttribute("transform",X);else X=getComputedStyle(H)[v],K[v]=q,$=F1(H,1),K[v]=X;for(L in D0)if(X=Z[L],W=$[L],X!==W&&Q.indexOf(L)<0)M=s(X),U=s(W),Y=M!==U?w0(H,L,X,U):parseFloat(X),z=parseFloat(W),J._pt=new t(J._pt,$,L,Y,z-Y,DJ),J._pt.u=U||0,J._props.push(L);g8($,Z)};a("padding,margin,Width,Radius",function(G,J){var q="Top",H="Right",Z="Bottom",Q="Left",K=(J<3?[q,H,Z,Q]:[q+Q,q+H,Z+H,Z+Q]).map(function($){return J<2?G+$:"border"+$+G});y1[J>1?"border"+G:G]=function($,L,X,W,Y){var z,M;if(arguments.length<4)return z=K.map(function(U){return I0($,U,X)}),M=z.join(" "),M.split(z[0]).length===5?z[0]:M;z=(W+"").split(" "),M={},K.forEach(function(U,j){return M[U]=z[j]=z[j]||z[(j-1)/2|0]}),$.init(L,M,Y)}});var bJ={name:"css",register:SJ,targetTest:function(J){return J.style&&J.nodeType},init:function(J,q,H,Z,Q){var K=this._props,$=J.style,L=H.vars.startAt,X,W,Y,z,M,U,j,R,B,F,C,I,O,S,E,V,D;NJ||SJ(),this.styles=this.styles||c8(J),V=this.styles.props,this.tween=H;for(j in q){if(j==="autoRound")continue;if(W=q[j],J0[j]&&UJ(j,q,H,Z,J,Q))continue;if(M=typeof W,U=y1[j],M==="function")W=W.call(H,Z,J,Q),M=typeof W;if(M==="string"&&~W.indexOf("random("))W=t0(W);if(U)U(this,J,j,W,H)&&(E=1);else if(j.substr(0,2)==="--"){if(X=(getComputedStyle(J).getPropertyValue(j)+"").trim(),W+="",C0.lastIndex=0,!C0.test(X))R=s(X),B=s(W),B?R!==B&&(X=w0(J,j,X,B)+B):R&&(W+=R);this.add($,"setProperty",X,W,Z,Q,0,0,j),K.push(j),V.push(j,0,$[j])}else if(M!=="undefined"){if(L&&j in L)X=typeof L[j]==="function"?L[j].call(H,Z,J,Q):L[j],l(X)&&~X.indexOf("random(")&&(X=t0(X)),s(X+"")||X==="auto"||(X+=Z0.units[j]||s(I0(J,j))||""),(X+"").charAt(1)==="="&&(X=I0(J,j));else X=I0(J,j);if(z=parseFloat(X),F=M==="string"&&W.charAt(1)==="="&&W.substr(0,2),F&&(W=W.substr(2)),Y=parseFloat(W),j in R0){if(j==="autoAlpha"){if(z===1&&I0(J,"visibility")==="hidden"&&Y)z=0;V.push("visibility",0,$.visibility),x0(this,$,"visibility",z?"inherit":"hidden",Y?"inherit":"hidden",!Y)}if(j!=="scale"&&j!=="transform")j=R0[j],~j.indexOf(",")&&(j=j.split(",")[0])}if(C=j in D0,C){if(this.styles.save(j),D=W,M==="string"&&W.substring(0,6)==="var(--"){if(W=L0(J,W.substring(4,W.indexOf(")"))),W.substring(0,5)==="calc("){var N=J.style.perspective;J.style.perspective=W,W=L0(J,"perspective"),N?J.style.perspective=N:f0(J,"perspective")}Y=parseFloat(W)}if(!I)O=J._gsap,O.renderTransform&&!q.parseTransform||F1(J,q.parseTransform),S=q.smoothOrigin!==!1&&O.smooth,I=this._pt=new t(this._pt,$,v,0,1,O.renderTransform,O,0,-1),I.dep=1;if(j==="scale")this._pt=new t(this._pt,O,"scaleY",O.scaleY,(F?m0(O.scaleY,F+Y):Y)-O.scaleY||0,DJ),this._pt.u=0,K.push("scaleY",j),j+="X";else if(j==="transformOrigin"){if(V.push(K0,0,$[K0]),W=fq(W),O.svg)VJ(J,W,0,S,0,this);else B=parseFloat(W.split(" ")[2])||0,B!==O.zOrigin&&x0(this,O,"zOrigin",O.zOrigin,B),x0(this,$,j,h1(X),h1(W));continue}else if(j==="svgOrigin"){VJ(J,W,1,S,0,this);continue}else if(j in s8){hq(this,O,j,z,F?m0(z,F+W):W);continue}else if(j==="smoothOrigin"){x0(this,O,"smooth",O.smooth,W);continue}else if(j==="force3D"){O[j]=W;continue}else if(j==="transform"){_q(this,W,J);continue}}else if(!(j in $))j=H1(j)||j;if(C||(Y||Y===0)&&(z||z===0)&&!Bq.test(W)&&j in $){if(R=(X+"").substr((z+"").length),Y||(Y=0),B=s(W)||(j in Z0.units?Z0.units[j]:R),R!==B&&(z=w0(J,j,X,B)),this._pt=new t(this._pt,C?O:$,j,z,(F?m0(z,F+Y):Y)-z,!C&&(B==="px"||j==="zIndex")&&q.autoRound!==!1?Iq:DJ),this._pt.u=B||0,C&&D!==W)this._pt.b=X,this._pt.e=D,this._pt.r=Oq;else if(R!==B&&B!=="%")this._pt.b=X,this._pt.r=Cq}else if(!(j in $)){if(j in J)this.add(J,j,X||J[j],F?F+W:W,Z,Q);else if(j!=="parseTransform"){x1(j,W);continue}}else xq.call(this,J,j,X,F?F+W:W);C||(j in $?V.push(j,0,$[j]):typeof J[j]==="function"?V.push(j,2,J[j]()):V.push(j,1,X||J[j])),K.push(j)}}E&&OJ(this)},render:function(J,q){if(q.tween._time||!kJ()){var H=q._pt;while(H)H.r(J,H.d),H=H._next}else q.styles.revert()},get:I0,aliases:R0,getSetter:function(J,q,H){var Z=R0[q];return Z&&Z.indexOf(",")<0&&(q=Z),q in D0&&q!==K0&&(J._gsap.x||I0(J,"x"))?H&&x8===H?q==="scale"?Vq:Sq:(x8=H||{})&&(q==="scale"?Nq:kq):J.style&&!b1(J.style[q])?Dq:~q.indexOf("-")?Eq:T1(J,q)},core:{_removeProperty:f0,_getMatrix:PJ}};i.utils.checkPrefix=H1;i.core.getStyleSaver=c8;(function(G,J,q,H){var Z=a(G+","+J+","+q,function(Q){D0[Q]=1});a(J,function(Q){Z0.units[Q]="deg",s8[Q]=1}),R0[Z[13]]=G+","+J,a(H,function(Q){var K=Q.split(":");R0[K[1]]=Z[K[0]]})})("x,y,z,scale,scaleX,scaleY,xPercent,yPercent","rotation,rotationX,rotationY,skewX,skewY","transform,transformOrigin,svgOrigin,force3D,smoothOrigin,transformPerspective","0:translateX,1:translateY,2:translateZ,8:rotate,8:rotationZ,8:rotateZ,9:rotateX,10:rotateY");a("x,y,z,top,right,bottom,left,width,height,fontSize,padding,margin,perspective",function(G){Z0.units[G]="px"});i.registerPlugin(bJ);var r=i.registerPlugin(bJ)||i,MH=r.core.Tween;function vq(){let G=document.querySelector(".org-tree");if(!G)return;let J=G.querySelector(".org-tree-svg");if(!J)J=document.createElementNS("http://www.w3.org/2000/svg","svg"),J.classList.add("org-tree-svg"),J.style.position="absolute",J.style.top="0",J.style.left="0",J.style.width="100%",J.style.height="100%",J.style.pointerEvents="none",J.style.overflow="visible",G.style.position="relative",G.insertBefore(J,G.firstChild);let q=new Map,H=new Map,Z=!0,Q=[];function K(){J.querySelectorAll("path").forEach((R)=>r.killTweensOf(R)),Q.forEach((R)=>R()),Q=[],J.innerHTML="",q.clear(),H.clear();let z=getComputedStyle(document.documentElement),M=z.getPropertyValue("--connector-color").trim()||z.getPropertyValue("--border-color").trim()||"#585b70",U=[];if(G.querySelectorAll("li").forEach((R)=>{let B=R.querySelector(":scope > a.org-node, :scope > .org-node"),F=R.querySelector(":scope > ul");if(!B||!F)return;let C=F.querySelectorAll(":scope > li");if(C.length===0)return;let I=0,O=R;while(O&&O!==G){if(O.tagName==="UL")I++;O=O.parentElement}let S=B.getBoundingClientRect(),E=G.getBoundingClientRect(),V=S.left+S.width/2-E.left,D=S.bottom-E.top,N=[];C.forEach((w)=>{let A=w.querySelector(":scope > a.org-node, :scope > .org-node");if(!A)return;let P=A.getBoundingClientRect(),c=P.left+P.width/2-E.left,u=P.top-E.top,y=(D+u)/2,b=document.createElementNS("http://www.w3.org/2000/svg","path"),e=`M ${V} ${D} C ${V} ${y}, ${c} ${y}, ${c} ${u}`,$0=document.createElementNS("http://www.w3.org/2000/svg","path");$0.setAttribute("d",e),$0.setAttribute("fill","none"),$0.setAttribute("stroke","transparent"),$0.setAttribute("stroke-width","14"),$0.setAttribute("stroke-linecap","round"),$0.classList.add("connector-hit-area"),J.appendChild($0),b.setAttribute("d",e),b.setAttribute("fill","none"),b.setAttribute("stroke",M),b.setAttribute("stroke-width","2"),b.setAttribute("stroke-linecap","round"),b.style.pointerEvents="none",J.appendChild(b),N.push({path:b,childNode:A,hitArea:$0}),H.set(A,{path:b,parentNode:B}),U.push({path:b,depth:I})}),q.set(B,N)}),Z){if(Z=!1,U.length>0)$(U);L(G)}X()}function $(z){z.sort((M,U)=>M.depth-U.depth),z.forEach(({path:M},U)=>{let j=M.getTotalLength();M.setAttribute("stroke-dasharray",String(j)),M.setAttribute("stroke-dashoffset",String(j)),r.to(M,{strokeDashoffset:0,duration:0.4,delay:U*0.06,ease:"power2.out",onComplete:()=>{M.removeAttribute("stroke-dasharray"),M.removeAttribute("stroke-dashoffset")}})})}function L(z){let M=new Map;z.querySelectorAll("li").forEach((R)=>{let B=R.querySelector(":scope > a.org-node, :scope > .org-node");if(!B)return;let F=0,C=R;while(C&&C!==z){if(C.tagName==="UL")F++;C=C.parentElement}let I=M.get(F);if(I)I.push(B);else M.set(F,[B])});let U=Array.from(M.keys()).sort((R,B)=>R-B),j=0;for(let R of U){let B=M.get(R);if(B.length===0)continue;r.fromTo(B,{opacity:0,y:15,scale:0.95},{opacity:1,y:0,scale:1,duration:0.35,stagger:0.04,delay:j,ease:"power2.out",clearProps:"transform"}),j+=0.1+B.length*0.02}}function X(){let z=getComputedStyle(document.documentElement),M=z.getPropertyValue("--accent").trim()||"#fab387",U=z.getPropertyValue("--connector-color").trim()||z.getPropertyValue("--border-color").trim()||"#585b70";function j(B,F,C){B.addEventListener(F,C),Q.push(()=>B.removeEventListener(F,C))}q.forEach((B,F)=>{j(F,"mouseenter",()=>{B.forEach(({path:C,childNode:I})=>{r.to(C,{stroke:M,strokeWidth:3,duration:0.2,overwrite:!0}),I.classList.add("org-node-highlight")})}),j(F,"mouseleave",()=>{B.forEach(({path:C,childNode:I})=>{r.to(C,{stroke:U,strokeWidth:2,duration:0.2,overwrite:!0}),I.classList.remove("org-node-highlight")})})}),H.forEach(({path:B,parentNode:F},C)=>{j(C,"mouseenter",()=>{r.to(B,{stroke:M,strokeWidth:3,duration:0.2,overwrite:!0}),F.classList.add("org-node-highlight")}),j(C,"mouseleave",()=>{r.to(B,{stroke:U,strokeWidth:2,duration:0.2,overwrite:!0}),F.classList.remove("org-node-highlight")})}),G.querySelectorAll(".org-node").forEach((B)=>{j(B,"mouseenter",()=>{r.to(B,{scale:1.05,boxShadow:"0 4px 12px rgba(0,0,0,0.15)",duration:0.2,ease:"power2.out",overwrite:!0})}),j(B,"mouseleave",()=>{r.to(B,{scale:1,boxShadow:"var(--shadow-sm)",duration:0.2,ease:"power2.inOut",overwrite:!0})}),j(B,"click",()=>{r.timeline().to(B,{scale:0.97,duration:0.08,ease:"power2.in"}).to(B,{scale:1.05,duration:0.15,ease:"power2.out"})})}),q.forEach((B,F)=>{B.forEach(({path:C,childNode:I,hitArea:O})=>{j(O,"mouseenter",()=>{r.to(C,{stroke:M,strokeWidth:3,duration:0.2,overwrite:!0}),F.classList.add("org-node-highlight"),I.classList.add("org-node-highlight")}),j(O,"mouseleave",()=>{r.to(C,{stroke:U,strokeWidth:2,duration:0.2,overwrite:!0}),F.classList.remove("org-node-highlight"),I.classList.remove("org-node-highlight")}),j(O,"click",()=>{r.timeline().to(C,{strokeWidth:5,stroke:M,duration:0.1,ease:"power2.out"}).to(C,{strokeWidth:2,stroke:U,duration:0.4,ease:"power2.inOut"})})})})}K();let W;window.addEventListener("resize",()=>{clearTimeout(W),W=window.setTimeout(K,100)}),G.addEventListener("scroll",K),new MutationObserver(()=>{requestAnimationFrame(K)}).observe(G,{childList:!0,subtree:!0})}function gq(){let G=document.querySelector(".epic-selector-wrapper");if(!G)return;let J=G.querySelector(".epic-selector"),q=G.querySelector("#epic-nav-left"),H=G.querySelector("#epic-nav-right");if(!J||!q||!H)return;let Z=200,Q=()=>{let{scrollLeft:K,scrollWidth:$,clientWidth:L}=J;q.disabled=K<=0,H.disabled=K+L>=$-1};q.addEventListener("click",()=>{J.scrollBy({left:-Z,behavior:"smooth"})}),H.addEventListener("click",()=>{J.scrollBy({left:Z,behavior:"smooth"})}),J.addEventListener("scroll",Q),window.addEventListener("resize",Q),Q()}function r8(){gq(),vq();let G=document.querySelector(".tree-view");if(!G)return;let J=G.querySelector(".tree-list"),q=document.querySelector(".controls-grid")||document.querySelector(".child-expand-controls"),H=new Set,Z=()=>G.querySelectorAll(".tree-node"),Q=()=>document.querySelectorAll(".type-filter");if(G.getAttribute("data-issue-type")===S0.TASK)Z().forEach((z)=>{if(z.getAttribute("data-has-children")==="true"){let U=z.getAttribute("data-id");if(U){H.add(U);let j=z.querySelector(".tree-toggle");if(j){j.classList.add("expanded");let R=j.querySelector(".toggle-icon");if(R)R.textContent="−"}}}});function $(){let z=Q(),M=z.length>0?new Set(Array.from(z).filter((j)=>j.checked).map((j)=>j.value)):null,U=[];if(Z().forEach((j)=>{let R=j.getAttribute("data-parent")||"",B=j.getAttribute("data-type")||"",F=!1;if(!R)F=!0;else if(H.has(R))F=!0;if(F&&M&&!M.has(B))F=!1;let C=j.classList.contains("hidden");if(j.classList.toggle("hidden",!F),F&&C)U.push(j)}),U.length>0)r.fromTo(U,{opacity:0,x:-8},{opacity:1,x:0,duration:0.25,stagger:0.03,ease:"power2.out",clearProps:"transform,opacity"})}if(J)J.addEventListener("click",(z)=>{let U=z.target.closest(".tree-toggle");if(!U)return;z.preventDefault(),z.stopPropagation();let j=U.closest(".tree-node");if(!j)return;let R=j.getAttribute("data-id");if(!R)return;if(H.has(R)){H.delete(R),U.classList.remove("expanded");let B=U.querySelector(".toggle-icon");if(B)B.textContent="+"}else{H.add(R),U.classList.add("expanded");let B=U.querySelector(".toggle-icon");if(B)B.textContent="−"}$()});if(document.addEventListener("change",(z)=>{if(z.target.classList.contains("type-filter"))$()}),q)q.addEventListener("click",(z)=>{let U=z.target.closest("button");if(!U)return;let j=U.id;if(j==="expand-all"||j==="detail-expand")L();else if(j==="collapse-all"||j==="detail-collapse")X();else if(j==="expand-one-level")W();else if(j==="collapse-one-level")Y()});function L(){Z().forEach((z)=>{if(z.getAttribute("data-has-children")==="true"){let U=z.getAttribute("data-id");if(U){H.add(U);let j=z.querySelector(".tree-toggle");if(j){j.classList.add("expanded");let R=j.querySelector(".toggle-icon");if(R)R.textContent="−"}}}}),$()}function X(){H.clear(),Z().forEach((z)=>{let M=z.querySelector(".tree-toggle");if(M){M.classList.remove("expanded");let U=M.querySelector(".toggle-icon");if(U)U.textContent="+"}}),$()}function W(){let z=Z(),M=-1;z.forEach((R)=>{let B=R.getAttribute("data-id");if(B&&H.has(B)){let F=parseInt(R.getAttribute("data-depth")||"0");if(F>M)M=F}});let U=M+1,j=[];z.forEach((R)=>{let B=parseInt(R.getAttribute("data-depth")||"0"),F=R.getAttribute("data-id"),C=R.getAttribute("data-has-children")==="true",I=R.getAttribute("data-parent");if(F&&C&&!H.has(F)){if(B<=U){if(!I||H.has(I))j.push({id:F,element:R})}}}),j.forEach(({id:R,element:B})=>{H.add(R);let F=B.querySelector(".tree-toggle");if(F){F.classList.add("expanded");let C=F.querySelector(".toggle-icon");if(C)C.textContent="−"}}),$()}function Y(){let z=Z(),M=0;H.forEach((U)=>{z.forEach((j)=>{if(j.getAttribute("data-id")===U){let R=parseInt(j.getAttribute("data-depth")||"0");M=Math.max(M,R)}})}),z.forEach((U)=>{let j=parseInt(U.getAttribute("data-depth")||"0"),R=U.getAttribute("data-id"),B=U.getAttribute("data-has-children")==="true";if(R&&B&&j>M-1){H.delete(R);let F=U.querySelector(".tree-toggle");if(F){F.classList.remove("expanded");let C=F.querySelector(".toggle-icon");if(C)C.textContent="+"}}}),$()}$()}function a8(){let G=document.querySelector(".graph-tree-container"),J=document.getElementById("epic-select");if(!G)return;if(J)J.addEventListener("change",()=>{let q=J.value;if(q)window.location.href=`${Lp0()}/graph/${q}`;else window.location.href=`${Lp0()}/graph`});G.addEventListener("click",(q)=>{let H=q.target.closest(".tree-toggle");if(!H)return;let Q=H.closest(".tree-node")?.dataset.id;if(!Q)return;let K=H.classList.toggle("expanded");t8(Q,K)})}function t8(G,J){document.querySelectorAll(`[data-parent="${G}"]`).forEach((q)=>{if(q.classList.toggle("hidden",!J),!J){let H=q.dataset.id;if(H){let Z=q.querySelector(".tree-toggle");if(Z?.classList.contains("expanded"))Z.classList.remove("expanded"),t8(H,!1)}}})}var mq=["status","type","priority"];function uq(G){return mq.includes(G)}function e8(){document.addEventListener("click",(G)=>{let q=G.target.closest(".sort-btn");if(!q)return;if(q.classList.contains("active")){dq(),q.classList.remove("active");return}let H=q.getAttribute("data-sort");if(H&&uq(H))cq(H),nq(q)})}function dq(){window.location.reload()}function cq(G){let J=document.querySelector(".tree-list");if(!J)return;let q=Array.from(J.querySelectorAll(".tree-node")),H=new Map;q.forEach((Q)=>{let K=Q.querySelector(".tree-toggle");if(K&&K.classList.contains("expanded"))H.set(Q.dataset.id,!0)}),q.sort((Q,K)=>lq(Q,K,G)),J.classList.add("sorting-active"),document.querySelectorAll("#expand-all, #collapse-all, #expand-one-level, #collapse-one-level").forEach((Q)=>{Q.disabled=!0}),J.innerHTML="",q.forEach((Q)=>{if(Q.classList.remove("hidden"),J.appendChild(Q),H.has(Q.dataset.id)){let K=Q.querySelector(".tree-toggle");if(K){K.classList.add("expanded");let $=K.querySelector(".toggle-icon");if($)$.textContent="−"}}})}function lq(G,J,q){switch(q){case"status":return pq(G.dataset.status,J.dataset.status);case"type":return oq(G.dataset.type,J.dataset.type);case"priority":return sq(G.dataset.priority,J.dataset.priority);default:return 0}}function pq(G,J){let q=_1[G]??999,H=_1[J]??999;return q-H}function oq(G,J){let q=v1[G]??999,H=v1[J]??999;return q-H}function sq(G,J){let q=parseInt(G)||999,H=parseInt(J)||999;return q-H}function nq(G){document.querySelectorAll(".sort-btn").forEach((q)=>{q.classList.remove("active")}),G.classList.add("active")}var LD0=1500,LS0=["issue_created","issue_updated","issue_removed","dependency_added","dependency_removed","resync"];function Lv0(){let G=document.querySelector(".board"),J=document.querySelector(".tree-list"),q=document.querySelector(".dashboard"),W=document.querySelector(".issue-links");if(!G&&!J&&!q&&!W)return;if(typeof EventSource>"u")return;let H=new EventSource(`${Lp0()}/api/events`),Z=null,Q=()=>{if(Z)clearTimeout(Z);Z=setTimeout(()=>{let K=document.activeElement,$=K instanceof HTMLInputElement||K instanceof HTMLTextAreaElement;if(document.querySelector(".dragging")||$){Q();return}window.location.reload()},LD0)};H.addEventListener("status_changed",(K)=>{let $=JSON.parse(K.data);if(q){Q();return}if(G&&!Lm0($))Q();if(J)Lt0($);if(W)Lu0($)}),LS0.forEach((K)=>{H.addEventListener(K,Q)}),window.addEventListener("beforeunload",()=>H.close())}function Lm0(G){let J=document.querySelector(`.issue-card[data-id="${CSS.escape(G.id)}"]`),q=document.querySelector(`.column-content[data-status="${CSS.escape(G.to)}"]`);if(!J)return q===null;if(!q)J.remove();else if(J.parentElement!==q)q.appendChild(J);return Lc0(),!0}function Lc0(){document.querySelectorAll(".board-column").forEach((G)=>{let J=G.querySelector(".column-header .count");if(J)J.textContent=String(G.querySelectorAll(".issue-card").length)})}function Lt0(G){let J=document.querySelector(`.tree-node[data-id="${CSS.escape(G.id)}"]`);if(!J)return;J.dataset.status=G.to;let q=J.querySelector(".tree-status");if(q)q.classList.remove(`status-${G.from}`),q.classList.add(`status-${G.to}`)}function Lu0(G){document.querySelectorAll(`.linked-issue[data-id="${CSS.escape(G.id)}"] .status-badge`).forEach((J)=>{J.className=`status-badge ${G.to.replace(/_/g,"-")}`,J.textContent=G.to.replace(/_/g," ")})}function Lb0(){let G=document.getElementById("bulk-bar");if(!G)return;let J=document.getElementById("bulk-count"),q=document.getElementById("bulk-status"),H=document.getElementById("bulk-priority"),W=document.getElementById("bulk-assignee"),Z=document.getElementById("bulk-label"),Q=document.getElementById("bulk-apply"),K=()=>Array.from(document.querySelectorAll(".bulk-select:checked")).map(($)=>$.dataset.id).filter(($)=>!!$),V=()=>{let $=K();J.textContent=String($.length),G.hidden=$.length===0};document.addEventListener("change",($)=>{if($.target.classList.contains("bulk-select"))V()}),document.getElementById("bulk-clear")?.addEventListener("click",()=>{document.querySelectorAll(".bulk-select:checked").forEach(($)=>{$.checked=!1}),V()}),Q.addEventListener("click",async()=>{let $={};if(q.value)$.status=q.value;if(H.value)$.priority=parseInt(H.value,10);if(W.value.trim())$.assignee=W.value.trim();if(Z.value.trim())$.add_labels=[Z.value.trim()];if(Object.keys($).length===0){_J.show({message:"Choose a change to apply",type:"warning",duration:3000});return}Q.disabled=!0;try{let X=await fetch(`${Lp0()}/api/issues/bulk`,{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({ids:K(),update:$})});if(!X.ok)throw Error(`HTTP ${X.status}`);let Y=await X.json();if(Y.failed>0){let B=Y.results.filter((z)=>!z.ok).map((z)=>`${z.id}: ${z.error}`).join(", ");_J.show({message:`Updated ${Y.updated}, failed ${Y.failed} (${B})`,type:"warning"})}else window.location.reload()}catch(X){C1(X,"Bulk update failed")}finally{Q.disabled=!1}})}document.addEventListener("DOMContentLoaded",()=>{wJ(),TJ(),yJ(),vJ(),mJ(),uJ(),dJ(),r8(),a8(),e8(),Lv0(),Lb0()});
@@ -37,3 +37,8 @@ export const TYPE_ORDER: Record<IssueType, number> = {
     [ISSUE_TYPE.TASK]: 3,
     [ISSUE_TYPE.CHORE]: 4,
 };
+
+// URL prefix of the current project: empty, or /p/<name> when nacre serves several
+export function basePath(): string {
+    return document.querySelector<HTMLMetaElement>('meta[name="nacre-base"]')?.content ?? '';
+}
@@ -8,6 +8,8 @@ import { initNavigation } from './modules/navigation';
 import { initGraph } from './modules/graph';
 import { initDependencyGraph } from './modules/dependency-graph';
 import { initSorting } from './modules/sorting';
+import { initLiveUpdates } from './modules/live';
+import { initBulkActions } from './modules/bulk';
 
 document.addEventListener('DOMContentLoaded', () => {
     initTheme();
@@ -20,4 +22,6 @@ document.addEventListener('DOMContentLoaded', () => {
     initGraph();
     initDependencyGraph();
     initSorting();
+    initLiveUpdates();
+    initBulkActions();
 });
\ No newline at end of file
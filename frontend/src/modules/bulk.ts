@@ -0,0 +1,88 @@
+import { basePath } from '../constants';
+import { handleError, toast } from './toast';
+
+interface BulkItemResult {
+    id: string;
+    ok: boolean;
+    error?: string;
+}
+
+interface BulkUpdateResponse {
+    updated: number;
+    failed: number;
+    results: BulkItemResult[];
+}
+
+export function initBulkActions() {
+    const bar = document.getElementById('bulk-bar');
+    if (!bar) return;
+
+    const count = document.getElementById('bulk-count') as HTMLElement;
+    const status = document.getElementById('bulk-status') as HTMLSelectElement;
+    const priority = document.getElementById('bulk-priority') as HTMLSelectElement;
+    const assignee = document.getElementById('bulk-assignee') as HTMLInputElement;
+    const label = document.getElementById('bulk-label') as HTMLInputElement;
+    const apply = document.getElementById('bulk-apply') as HTMLButtonElement;
+
+    const selected = () =>
+        Array.from(document.querySelectorAll<HTMLInputElement>('.bulk-select:checked'))
+            .map((box) => box.dataset.id)
+            .filter((id): id is string => !!id);
+
+    const refresh = () => {
+        const ids = selected();
+        count.textContent = String(ids.length);
+        bar.hidden = ids.length === 0;
+    };
+
+    document.addEventListener('change', (e) => {
+        if ((e.target as HTMLElement).classList.contains('bulk-select')) refresh();
+    });
+
+    document.getElementById('bulk-clear')?.addEventListener('click', () => {
+        document.querySelectorAll<HTMLInputElement>('.bulk-select:checked').forEach((box) => {
+            box.checked = false;
+        });
+        refresh();
+    });
+
+    apply.addEventListener('click', async () => {
+        const update: Record<string, unknown> = {};
+        if (status.value) update.status = status.value;
+        if (priority.value) update.priority = parseInt(priority.value, 10);
+        if (assignee.value.trim()) update.assignee = assignee.value.trim();
+        if (label.value.trim()) update.add_labels = [label.value.trim()];
+        if (Object.keys(update).length === 0) {
+            toast.show({ message: 'Choose a change to apply', type: 'warning', duration: 3000 });
+            return;
+        }
+
+        apply.disabled = true;
+        try {
+            const res = await fetch(`${basePath()}/api/issues/bulk`, {
+                method: 'POST',
+                headers: { 'Content-Type': 'application/json' },
+                body: JSON.stringify({ ids: selected(), update }),
+            });
+            if (!res.ok) throw new Error(`HTTP ${res.status}`);
+
+            const result = (await res.json()) as BulkUpdateResponse;
+            if (result.failed > 0) {
+                const failures = result.results
+                    .filter((r) => !r.ok)
+                    .map((r) => `${r.id}: ${r.error}`)
+                    .join(', ');
+                toast.show({
+                    message: `Updated ${result.updated}, failed ${result.failed} (${failures})`,
+                    type: 'warning',
+                });
+            } else {
+                window.location.reload();
+            }
+        } catch (err) {
+            handleError(err, 'Bulk update failed');
+        } finally {
+            apply.disabled = false;
+        }
+    });
+}
@@ -1,3 +1,5 @@
+import { basePath } from '../constants';
+
 /**
  * Dependency graph tree view - expand/collapse and epic selection
  */
@@ -13,9 +15,9 @@ export function initDependencyGraph() {
         epicSelect.addEventListener('change', () => {
             const epicId = epicSelect.value;
             if (epicId) {
-                window.location.href = `/graph/${epicId}`;
+                window.location.href = `${basePath()}/graph/${epicId}`;
             } else {
-                window.location.href = '/graph';
+                window.location.href = `${basePath()}/graph`;
             }
         });
     }
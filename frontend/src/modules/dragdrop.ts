@@ -1,3 +1,4 @@
+import { basePath } from '../constants';
 import { handleError, handleNetworkError } from './toast';
 
 export function initDragAndDrop() {
@@ -40,7 +41,7 @@ export function initDragAndDrop() {
 
                 if (id && apiStatus) {
                     const updateStatus = async () => {
-                        const res = await fetch(`/api/issues/${id}`, {
+                        const res = await fetch(`${basePath()}/api/issues/${id}`, {
                             method: 'POST',
                             headers: {'Content-Type': 'application/json'},
                             body: JSON.stringify({status: apiStatus})
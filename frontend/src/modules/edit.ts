@@ -1,3 +1,4 @@
+import { basePath } from '../constants';
 import { handleError, handleNetworkError } from './toast';
 
 export function initInlineEdit() {
@@ -33,7 +34,7 @@ export function initInlineEdit() {
 
             if (newTitle && newTitle !== currentTitle && id) {
                 const updateTitle = async () => {
-                    const res = await fetch(`/api/issues/${id}`, {
+                    const res = await fetch(`${basePath()}/api/issues/${id}`, {
                         method: 'POST',
                         headers: {'Content-Type': 'application/json'},
                         body: JSON.stringify({title: newTitle})
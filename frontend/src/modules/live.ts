@@ -0,0 +1,108 @@
+import { basePath } from '../constants';
+
+// Live updates from the /api/events Server-Sent Events stream.
+// Status changes are patched in place (board cards, tree nodes and the
+// linked-issue badges on the detail page); anything structural (new or
+// removed issues, dependency edits) triggers a debounced reload of the page.
+
+interface StatusChangedEvent {
+    id: string;
+    from: string;
+    to: string;
+}
+
+const RELOAD_DELAY_MS = 1500;
+
+const STRUCTURAL_EVENTS = [
+    'issue_created',
+    'issue_updated',
+    'issue_removed',
+    'dependency_added',
+    'dependency_removed',
+    'resync',
+];
+
+export function initLiveUpdates() {
+    const board = document.querySelector('.board');
+    const tree = document.querySelector('.tree-list');
+    const dashboard = document.querySelector('.dashboard');
+    const links = document.querySelector('.issue-links');
+
+    if (!board && !tree && !dashboard && !links) return;
+    if (typeof EventSource === 'undefined') return;
+
+    const source = new EventSource(`${basePath()}/api/events`);
+    let reloadTimer: ReturnType<typeof setTimeout> | null = null;
+
+    const scheduleReload = () => {
+        if (reloadTimer) clearTimeout(reloadTimer);
+        reloadTimer = setTimeout(() => {
+            // Do not yank the page away mid-drag or while the user is typing
+            const active = document.activeElement;
+            const typing = active instanceof HTMLInputElement || active instanceof HTMLTextAreaElement;
+            if (document.querySelector('.dragging') || typing) {
+                scheduleReload();
+                return;
+            }
+            window.location.reload();
+        }, RELOAD_DELAY_MS);
+    };
+
+    source.addEventListener('status_changed', (e) => {
+        const change = JSON.parse((e as MessageEvent).data) as StatusChangedEvent;
+        if (dashboard) {
+            scheduleReload();
+            return;
+        }
+        if (board && !moveBoardCard(change)) scheduleReload();
+        if (tree) updateTreeNode(change);
+        if (links) updateLinkedIssues(change);
+    });
+
+    STRUCTURAL_EVENTS.forEach((name) => {
+        source.addEventListener(name, scheduleReload);
+    });
+
+    window.addEventListener('beforeunload', () => source.close());
+}
+
+/** Move a card to its new column; returns false if the page needs a reload */
+function moveBoardCard(change: StatusChangedEvent): boolean {
+    const card = document.querySelector(`.issue-card[data-id="${CSS.escape(change.id)}"]`);
+    const column = document.querySelector(`.column-content[data-status="${CSS.escape(change.to)}"]`);
+    if (!card) return column === null;
+    if (!column) {
+        card.remove();
+    } else if (card.parentElement !== column) {
+        column.appendChild(card);
+    }
+    updateColumnCounts();
+    return true;
+}
+
+function updateColumnCounts() {
+    document.querySelectorAll('.board-column').forEach((column) => {
+        const count = column.querySelector('.column-header .count');
+        if (count) {
+            count.textContent = String(column.querySelectorAll('.issue-card').length);
+        }
+    });
+}
+
+function updateTreeNode(change: StatusChangedEvent) {
+    const node = document.querySelector(`.tree-node[data-id="${CSS.escape(change.id)}"]`) as HTMLElement | null;
+    if (!node) return;
+    node.dataset.status = change.to;
+    const indicator = node.querySelector('.tree-status');
+    if (indicator) {
+        indicator.classList.remove(`status-${change.from}`);
+        indicator.classList.add(`status-${change.to}`);
+    }
+}
+
+function updateLinkedIssues(change: StatusChangedEvent) {
+    document.querySelectorAll(`.linked-issue[data-id="${CSS.escape(change.id)}"] .status-badge`).forEach((badge) => {
+        badge.className = `status-badge ${change.to.replace(/_/g, '-')}`;
+        badge.textContent = change.to.replace(/_/g, ' ');
+    });
+}
@@ -3,8 +3,10 @@ const LIGHT_THEMES = ['nacre-light', 'catppuccin-latte'];
 
 (function() {
     const stored = localStorage.getItem('theme');
+    // Default theme from nacre.toml, if any
+    const configured = document.querySelector<HTMLMetaElement>('meta[name="nacre-theme"]')?.content;
     const prefersDark = window.matchMedia('(prefers-color-scheme: dark)').matches;
-    const theme = stored || (prefersDark ? 'nacre-dark' : 'nacre-light');
+    const theme = stored || configured || (prefersDark ? 'nacre-dark' : 'nacre-light');
     document.documentElement.setAttribute('data-theme', theme);
     // Set syntax theme based on light/dark
     const syntaxTheme = LIGHT_THEMES.includes(theme) ? 'light' : 'dark';
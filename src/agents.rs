@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::beads::{IssueUpdate, Status};
+
+/// How often the reaper looks for expired sessions
+const REAP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Label put on issues whose agent stopped sending heartbeats
+pub const STALE_LABEL: &str = "stale-agent";
+
+/// What happens to the issue of an agent whose heartbeat expired
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExpiryAction {
+    /// Only show the session as stale on `/agents`
+    #[default]
+    None,
+    /// Add the `stale-agent` label, leaving the issue in progress
+    Flag,
+    /// Put the issue back to open and unassign it
+    Release,
+}
+
+impl ExpiryAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Flag => "flag",
+            Self::Release => "release",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(Self::None),
+            "flag" => Some(Self::Flag),
+            "release" => Some(Self::Release),
+            _ => None,
+        }
+    }
+}
+
+/// The last heartbeat of one agent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentSession {
+    pub name: String,
+    /// Issue the agent said it is working on
+    pub issue_id: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub last_seen: OffsetDateTime,
+}
+
+/// Agent sessions keyed by name, kept in memory and mirrored to a JSON file
+/// so a restart does not forget who was working on what
+pub struct AgentRegistry {
+    sessions: RwLock<HashMap<String, AgentSession>>,
+    path: Option<PathBuf>,
+    /// One save at a time, as every save goes through the same temporary file
+    saving: tokio::sync::Mutex<()>,
+    pub stale_after: Duration,
+    pub on_expiry: ExpiryAction,
+}
+
+impl Default for AgentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentRegistry {
+    /// In-memory registry: sessions expire after five minutes and nothing
+    /// happens to their issues
+    pub fn new() -> Self {
+        Self {
+            sessions: RwLock::new(HashMap::new()),
+            path: None,
+            saving: tokio::sync::Mutex::new(()),
+            stale_after: Duration::from_secs(300),
+            on_expiry: ExpiryAction::None,
+        }
+    }
+
+    /// Persist sessions to `path`, loading the ones already there
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        match std::fs::read_to_string(&path) {
+            Ok(content) => match serde_json::from_str::<Vec<AgentSession>>(&content) {
+                Ok(sessions) => {
+                    let sessions = sessions.into_iter().map(|s| (s.name.clone(), s)).collect();
+                    self.sessions = RwLock::new(sessions);
+                }
+                Err(e) => tracing::warn!("Ignoring unreadable {}: {}", path.display(), e),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => tracing::warn!("Could not read {}: {}", path.display(), e),
+        }
+        self.path = Some(path);
+        self
+    }
+
+    pub fn with_stale_after(mut self, stale_after: Duration) -> Self {
+        self.stale_after = stale_after;
+        self
+    }
+
+    pub fn with_expiry_action(mut self, action: ExpiryAction) -> Self {
+        self.on_expiry = action;
+        self
+    }
+
+    /// Record that `name` is alive, now working on `issue_id` if given;
+    /// without one the session keeps the issue it had
+    pub async fn heartbeat(&self, name: &str, issue_id: Option<String>) -> AgentSession {
+        self.touch(name, |session| {
+            if let Some(issue_id) = issue_id {
+                session.issue_id = Some(issue_id);
+            }
+        })
+        .await
+    }
+
+    /// Record that `name` is alive and no longer working on an issue
+    pub async fn finish(&self, name: &str) -> AgentSession {
+        self.touch(name, |session| session.issue_id = None).await
+    }
+
+    async fn touch(&self, name: &str, update: impl FnOnce(&mut AgentSession)) -> AgentSession {
+        let now = OffsetDateTime::now_utc();
+        let session = {
+            let mut sessions = self.sessions.write().unwrap();
+            let session = sessions
+                .entry(name.to_string())
+                .and_modify(|s| {
+                    // A session that went stale starts over
+                    if !self.is_live_at(s, now) {
+                        s.started_at = now;
+                    }
+                })
+                .or_insert_with(|| AgentSession {
+                    name: name.to_string(),
+                    issue_id: None,
+                    started_at: now,
+                    last_seen: now,
+                });
+            update(session);
+            session.last_seen = now;
+            session.clone()
+        };
+        self.save().await;
+        session
+    }
+
+    /// Drop a session; returns whether it existed
+    pub async fn remove(&self, name: &str) -> bool {
+        let removed = self.sessions.write().unwrap().remove(name).is_some();
+        if removed {
+            self.save().await;
+        }
+        removed
+    }
+
+    /// Every session, most recently seen first
+    pub fn sessions(&self) -> Vec<AgentSession> {
+        let mut sessions: Vec<AgentSession> =
+            self.sessions.read().unwrap().values().cloned().collect();
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.last_seen));
+        sessions
+    }
+
+    pub fn is_live(&self, session: &AgentSession) -> bool {
+        self.is_live_at(session, OffsetDateTime::now_utc())
+    }
+
+    fn is_live_at(&self, session: &AgentSession, now: OffsetDateTime) -> bool {
+        now - session.last_seen <= self.stale_after
+    }
+
+    async fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        // Read the sessions only once it is our turn, so the last save to
+        // finish writes the newest state
+        let _saving = self.saving.lock().await;
+        let sessions = self.sessions();
+        let target = path.clone();
+        let written = tokio::task::spawn_blocking(move || write_atomically(&target, &sessions))
+            .await
+            .map_err(std::io::Error::other)
+            .and_then(|result| result);
+        if let Err(e) = written {
+            tracing::warn!("Could not save agent sessions to {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn write_atomically(path: &Path, sessions: &[AgentSession]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(sessions)?)?;
+    std::fs::rename(tmp, path)
+}
+
+/// Apply the registry's expiry action to issues still held by stale agents.
+///
+/// Only issues that are in progress and assigned to the stale agent are
+/// touched, so an issue someone else picked up in the meantime is safe.
+/// Returns the IDs of the issues that were changed.
+pub async fn reap_expired(state: &crate::AppState) -> crate::AppResult<Vec<String>> {
+    let action = state.agents.on_expiry;
+    if action == ExpiryAction::None {
+        return Ok(Vec::new());
+    }
+
+    let _guard = state.writes.lock().await;
+    let mut changed = Vec::new();
+    for session in state.agents.sessions() {
+        let Some(issue_id) = &session.issue_id else {
+            continue;
+        };
+        if state.agents.is_live(&session) {
+            continue;
+        }
+        let issue = match state.backend.get_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(crate::beads::BeadsError::NotFound(_)) => continue,
+            Err(e) => return Err(e.into()),
+        };
+        let held = issue.status == Status::InProgress
+            && issue.assignee.as_deref() == Some(session.name.as_str());
+        let flagged = issue
+            .labels
+            .as_ref()
+            .is_some_and(|labels| labels.iter().any(|l| l == STALE_LABEL));
+        if !held || (action == ExpiryAction::Flag && flagged) {
+            continue;
+        }
+
+        let update = match action {
+            ExpiryAction::Flag => IssueUpdate {
+                add_labels: vec![STALE_LABEL.to_string()],
+                ..Default::default()
+            },
+            _ => IssueUpdate {
+                status: Some(Status::Open),
+                assignee: Some(String::new()),
+                ..Default::default()
+            },
+        };
+        state.backend.update_issue(issue_id, update).await?;
+        tracing::warn!(
+            "Agent {} went quiet, {:?} applied to {}",
+            session.name,
+            action,
+            issue_id
+        );
+        changed.push(issue_id.clone());
+    }
+
+    if !changed.is_empty() {
+        state.data_changed();
+    }
+    Ok(changed)
+}
+
+/// Periodically run `reap_expired`, if an expiry action is configured
+pub fn spawn_reaper(state: crate::SharedAppState) -> Option<tokio::task::JoinHandle<()>> {
+    if state.agents.on_expiry == ExpiryAction::None {
+        return None;
+    }
+    Some(tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(REAP_INTERVAL).await;
+            if let Err(e) = reap_expired(&state).await {
+                tracing::warn!(error = %e, "Failed to handle expired agent sessions");
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sessions_persist_and_go_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".nacre").join("agents.json");
+
+        let registry = AgentRegistry::new().with_path(&path);
+        registry
+            .heartbeat("agent-a", Some("nc-1".to_string()))
+            .await;
+        registry.heartbeat("agent-b", None).await;
+        assert!(registry.remove("agent-b").await);
+
+        let reloaded = AgentRegistry::new()
+            .with_path(&path)
+            .with_stale_after(Duration::ZERO);
+        let sessions = reloaded.sessions();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].issue_id.as_deref(), Some("nc-1"));
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(!reloaded.is_live(&sessions[0]));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_heartbeats_save_every_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("agents.json");
+        let registry = std::sync::Arc::new(AgentRegistry::new().with_path(&path));
+
+        let mut beats = tokio::task::JoinSet::new();
+        for i in 0..8 {
+            let registry = registry.clone();
+            beats.spawn(async move {
+                for _ in 0..10 {
+                    registry.heartbeat(&format!("agent-{}", i), None).await;
+                }
+            });
+        }
+        beats.join_all().await;
+
+        let reloaded = AgentRegistry::new().with_path(&path);
+        assert_eq!(reloaded.sessions().len(), 8);
+    }
+}
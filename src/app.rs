@@ -2,13 +2,34 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use axum::Router;
-use axum::routing::{get, post};
+use axum::routing::{delete, get, post};
 use tower_http::compression::CompressionLayer;
 use tower_http::trace::TraceLayer;
 use tracing::Span;
 
+use crate::agents::AgentRegistry;
 use crate::beads;
+use crate::config::Config;
+use crate::events::EventHub;
 use crate::handlers;
+use crate::snapshot::{ProjectSnapshot, SnapshotCache};
+
+/// Every project served by a multi-project app, in the order given
+pub struct Projects {
+    states: Vec<SharedAppState>,
+}
+
+impl Projects {
+    pub fn new(states: Vec<SharedAppState>) -> Self {
+        Self { states }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SharedAppState> {
+        self.states.iter()
+    }
+}
+
+pub type SharedProjects = Arc<Projects>;
 
 /// Format latency in human-readable units
 fn format_latency(duration: std::time::Duration) -> String {
@@ -23,26 +44,97 @@ fn format_latency(duration: std::time::Duration) -> String {
 }
 
 pub struct AppState {
-    pub client: beads::Client,
+    pub backend: Arc<dyn beads::BeadsBackend>,
+    pub snapshots: SnapshotCache,
+    pub events: EventHub,
+    /// Held across check-then-write sequences, such as an `If-Match` update,
+    /// so two requests through nacre cannot interleave
+    pub writes: tokio::sync::Mutex<()>,
+    /// Heartbeats of agents working on issues
+    pub agents: AgentRegistry,
+    /// Version and capabilities of `bd`, when the backend runs it
+    pub bd: Option<beads::BdInfo>,
     pub project_name: String,
     pub app_version: String,
+    /// Prefix of every URL of this project: empty, or `/p/<name>` when
+    /// several projects are served
+    pub base_path: String,
+    /// The project's `nacre.toml`
+    pub config: Config,
 }
 
 pub type SharedAppState = Arc<AppState>;
 
 impl AppState {
+    /// Create state backed by the `bd` CLI
     pub fn new() -> Self {
+        Self::with_backend(Arc::new(beads::Client::new()))
+    }
+
+    pub fn with_backend(backend: Arc<dyn beads::BeadsBackend>) -> Self {
         let project_name = std::env::current_dir()
             .ok()
             .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
             .unwrap_or_else(|| "Nacre".to_string());
 
         Self {
-            client: beads::Client::new(),
+            backend,
+            snapshots: SnapshotCache::new(),
+            events: EventHub::new(),
+            writes: tokio::sync::Mutex::new(()),
+            agents: AgentRegistry::new(),
+            bd: None,
             project_name,
             app_version: env!("CARGO_PKG_VERSION").to_string(),
+            base_path: String::new(),
+            config: Config::default(),
         }
     }
+
+    /// Use the settings of a loaded `nacre.toml`
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Serve this state as one of several projects, under `/p/<name>`
+    pub fn with_project(mut self, name: impl Into<String>) -> Self {
+        self.project_name = name.into();
+        self.base_path = format!("/p/{}", self.project_name);
+        self
+    }
+
+    /// Record what probing `bd` found, shown on the about page
+    pub fn with_bd_info(mut self, info: beads::BdInfo) -> Self {
+        self.bd = Some(info);
+        self
+    }
+
+    /// Track agent sessions with `registry`, e.g. one persisted to disk
+    pub fn with_agents(mut self, registry: AgentRegistry) -> Self {
+        self.agents = registry;
+        self
+    }
+
+    /// Optional `bd` features available; everything when `bd` was not probed
+    pub fn capabilities(&self) -> beads::Capabilities {
+        self.bd
+            .as_ref()
+            .map(|info| info.capabilities)
+            .unwrap_or_default()
+    }
+
+    /// Current project snapshot, rebuilt only when the beads data changed
+    pub async fn snapshot(&self) -> crate::AppResult<Arc<ProjectSnapshot>> {
+        Ok(self.snapshots.get(self.backend.as_ref()).await?)
+    }
+
+    /// Call after writing through the backend: drops the cached snapshot and
+    /// lets the event watcher publish the change right away
+    pub fn data_changed(&self) {
+        self.snapshots.invalidate();
+        self.events.wake();
+    }
 }
 
 impl Default for AppState {
@@ -51,11 +143,14 @@ impl Default for AppState {
     }
 }
 
-pub fn create_app(state: SharedAppState) -> Router {
+/// Pages and API of one project, relative to where it is mounted
+fn project_routes() -> Router<SharedAppState> {
     Router::new()
         .route("/", get(handlers::landing))
         .route("/tasks", get(handlers::tasks_list))
         .route("/tasks/new", get(handlers::new_task_form))
+        .route("/ready", get(handlers::ready))
+        .route("/agents", get(handlers::agents_page))
         .route("/tasks/:id", get(handlers::task_detail))
         .route("/tasks/:id/edit", get(handlers::edit_task))
         .route("/board", get(handlers::board))
@@ -65,10 +160,43 @@ pub fn create_app(state: SharedAppState) -> Router {
         .route("/palette", get(handlers::palette))
         .route("/prds", get(handlers::prds_list))
         .route("/prds/:filename", get(handlers::prd_view))
+        .route("/about", get(handlers::about))
+        .route("/trash", get(handlers::trash))
         .route("/api/issues", get(handlers::list_tasks))
-        .route("/api/issues/:id", post(handlers::update_task))
+        .route("/api/ready", get(handlers::ready_api))
+        .route("/api/rpc", post(handlers::rpc))
+        .route("/api/agents/claim", post(handlers::claim))
+        .route("/api/agents/release", post(handlers::release))
+        .route("/api/agents/complete", post(handlers::complete))
+        .route("/api/agents/:name/heartbeat", post(handlers::heartbeat))
+        .route("/api/agents/:name", delete(handlers::forget_agent))
+        .route("/api/issues/bulk", post(handlers::bulk_update))
+        .route(
+            "/api/issues/:id",
+            get(handlers::get_task)
+                .post(handlers::update_task)
+                .delete(handlers::delete_task),
+        )
+        .route("/api/issues/:id/restore", post(handlers::restore_task))
+        .route("/api/issues/:id/activity", get(handlers::issue_activity))
+        .route("/api/issues/:id/close", post(handlers::close_task))
+        .route("/api/issues/:id/reopen", post(handlers::reopen_task))
         .route("/api/issues", post(handlers::create_task))
+        .route(
+            "/api/issues/:id/comments",
+            get(handlers::list_comments).post(handlers::add_comment),
+        )
+        .route(
+            "/api/issues/:id/dependencies",
+            post(handlers::add_dependency).delete(handlers::remove_dependency),
+        )
         .route("/api/graph", get(handlers::graph_data))
+        .route("/api/events", get(handlers::events))
+}
+
+/// Stylesheets, scripts and icons, shared by every project
+fn asset_routes() -> Router {
+    Router::new()
         .route("/health", get(handlers::health_check))
         .route("/style.css", get(handlers::serve_css))
         .route("/themes/:filename", get(handlers::serve_theme))
@@ -77,7 +205,36 @@ pub fn create_app(state: SharedAppState) -> Router {
         .route("/app.js", get(handlers::serve_js))
         .route("/favicon.ico", get(handlers::serve_favicon))
         .route("/favicon.svg", get(handlers::serve_favicon))
-        .with_state(state)
+}
+
+pub fn create_app(state: SharedAppState) -> Router {
+    with_layers(project_routes().with_state(state).merge(asset_routes()))
+}
+
+/// Serve several projects, each under its own `/p/<name>`, with an overview
+/// of all of them at `/overview` and a list at `/api/projects`
+pub fn create_multi_app(projects: Vec<SharedAppState>) -> Router {
+    let projects = Arc::new(Projects::new(projects));
+    let mut router = Router::new()
+        .route("/", get(handlers::projects_index))
+        .route("/overview", get(handlers::overview))
+        .route("/api/overview", get(handlers::overview_api))
+        .route("/api/projects", get(handlers::list_projects))
+        .with_state(projects.clone());
+    for state in projects.iter() {
+        // Nesting only matches the bare prefix, but pages link home as `<prefix>/`
+        router = router
+            .route(
+                &format!("{}/", state.base_path),
+                get(handlers::landing).with_state(state.clone()),
+            )
+            .nest(&state.base_path, project_routes().with_state(state.clone()));
+    }
+    with_layers(router.merge(asset_routes()))
+}
+
+fn with_layers(router: Router) -> Router {
+    router
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(|request: &axum::http::Request<_>| {
@@ -1,8 +1,22 @@
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::process::Command;
+use std::time::Duration;
 use thiserror::Error;
 use time::OffsetDateTime;
+use tokio::process::Command;
+
+pub mod capabilities;
+pub mod jsonl;
+pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+pub use capabilities::{BdInfo, BdVersion, Capabilities, MIN_BD_VERSION};
+pub use jsonl::JsonlBackend;
+pub use memory::MemoryBackend;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteBackend;
 
 #[derive(Error, Debug)]
 pub enum BeadsError {
@@ -17,10 +31,58 @@ pub enum BeadsError {
 
     #[error("Issue not found: {0}")]
     NotFound(String),
+
+    #[error("Beads command timed out after {}s", .0.as_secs())]
+    Timeout(Duration),
+
+    #[error("Not supported by this bd: {0}")]
+    Unsupported(String),
 }
 
 pub type Result<T> = std::result::Result<T, BeadsError>;
 
+/// Serialize an enum through `as_str` and deserialize it through
+/// `From<&str>`, so values introduced by a newer `bd` land in the `Unknown`
+/// variant and round-trip unchanged instead of failing the whole parse.
+macro_rules! string_enum_serde {
+    ($name:ty) => {
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let value = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+                Ok(Self::from(value.as_ref()))
+            }
+        }
+
+        // A plain string in tool schemas; fields list the values they accept
+        impl schemars::JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn json_schema(
+                generator: &mut schemars::r#gen::SchemaGenerator,
+            ) -> schemars::schema::Schema {
+                String::json_schema(generator)
+            }
+        }
+    };
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Issue {
     pub id: String,
@@ -39,9 +101,32 @@ pub struct Issue {
     pub description: Option<String>,
     pub acceptance_criteria: Option<String>,
     pub close_reason: Option<String>,
+    #[serde(alias = "estimated_minutes")]
     pub estimate: Option<u32>,
     #[serde(default)]
     pub dependencies: Vec<Dependency>,
+    /// When a tombstone was deleted, as recorded by `bd delete`
+    #[serde(
+        default,
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deleted_at: Option<OffsetDateTime>,
+    /// Who deleted a tombstone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_by: Option<String>,
+    /// Reverse edges: other issues' dependencies on this one. Only filled in
+    /// by `get_issue`; list results leave it empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependents: Vec<Dependency>,
+}
+
+impl Issue {
+    /// Strong entity tag for this version of the issue, derived from
+    /// `updated_at`, which every write through `bd` bumps
+    pub fn etag(&self) -> String {
+        format!("\"{:x}\"", self.updated_at.unix_timestamp_nanos())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -54,6 +139,22 @@ pub struct Activity {
     pub message: String,
     pub old_status: Option<Status>,
     pub new_status: Option<Status>,
+    /// Who caused the event, when `bd` recorded it
+    #[serde(default)]
+    pub actor: Option<String>,
+}
+
+/// A comment on an issue, as listed by `bd comments --json`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Comment {
+    #[serde(default)]
+    pub id: i64,
+    pub issue_id: String,
+    #[serde(default)]
+    pub author: String,
+    pub text: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
 }
 
 /// Defines relationships between issues.
@@ -81,8 +182,9 @@ pub struct Activity {
 /// - `AuthoredBy`: Creator relationship
 /// - `AssignedTo`: Assignment relationship
 /// - `ApprovedBy`: Approval relationship
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[serde(rename_all = "kebab-case")]
+///
+/// Any other value (from a newer `bd`) is kept verbatim in `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum DependencyType {
     // Workflow types (affect ready work calculation)
     /// Standard blocking relationship
@@ -118,11 +220,31 @@ pub enum DependencyType {
     AssignedTo,
     /// Approval relationship
     ApprovedBy,
+
+    /// A type this version of nacre does not know about
+    Unknown(String),
 }
 
 impl DependencyType {
+    /// Every type nacre knows about, in declaration order
+    pub const KNOWN: [DependencyType; 13] = [
+        DependencyType::Blocks,
+        DependencyType::ParentChild,
+        DependencyType::ConditionalBlocks,
+        DependencyType::WaitsFor,
+        DependencyType::Related,
+        DependencyType::DiscoveredFrom,
+        DependencyType::RepliesTo,
+        DependencyType::RelatesTo,
+        DependencyType::Duplicates,
+        DependencyType::Supersedes,
+        DependencyType::AuthoredBy,
+        DependencyType::AssignedTo,
+        DependencyType::ApprovedBy,
+    ];
+
     /// Returns the kebab-case string representation used by Beads CLI/API
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             DependencyType::Blocks => "blocks",
             DependencyType::ParentChild => "parent-child",
@@ -137,6 +259,7 @@ impl DependencyType {
             DependencyType::AuthoredBy => "authored-by",
             DependencyType::AssignedTo => "assigned-to",
             DependencyType::ApprovedBy => "approved-by",
+            DependencyType::Unknown(value) => value,
         }
     }
 
@@ -151,12 +274,56 @@ impl DependencyType {
         )
     }
 
-    /// Returns true if this is a valid dependency type variant
+    /// Returns true unless this is a type nacre does not know about
     pub fn is_valid(&self) -> bool {
-        true
+        !matches!(self, DependencyType::Unknown(_))
+    }
+
+    /// Returns true if a new edge of this type must not close a cycle
+    pub fn forbids_cycles(&self) -> bool {
+        matches!(self, DependencyType::Blocks | DependencyType::ParentChild)
+    }
+
+    /// How an edge reads on the detail page of each end: `(dependent side,
+    /// depended-on side)`, e.g. A blocks-on B shows "Blocked by" on A and
+    /// "Blocks" on B. `None` for types not listed as relationships.
+    pub fn relation_labels(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            DependencyType::Blocks
+            | DependencyType::ConditionalBlocks
+            | DependencyType::WaitsFor => Some(("Blocked by", "Blocks")),
+            DependencyType::Related | DependencyType::RelatesTo => Some(("Related", "Related")),
+            DependencyType::Duplicates => Some(("Duplicates", "Duplicated by")),
+            DependencyType::Supersedes => Some(("Supersedes", "Superseded by")),
+            DependencyType::DiscoveredFrom => Some(("Discovered from", "Discovered")),
+            _ => None,
+        }
     }
 }
 
+impl From<&str> for DependencyType {
+    fn from(value: &str) -> Self {
+        match value {
+            "blocks" => DependencyType::Blocks,
+            "parent-child" => DependencyType::ParentChild,
+            "conditional-blocks" => DependencyType::ConditionalBlocks,
+            "waits-for" => DependencyType::WaitsFor,
+            "related" => DependencyType::Related,
+            "discovered-from" => DependencyType::DiscoveredFrom,
+            "replies-to" => DependencyType::RepliesTo,
+            "relates-to" => DependencyType::RelatesTo,
+            "duplicates" => DependencyType::Duplicates,
+            "supersedes" => DependencyType::Supersedes,
+            "authored-by" => DependencyType::AuthoredBy,
+            "assigned-to" => DependencyType::AssignedTo,
+            "approved-by" => DependencyType::ApprovedBy,
+            other => DependencyType::Unknown(other.to_string()),
+        }
+    }
+}
+
+string_enum_serde!(DependencyType);
+
 /// Categorizes audit trail events.
 ///
 /// This enum mirrors the Go Beads EventType type from `internal/types/types.go`.
@@ -182,18 +349,16 @@ impl DependencyType {
 ///
 /// System events:
 /// - `Compacted`: Database compaction event
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[serde(rename_all = "kebab-case")]
+///
+/// Any other value (from a newer `bd`) is kept verbatim in `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum EventType {
     /// Issue was created
     #[default]
-    #[serde(rename = "create")]
     Created,
     /// General issue update
-    #[serde(rename = "update")]
     Updated,
     /// Issue status changed
-    #[serde(rename = "status")]
     StatusChanged,
     /// Comment was added
     Commented,
@@ -202,27 +367,25 @@ pub enum EventType {
     /// Previously closed issue was reopened
     Reopened,
     /// Dependency relationship was added
-    #[serde(rename = "dependency_added")]
     DependencyAdded,
     /// Dependency relationship was removed
-    #[serde(rename = "dependency_removed")]
     DependencyRemoved,
     /// Label was added to issue
-    #[serde(rename = "label_added")]
     LabelAdded,
     /// Label was removed from issue
-    #[serde(rename = "label_removed")]
     LabelRemoved,
     /// Database compaction event
     Compacted,
     /// Issue was deleted
-    #[serde(rename = "delete")]
     Deleted,
+
+    /// An event kind this version of nacre does not know about
+    Unknown(String),
 }
 
 impl EventType {
     /// Returns the string representation used by Beads CLI/API
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             EventType::Created => "create",
             EventType::Updated => "update",
@@ -236,15 +399,38 @@ impl EventType {
             EventType::LabelRemoved => "label_removed",
             EventType::Compacted => "compacted",
             EventType::Deleted => "delete",
+            EventType::Unknown(value) => value,
         }
     }
 
-    /// Returns true if this is a valid event type variant
+    /// Returns true unless this is an event kind nacre does not know about
     pub fn is_valid(&self) -> bool {
-        true
+        !matches!(self, EventType::Unknown(_))
+    }
+}
+
+impl From<&str> for EventType {
+    fn from(value: &str) -> Self {
+        match value {
+            "create" => EventType::Created,
+            "update" => EventType::Updated,
+            "status" => EventType::StatusChanged,
+            "commented" => EventType::Commented,
+            "closed" => EventType::Closed,
+            "reopened" => EventType::Reopened,
+            "dependency_added" => EventType::DependencyAdded,
+            "dependency_removed" => EventType::DependencyRemoved,
+            "label_added" => EventType::LabelAdded,
+            "label_removed" => EventType::LabelRemoved,
+            "compacted" => EventType::Compacted,
+            "delete" => EventType::Deleted,
+            other => EventType::Unknown(other.to_string()),
+        }
     }
 }
 
+string_enum_serde!(EventType);
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Dependency {
     pub issue_id: String,
@@ -268,8 +454,9 @@ pub struct Dependency {
 /// - `Closed`: Completed or resolved
 /// - `Tombstone`: Soft-deleted issue (bd-vw8)
 /// - `Pinned`: Persistent bead that stays open indefinitely (bd-6v2)
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[serde(rename_all = "snake_case")]
+///
+/// Any other value (from a newer `bd`) is kept verbatim in `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum Status {
     /// New issue ready for work consideration
     #[default]
@@ -286,6 +473,9 @@ pub enum Status {
     Tombstone,
     /// Persistent bead that stays open indefinitely (bd-6v2)
     Pinned,
+
+    /// A status this version of nacre does not know about
+    Unknown(String),
 }
 
 impl fmt::Display for Status {
@@ -298,13 +488,14 @@ impl fmt::Display for Status {
             Status::Closed => write!(f, "Closed"),
             Status::Tombstone => write!(f, "Tombstone"),
             Status::Pinned => write!(f, "Pinned"),
+            Status::Unknown(value) => write!(f, "{}", value),
         }
     }
 }
 
 impl Status {
     /// Returns the snake_case string representation used by Beads CLI/API
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Status::Open => "open",
             Status::InProgress => "in_progress",
@@ -313,13 +504,27 @@ impl Status {
             Status::Closed => "closed",
             Status::Tombstone => "tombstone",
             Status::Pinned => "pinned",
+            Status::Unknown(value) => value,
         }
     }
 
-    /// Returns true if this is a valid status variant
-    /// All defined variants are considered valid by definition
+    /// Returns true unless this is a status nacre does not know about
     pub fn is_valid(&self) -> bool {
-        true
+        !matches!(self, Status::Unknown(_))
+    }
+
+    /// Returns a CSS-friendly class name, as used by `.status-badge`
+    pub fn as_css_class(&self) -> &'static str {
+        match self {
+            Status::Open => "open",
+            Status::InProgress => "in-progress",
+            Status::Blocked => "blocked",
+            Status::Deferred => "deferred",
+            Status::Closed => "closed",
+            Status::Tombstone => "tombstone",
+            Status::Pinned => "pinned",
+            Status::Unknown(_) => "unknown",
+        }
     }
 
     /// Returns sort order (lower = higher priority in list)
@@ -330,13 +535,31 @@ impl Status {
             Status::Blocked => 1,
             Status::Pinned => 2, // Persistent items should stay visible
             Status::Open => 3,
-            Status::Deferred => 4,
+            // Unrecognised statuses sort with the parked work
+            Status::Deferred | Status::Unknown(_) => 4,
             Status::Closed => 5,
             Status::Tombstone => 6,
         }
     }
 }
 
+impl From<&str> for Status {
+    fn from(value: &str) -> Self {
+        match value {
+            "open" => Status::Open,
+            "in_progress" => Status::InProgress,
+            "blocked" => Status::Blocked,
+            "deferred" => Status::Deferred,
+            "closed" => Status::Closed,
+            "tombstone" => Status::Tombstone,
+            "pinned" => Status::Pinned,
+            other => Status::Unknown(other.to_string()),
+        }
+    }
+}
+
+string_enum_serde!(Status);
+
 /// Categorizes the kind of work an issue represents.
 ///
 /// This enum mirrors the Go Beads IssueType type from `internal/types/types.go`.
@@ -351,8 +574,9 @@ impl Status {
 /// - `MergeRequest`: Merge queue entry for refinery processing
 /// - `Molecule`: Template molecule for issue hierarchies (beads-1ra)
 /// - `Gate`: Async coordination gate (bd-udsi)
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[serde(rename_all = "kebab-case")]
+///
+/// Any other value (from a newer `bd`) is kept verbatim in `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum IssueType {
     /// Defect or error that needs fixing
     Bug,
@@ -368,12 +592,14 @@ pub enum IssueType {
     /// Ephemeral communication between workers
     Message,
     /// Merge queue entry for refinery processing
-    #[serde(rename = "merge-request")]
     MergeRequest,
     /// Template molecule for issue hierarchies (beads-1ra)
     Molecule,
     /// Async coordination gate (bd-udsi)
     Gate,
+
+    /// A type this version of nacre does not know about
+    Unknown(String),
 }
 
 impl fmt::Display for IssueType {
@@ -388,11 +614,25 @@ impl fmt::Display for IssueType {
             IssueType::MergeRequest => write!(f, "Merge Request"),
             IssueType::Molecule => write!(f, "Molecule"),
             IssueType::Gate => write!(f, "Gate"),
+            IssueType::Unknown(value) => write!(f, "{}", value),
         }
     }
 }
 
 impl IssueType {
+    /// Every type nacre knows about, in declaration order
+    pub const KNOWN: [IssueType; 9] = [
+        IssueType::Bug,
+        IssueType::Feature,
+        IssueType::Task,
+        IssueType::Epic,
+        IssueType::Chore,
+        IssueType::Message,
+        IssueType::MergeRequest,
+        IssueType::Molecule,
+        IssueType::Gate,
+    ];
+
     /// Returns a CSS-friendly class name (lowercase, no spaces)
     pub fn as_css_class(&self) -> &'static str {
         match self {
@@ -405,11 +645,13 @@ impl IssueType {
             IssueType::MergeRequest => "merge-request",
             IssueType::Molecule => "molecule",
             IssueType::Gate => "gate",
+            // Arbitrary strings do not belong in class names
+            IssueType::Unknown(_) => "unknown",
         }
     }
 
     /// Returns the kebab-case string representation used by Beads CLI/API
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssueType::Task => "task",
             IssueType::Bug => "bug",
@@ -420,20 +662,145 @@ impl IssueType {
             IssueType::MergeRequest => "merge-request",
             IssueType::Molecule => "molecule",
             IssueType::Gate => "gate",
+            IssueType::Unknown(value) => value,
         }
     }
 
-    /// Returns true if this is a valid issue type variant
-    /// All defined variants are considered valid by definition
+    /// Returns true unless this is a type nacre does not know about
     pub fn is_valid(&self) -> bool {
-        true
+        !matches!(self, IssueType::Unknown(_))
+    }
+}
+
+impl From<&str> for IssueType {
+    fn from(value: &str) -> Self {
+        match value {
+            "bug" => IssueType::Bug,
+            "feature" => IssueType::Feature,
+            "task" => IssueType::Task,
+            "epic" => IssueType::Epic,
+            "chore" => IssueType::Chore,
+            "message" => IssueType::Message,
+            "merge-request" => IssueType::MergeRequest,
+            "molecule" => IssueType::Molecule,
+            "gate" => IssueType::Gate,
+            other => IssueType::Unknown(other.to_string()),
+        }
+    }
+}
+
+string_enum_serde!(IssueType);
+
+/// Source of beads data for the web handlers.
+///
+/// `AppState` holds a `dyn BeadsBackend`, so handlers never care whether
+/// the data comes from the `bd` CLI (`Client`) or from memory
+/// (`MemoryBackend`, used to drive the router in tests). Methods are async
+/// so a slow `bd` never ties up a runtime worker thread.
+#[async_trait]
+pub trait BeadsBackend: Send + Sync {
+    /// List open issues (excludes closed)
+    async fn list_issues(&self) -> Result<Vec<Issue>>;
+
+    /// List all issues including closed (for stats and metrics)
+    async fn list_all_issues(&self) -> Result<Vec<Issue>>;
+
+    async fn get_issue(&self, id: &str) -> Result<Issue>;
+
+    async fn update_issue(&self, id: &str, update: IssueUpdate) -> Result<()>;
+
+    /// Create an issue and return its ID
+    async fn create_issue(&self, create: IssueCreate) -> Result<String>;
+
+    /// Close an issue, recording why
+    async fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()>;
+
+    /// Reopen a closed issue
+    async fn reopen_issue(&self, id: &str, reason: Option<&str>) -> Result<()>;
+
+    /// Soft-delete an issue, leaving a tombstone behind
+    async fn delete_issue(&self, id: &str) -> Result<()>;
+
+    /// Bring a tombstoned issue back as open
+    async fn restore_issue(&self, id: &str) -> Result<()>;
+
+    /// Deleted issues, which every other listing leaves out
+    async fn list_tombstones(&self) -> Result<Vec<Issue>>;
+
+    async fn get_activity(&self) -> Result<Vec<Activity>>;
+
+    /// Activity of a single issue, oldest first
+    async fn get_issue_activity(&self, id: &str) -> Result<Vec<Activity>>;
+
+    /// Make `issue_id` depend on `depends_on_id`
+    async fn add_dependency(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &DependencyType,
+    ) -> Result<()>;
+
+    /// Remove the dependency of `issue_id` on `depends_on_id`
+    async fn remove_dependency(&self, issue_id: &str, depends_on_id: &str) -> Result<()>;
+
+    /// Comments on an issue, oldest first
+    async fn list_comments(&self, id: &str) -> Result<Vec<Comment>>;
+
+    /// Add a comment to an issue and return it
+    async fn add_comment(&self, id: &str, comment: CommentCreate) -> Result<Comment>;
+
+    async fn get_status_summary(&self) -> Result<serde_json::Value>;
+
+    /// List the dependencies of every issue in the project
+    async fn list_all_dependencies(&self) -> Result<Vec<Dependency>>;
+
+    /// Cheap token that changes whenever the underlying data changes.
+    ///
+    /// `None` means the backend cannot tell, and callers must not cache.
+    fn fingerprint(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Hash the modification time and size of each file.
+///
+/// Missing files hash as absent, so creating one changes the fingerprint too.
+pub fn files_fingerprint(paths: &[std::path::PathBuf]) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for path in paths {
+        path.hash(&mut hasher);
+        std::fs::metadata(path)
+            .ok()
+            .map(|m| (m.modified().ok(), m.len()))
+            .hash(&mut hasher);
     }
+    hasher.finish()
+}
+
+/// Reverse edges onto `id`: the dependencies other issues have on it
+pub fn dependents_of<'a>(
+    id: &str,
+    dependencies: impl IntoIterator<Item = &'a Dependency>,
+) -> Vec<Dependency> {
+    dependencies
+        .into_iter()
+        .filter(|d| d.depends_on_id == id && d.issue_id != id)
+        .cloned()
+        .collect()
 }
 
+/// How long a single `bd` invocation may run before it is killed
+pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Clone)]
 pub struct Client {
     bin_path: String,
     db_path: Option<String>,
+    project_dir: Option<std::path::PathBuf>,
+    timeout: Duration,
+    capabilities: Capabilities,
 }
 
 impl Default for Client {
@@ -442,27 +809,116 @@ impl Default for Client {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, schemars::JsonSchema)]
 pub struct IssueUpdate {
     pub title: Option<String>,
+    /// `open`, `in_progress`, `blocked`, `deferred` or `closed`
     pub status: Option<Status>,
+    /// 0 (critical) to 4 (backlog)
     pub priority: Option<u8>,
     pub description: Option<String>,
+    /// An empty string unassigns the issue
+    pub assignee: Option<String>,
+    #[serde(default)]
+    pub add_labels: Vec<String>,
+    #[serde(default)]
+    pub remove_labels: Vec<String>,
+    pub acceptance_criteria: Option<String>,
+    /// Estimated effort in minutes
+    pub estimate: Option<u32>,
+    /// `bug`, `feature`, `task`, `epic`, `chore`, ...
+    pub issue_type: Option<IssueType>,
+    /// Only applied together with `status: closed`
+    pub close_reason: Option<String>,
+    /// Close an epic even though some of its children are still open
+    #[serde(default)]
+    pub force: bool,
+}
+
+impl IssueUpdate {
+    /// Whether this update closes the issue
+    pub fn closes(&self) -> bool {
+        self.status == Some(Status::Closed)
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CommentCreate {
+    pub text: String,
+    /// Recorded as the comment author; `bd` defaults to `BD_ACTOR` or `USER`
+    pub author: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
 pub struct IssueCreate {
     pub title: String,
+    /// `bug`, `feature`, `task`, `epic`, `chore`, ...; `bd` defaults to `task`
     pub issue_type: Option<String>,
+    /// 0 (critical) to 4 (backlog)
     pub priority: Option<u8>,
     pub description: Option<String>,
+    /// Made the parent of the new issue through a parent-child dependency
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub assignee: Option<String>,
+    pub acceptance_criteria: Option<String>,
+    /// Estimated effort in minutes
+    pub estimate: Option<u32>,
+    /// Issues the new one depends on from the start
+    #[serde(default)]
+    pub dependencies: Vec<NewDependency>,
+}
+
+impl IssueCreate {
+    /// Every dependency the new issue starts with, the parent first
+    pub fn links(&self) -> impl Iterator<Item = (&str, DependencyType)> {
+        self.parent
+            .iter()
+            .map(|parent| (parent.as_str(), DependencyType::ParentChild))
+            .chain(
+                self.dependencies
+                    .iter()
+                    .map(|dep| (dep.depends_on_id.as_str(), dep.dep_type.clone())),
+            )
+    }
+}
+
+/// A dependency requested together with a new issue
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+pub struct NewDependency {
+    pub depends_on_id: String,
+    /// `blocks` (default), `parent-child`, `related`, `discovered-from`, ...
+    #[serde(rename = "type", default)]
+    pub dep_type: DependencyType,
 }
 
 impl Client {
     pub fn new() -> Self {
         let bin_path = std::env::var("BD_BIN").unwrap_or_else(|_| "bd".to_string());
         let db_path = std::env::var("BEADS_DB").ok();
-        Self { bin_path, db_path }
+        Self {
+            bin_path,
+            db_path,
+            project_dir: None,
+            timeout: DEFAULT_COMMAND_TIMEOUT,
+            capabilities: Capabilities::ALL,
+        }
+    }
+
+    /// `BD_BIN` and `BEADS_DB` when set, otherwise `bd_bin` and `db_path`
+    /// from `nacre.toml`
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        let mut client = Self::new();
+        if std::env::var_os("BD_BIN").is_none()
+            && let Some(bin) = &config.bd_bin
+        {
+            client.bin_path = bin.clone();
+        }
+        if client.db_path.is_none() {
+            client.db_path = config.db_path.clone();
+        }
+        client
     }
 
     pub fn with_db(mut self, path: String) -> Self {
@@ -470,49 +926,142 @@ impl Client {
         self
     }
 
+    /// Work on the project in `dir` rather than the current directory: `bd`
+    /// runs there and gets that project's own database as `--db`, whatever
+    /// `BEADS_DB` says
+    pub fn with_project_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Result<Self> {
+        self.project_dir = Some(dir.into());
+        self.db_path = None;
+        let db = self.database_path()?;
+        self.db_path = Some(db.to_string_lossy().to_string());
+        Ok(self)
+    }
+
+    /// Directory of the project set with `with_project_dir`
+    pub fn project_dir(&self) -> Option<&std::path::Path> {
+        self.project_dir.as_deref()
+    }
+
+    /// Path of the `bd` binary (default `BD_BIN`, or `bd` on the `PATH`)
+    pub fn with_bin(mut self, path: String) -> Self {
+        self.bin_path = path;
+        self
+    }
+
+    /// Per-command timeout (default 30 seconds)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Features the client may rely on (default: all of them). Pass the
+    /// result of `probe` so missing ones fall back to simpler commands.
+    pub fn with_capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    pub fn bin_path(&self) -> &str {
+        &self.bin_path
+    }
+
+    /// Run `bd version` and each command's `--help` to find out which `bd`
+    /// this is and what it supports.
+    ///
+    /// Fails when `bd` cannot be run or prints no recognisable version.
+    pub async fn probe(&self) -> Result<BdInfo> {
+        let mut cmd = self.base_command();
+        cmd.arg("version");
+        let output = String::from_utf8_lossy(&self.run(cmd).await?).to_string();
+        let version = BdVersion::parse(&output).ok_or_else(|| {
+            BeadsError::Unsupported(format!("unrecognised version output {:?}", output.trim()))
+        })?;
+
+        let (list, activity, create, status, comments) = tokio::join!(
+            self.help(&["list"]),
+            self.help(&["activity"]),
+            self.help(&["create"]),
+            self.help(&["status"]),
+            self.help(&["comments", "add"]),
+        );
+        let supports = |help: &Option<String>, flags: &[&str]| {
+            help.as_deref()
+                .is_some_and(|h| capabilities::help_mentions(h, flags))
+        };
+
+        Ok(BdInfo {
+            bin_path: self.bin_path.clone(),
+            version,
+            version_line: output.lines().next().unwrap_or_default().trim().to_string(),
+            db_path: self.database_path().ok(),
+            capabilities: Capabilities {
+                list_all: supports(&list, &["--all", "--limit"]),
+                activity: supports(&activity, &["--json"]),
+                issue_activity: supports(&activity, &["--json", "--issue"]),
+                create_silent: supports(&create, &["--silent"]),
+                status: supports(&status, &["--json"]),
+                comments: comments.is_some(),
+            },
+        })
+    }
+
+    /// `--help` output of a subcommand, or `None` if `bd` does not know it
+    async fn help(&self, subcommand: &[&str]) -> Option<String> {
+        let mut cmd = self.base_command();
+        cmd.args(subcommand).arg("--help");
+        let output = self.output(cmd).await.ok()?;
+        let text = String::from_utf8_lossy(&output.stdout).to_string();
+        (output.status.success() && !text.to_lowercase().contains("unknown command"))
+            .then_some(text)
+    }
+
     fn base_command(&self) -> Command {
         let mut cmd = Command::new(&self.bin_path);
         if let Some(db) = &self.db_path {
             cmd.arg("--db").arg(db);
         }
+        if let Some(dir) = &self.project_dir {
+            cmd.current_dir(dir);
+        }
+        // Dropping the future (timeout, or the HTTP request went away) kills bd
+        cmd.kill_on_drop(true);
         cmd
     }
 
-    pub fn list_issues(&self) -> Result<Vec<Issue>> {
-        let output = self.base_command().args(["list", "--json"]).output()?;
-
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(BeadsError::CommandError(error_msg.to_string()));
+    /// Run a command to completion, killing it once the timeout elapses
+    async fn output(&self, mut cmd: Command) -> Result<std::process::Output> {
+        match tokio::time::timeout(self.timeout, cmd.output()).await {
+            Ok(output) => Ok(output?),
+            Err(_) => {
+                tracing::warn!(timeout = ?self.timeout, "bd command timed out: {:?}", cmd.as_std());
+                Err(BeadsError::Timeout(self.timeout))
+            }
         }
-
-        let issues: Vec<Issue> = serde_json::from_slice(&output.stdout)?;
-        Ok(issues)
     }
 
-    /// List all issues including closed (for stats and metrics)
-    pub fn list_all_issues(&self) -> Result<Vec<Issue>> {
-        let output = self
-            .base_command()
-            .args(["list", "--json", "--all", "--limit", "0"])
-            .output()?;
+    /// Run a command and return its stdout, turning a failed exit into an error
+    async fn run(&self, cmd: Command) -> Result<Vec<u8>> {
+        let output = self.output(cmd).await?;
 
         if !output.status.success() {
             let error_msg = String::from_utf8_lossy(&output.stderr);
             return Err(BeadsError::CommandError(error_msg.to_string()));
         }
 
-        let issues: Vec<Issue> = serde_json::from_slice(&output.stdout)?;
-        Ok(issues)
+        Ok(output.stdout)
     }
 
-    pub fn get_issue(&self, id: &str) -> Result<Issue> {
-        let output = self
-            .base_command()
-            .arg("show")
-            .arg(id)
-            .arg("--json")
-            .output()?;
+    /// Like `run`, but a "not found" error becomes `NotFound(id)`
+    async fn run_for_issue(&self, cmd: Command, id: &str) -> Result<Vec<u8>> {
+        let output = self.output(cmd).await?;
 
         if !output.status.success() {
             let error_msg = String::from_utf8_lossy(&output.stderr);
@@ -522,88 +1071,304 @@ impl Client {
             return Err(BeadsError::CommandError(error_msg.to_string()));
         }
 
-        #[derive(Deserialize)]
-        struct IssueFromShow {
-            pub id: String,
-            pub title: String,
-            pub status: Status,
-            pub priority: Option<u8>,
-            pub issue_type: IssueType,
-            #[serde(with = "time::serde::rfc3339")]
-            pub created_at: OffsetDateTime,
-            #[serde(with = "time::serde::rfc3339")]
-            pub updated_at: OffsetDateTime,
-            #[serde(default, with = "time::serde::rfc3339::option")]
-            pub closed_at: Option<OffsetDateTime>,
-            pub assignee: Option<String>,
-            pub labels: Option<Vec<String>>,
-            pub description: Option<String>,
-            pub acceptance_criteria: Option<String>,
-            pub close_reason: Option<String>,
-            pub estimate: Option<u32>,
-            #[serde(default)]
-            #[allow(dead_code)]
-            pub dependencies: Vec<serde_json::Value>,
+        Ok(output.stdout)
+    }
+
+    /// Database path passed to `bd --db`, if any
+    pub fn db_path(&self) -> Option<&str> {
+        self.db_path.as_deref()
+    }
+
+    /// Path of the beads database: `BEADS_DB`, or the file named in
+    /// `.beads/metadata.json` (default `beads.db`)
+    pub fn database_path(&self) -> Result<std::path::PathBuf> {
+        if let Some(db) = &self.db_path {
+            return Ok(std::path::PathBuf::from(db));
+        }
+
+        let beads_dir = self.find_beads_dir()?;
+        let name = std::fs::read_to_string(beads_dir.join("metadata.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+            .and_then(|v| v["database"].as_str().map(str::to_string))
+            .unwrap_or_else(|| "beads.db".to_string());
+        Ok(beads_dir.join(name))
+    }
+
+    /// Files whose changes mean the beads data changed: the database (and
+    /// its write-ahead log) plus the JSONL export
+    pub fn watch_paths(&self) -> Result<Vec<std::path::PathBuf>> {
+        let db = self.database_path()?;
+        let mut wal = db.clone().into_os_string();
+        wal.push("-wal");
+        let jsonl = self.find_beads_dir()?.join("issues.jsonl");
+        Ok(vec![db, std::path::PathBuf::from(wal), jsonl])
+    }
+
+    /// Export pending database changes to `issues.jsonl`
+    pub async fn flush(&self) -> Result<()> {
+        let mut cmd = self.base_command();
+        cmd.args(["sync", "--flush-only"]);
+        self.run(cmd).await?;
+        Ok(())
+    }
+
+    /// Root of the project: the directory holding `.beads`, found the same
+    /// way as the database
+    pub fn project_root(&self) -> Result<std::path::PathBuf> {
+        let beads_dir = self.find_beads_dir()?;
+        Ok(beads_dir
+            .parent()
+            .map(std::path::Path::to_path_buf)
+            .unwrap_or(beads_dir))
+    }
+
+    /// Find the .beads directory by walking up from the project directory
+    /// (the current directory unless one was set)
+    pub fn find_beads_dir(&self) -> Result<std::path::PathBuf> {
+        if let Some(db_path) = &self.db_path {
+            let path = std::path::Path::new(db_path);
+            if let Some(parent) = path.parent() {
+                return Ok(parent.to_path_buf());
+            }
         }
 
-        let issues: Vec<IssueFromShow> = serde_json::from_slice(&output.stdout)?;
-        let issue_show = issues
+        let mut current = match &self.project_dir {
+            Some(dir) => dir.clone(),
+            None => std::env::current_dir().map_err(|e| {
+                BeadsError::CommandError(format!("Failed to get current dir: {}", e))
+            })?,
+        };
+
+        loop {
+            let beads_dir = current.join(".beads");
+            if beads_dir.is_dir() {
+                return Ok(beads_dir);
+            }
+
+            if !current.pop() {
+                return Err(BeadsError::CommandError(
+                    "No .beads directory found".to_string(),
+                ));
+            }
+        }
+    }
+}
+
+/// One entry of `dependencies`/`dependents` in `bd show --json`: a plain
+/// dependency record from older releases, or the linked issue itself with
+/// the edge type in `dependency_type`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ShowLink {
+    Record(Dependency),
+    Issue {
+        id: String,
+        #[serde(default)]
+        dependency_type: DependencyType,
+    },
+}
+
+impl ShowLink {
+    /// Remove and parse a link list from a `bd show` issue object
+    fn take(issue: &mut serde_json::Value, field: &str) -> Result<Vec<ShowLink>> {
+        let Some(list) = issue.as_object_mut().and_then(|o| o.remove(field)) else {
+            return Ok(vec![]);
+        };
+        Ok(serde_json::from_value::<Option<Vec<ShowLink>>>(list)?.unwrap_or_default())
+    }
+
+    /// `outgoing` edges go from `id` to the link, incoming ones the other way
+    fn into_dependency(self, id: &str, outgoing: bool) -> Dependency {
+        match self {
+            ShowLink::Record(dep) => dep,
+            ShowLink::Issue {
+                id: other,
+                dependency_type,
+            } => {
+                let (issue_id, depends_on_id) = if outgoing {
+                    (id.to_string(), other)
+                } else {
+                    (other, id.to_string())
+                };
+                Dependency {
+                    issue_id,
+                    depends_on_id,
+                    dep_type: dependency_type,
+                    created_at: None,
+                    created_by: None,
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl BeadsBackend for Client {
+    async fn list_issues(&self) -> Result<Vec<Issue>> {
+        let mut cmd = self.base_command();
+        cmd.args(["list", "--json"]);
+        let stdout = self.run(cmd).await?;
+
+        let issues: Vec<Issue> = serde_json::from_slice(&stdout)?;
+        Ok(issues)
+    }
+
+    async fn list_all_issues(&self) -> Result<Vec<Issue>> {
+        if !self.capabilities.list_all {
+            // Older bd: open issues plus an explicit query for closed ones
+            let mut cmd = self.base_command();
+            cmd.args(["list", "--json", "--status", "closed"]);
+            let (open, closed) = tokio::join!(self.list_issues(), self.run(cmd));
+            let mut issues = open?;
+            issues.extend(serde_json::from_slice::<Vec<Issue>>(&closed?)?);
+            return Ok(issues);
+        }
+
+        let mut cmd = self.base_command();
+        cmd.args(["list", "--json", "--all", "--limit", "0"]);
+        let stdout = self.run(cmd).await?;
+
+        let issues: Vec<Issue> = serde_json::from_slice(&stdout)?;
+        Ok(issues)
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<Issue> {
+        let mut cmd = self.base_command();
+        cmd.arg("show").arg(id).arg("--json");
+        let output = self.run_for_issue(cmd, id).await?;
+
+        let issues: Vec<serde_json::Value> = serde_json::from_slice(&output)?;
+        let mut value = issues
             .into_iter()
             .next()
             .ok_or_else(|| BeadsError::NotFound(id.to_string()))?;
 
-        Ok(Issue {
-            id: issue_show.id,
-            title: issue_show.title,
-            status: issue_show.status,
-            priority: issue_show.priority,
-            issue_type: issue_show.issue_type,
-            created_at: issue_show.created_at,
-            updated_at: issue_show.updated_at,
-            closed_at: issue_show.closed_at,
-            assignee: issue_show.assignee,
-            labels: issue_show.labels,
-            description: issue_show.description,
-            acceptance_criteria: issue_show.acceptance_criteria,
-            close_reason: issue_show.close_reason,
-            estimate: issue_show.estimate,
-            dependencies: vec![],
-        })
+        // `bd show` lists the linked issues in place of dependency records,
+        // so take both link lists out before parsing the issue itself
+        let dependencies = ShowLink::take(&mut value, "dependencies")?;
+        let dependents = ShowLink::take(&mut value, "dependents")?;
+        let mut issue: Issue = serde_json::from_value(value)?;
+
+        issue.dependencies = dependencies
+            .into_iter()
+            .map(|link| link.into_dependency(&issue.id, true))
+            .collect();
+        issue.dependents = dependents
+            .into_iter()
+            .map(|link| link.into_dependency(&issue.id, false))
+            .collect();
+        Ok(issue)
     }
 
-    pub fn update_issue(&self, id: &str, update: IssueUpdate) -> Result<()> {
+    async fn update_issue(&self, id: &str, update: IssueUpdate) -> Result<()> {
         let mut cmd = self.base_command();
-        cmd.arg("update").arg(id);
+        cmd.arg("update");
+        let mut has_changes = false;
+        let mut flag = |name: &str, value: &str| {
+            cmd.arg(name).arg(value);
+            has_changes = true;
+        };
 
         if let Some(title) = &update.title {
-            cmd.arg("--title").arg(title);
+            flag("--title", title);
         }
-        if let Some(status) = &update.status {
-            cmd.arg("--status").arg(status.as_str());
+        // Closing goes through `bd close` so the reason is recorded with it
+        if let Some(status) = &update.status
+            && !update.closes()
+        {
+            flag("--status", status.as_str());
         }
         if let Some(priority) = update.priority {
-            cmd.arg("--priority").arg(priority.to_string());
+            flag("--priority", &priority.to_string());
         }
         if let Some(description) = &update.description {
-            cmd.arg("--description").arg(description);
+            flag("--description", description);
+        }
+        if let Some(assignee) = &update.assignee {
+            flag("--assignee", assignee);
+        }
+        for label in &update.add_labels {
+            flag("--add-label", label);
+        }
+        for label in &update.remove_labels {
+            flag("--remove-label", label);
+        }
+        if let Some(acceptance) = &update.acceptance_criteria {
+            flag("--acceptance", acceptance);
+        }
+        if let Some(estimate) = update.estimate {
+            flag("--estimate", &estimate.to_string());
+        }
+        if let Some(issue_type) = &update.issue_type {
+            flag("--type", issue_type.as_str());
         }
 
-        let output = cmd.output()?;
+        if has_changes {
+            cmd.arg("--").arg(id);
+            self.run_for_issue(cmd, id).await?;
+        }
 
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(BeadsError::CommandError(error_msg.to_string()));
+        if update.closes() {
+            self.close_issue(id, update.close_reason.as_deref()).await?;
+        }
+        Ok(())
+    }
+
+    async fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        let mut cmd = self.base_command();
+        cmd.arg("close");
+        if let Some(reason) = reason {
+            cmd.arg("--reason").arg(reason);
+        }
+        cmd.arg("--").arg(id);
+        self.run_for_issue(cmd, id).await?;
+        Ok(())
+    }
+
+    async fn reopen_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        let mut cmd = self.base_command();
+        cmd.arg("reopen");
+        if let Some(reason) = reason {
+            cmd.arg("--reason").arg(reason);
         }
+        cmd.arg("--").arg(id);
+        self.run_for_issue(cmd, id).await?;
+        Ok(())
+    }
 
+    async fn delete_issue(&self, id: &str) -> Result<()> {
+        let mut cmd = self.base_command();
+        cmd.args(["delete", "--force", "--", id]);
+        self.run_for_issue(cmd, id).await?;
         Ok(())
     }
 
-    pub fn create_issue(&self, create: IssueCreate) -> Result<String> {
+    async fn restore_issue(&self, id: &str) -> Result<()> {
+        let mut cmd = self.base_command();
+        cmd.args(["update", "--status", "open", "--", id]);
+        self.run_for_issue(cmd, id).await?;
+        Ok(())
+    }
+
+    async fn list_tombstones(&self) -> Result<Vec<Issue>> {
+        let mut cmd = self.base_command();
+        cmd.args(["list", "--json", "--status", "tombstone"]);
+        if self.capabilities.list_all {
+            cmd.args(["--limit", "0"]);
+        }
+        let stdout = self.run(cmd).await?;
+        Ok(serde_json::from_slice(&stdout)?)
+    }
+
+    async fn create_issue(&self, create: IssueCreate) -> Result<String> {
         let mut cmd = self.base_command();
-        cmd.arg("create")
-            .arg("--title")
-            .arg(&create.title)
-            .arg("--silent");
+        cmd.arg("create").arg("--title").arg(&create.title);
+        if self.capabilities.create_silent {
+            cmd.arg("--silent");
+        } else {
+            cmd.arg("--json");
+        }
 
         if let Some(issue_type) = &create.issue_type {
             cmd.arg("--type").arg(issue_type);
@@ -614,112 +1379,167 @@ impl Client {
         if let Some(description) = &create.description {
             cmd.arg("--description").arg(description);
         }
+        if !create.labels.is_empty() {
+            cmd.arg("--labels").arg(create.labels.join(","));
+        }
+        if let Some(assignee) = &create.assignee {
+            cmd.arg("--assignee").arg(assignee);
+        }
+        if let Some(acceptance) = &create.acceptance_criteria {
+            cmd.arg("--acceptance").arg(acceptance);
+        }
+        if let Some(estimate) = create.estimate {
+            cmd.arg("--estimate").arg(estimate.to_string());
+        }
 
-        let output = cmd.output()?;
+        let stdout = self.run(cmd).await?;
 
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(BeadsError::CommandError(error_msg.to_string()));
+        let id = if self.capabilities.create_silent {
+            // bd create --silent outputs just the issue ID
+            String::from_utf8_lossy(&stdout).trim().to_string()
+        } else {
+            #[derive(Deserialize)]
+            struct Created {
+                id: String,
+            }
+            let created: Created = serde_json::from_slice(&stdout)?;
+            created.id
+        };
+
+        // Links need the new ID, so they are added one by one afterwards and
+        // the issue is deleted again if any of them fails
+        for (depends_on_id, dep_type) in create.links() {
+            if let Err(e) = self.add_dependency(&id, depends_on_id, &dep_type).await {
+                let mut cmd = self.base_command();
+                cmd.arg("delete").arg("--force").arg("--").arg(&id);
+                if let Err(rollback) = self.run(cmd).await {
+                    tracing::warn!("Could not roll back creation of {}: {}", id, rollback);
+                }
+                return Err(e);
+            }
         }
 
-        // bd create --silent outputs just the issue ID
-        let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
         Ok(id)
     }
 
-    pub fn get_activity(&self) -> Result<Vec<Activity>> {
+    async fn get_activity(&self) -> Result<Vec<Activity>> {
+        if !self.capabilities.activity {
+            return Err(BeadsError::Unsupported("activity --json".to_string()));
+        }
+
         // Use a high limit to ensure we get all InProgress transitions needed for cycle time
-        let output = self
-            .base_command()
-            .arg("activity")
+        let mut cmd = self.base_command();
+        cmd.arg("activity")
             .arg("--json")
             .arg("--limit")
-            .arg("10000")
-            .output()?;
+            .arg("10000");
+        let stdout = self.run(cmd).await?;
 
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(BeadsError::CommandError(error_msg.to_string()));
-        }
-
-        let activities: Vec<Activity> = serde_json::from_slice(&output.stdout)?;
+        let activities: Vec<Activity> = serde_json::from_slice(&stdout)?;
         Ok(activities)
     }
 
-    pub fn get_status_summary(&self) -> Result<serde_json::Value> {
-        let output = self.base_command().arg("status").arg("--json").output()?;
-
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(BeadsError::CommandError(error_msg.to_string()));
+    async fn get_issue_activity(&self, id: &str) -> Result<Vec<Activity>> {
+        if !self.capabilities.issue_activity {
+            // Older bd cannot scope the feed, so filter the whole project's
+            let mut activity = self.get_activity().await?;
+            activity.retain(|a| a.issue_id == id);
+            return Ok(activity);
         }
 
-        let summary: serde_json::Value = serde_json::from_slice(&output.stdout)?;
-        Ok(summary)
+        let mut cmd = self.base_command();
+        cmd.args(["activity", "--json", "--limit", "0", "--issue", id]);
+        let stdout = self.run_for_issue(cmd, id).await?;
+        let mut activity: Vec<Activity> = serde_json::from_slice(&stdout)?;
+        activity.sort_by_key(|a| a.timestamp);
+        Ok(activity)
     }
 
-    /// List all dependencies by reading the JSONL file directly.
-    ///
-    /// This is necessary because `bd list --json` does not include dependencies.
-    /// The JSONL file contains the full dependency information for each issue.
-    pub fn list_all_dependencies(&self) -> Result<Vec<Dependency>> {
-        // Find the .beads directory
-        let beads_dir = self.find_beads_dir()?;
-        let jsonl_path = beads_dir.join("issues.jsonl");
+    async fn add_dependency(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &DependencyType,
+    ) -> Result<()> {
+        let mut cmd = self.base_command();
+        cmd.args(["dep", "add", "--type", dep_type.as_str(), "--"])
+            .arg(issue_id)
+            .arg(depends_on_id);
+        self.run_for_issue(cmd, issue_id).await?;
+        Ok(())
+    }
 
-        if !jsonl_path.exists() {
-            return Ok(vec![]);
-        }
+    async fn remove_dependency(&self, issue_id: &str, depends_on_id: &str) -> Result<()> {
+        let mut cmd = self.base_command();
+        cmd.args(["dep", "remove", "--"])
+            .arg(issue_id)
+            .arg(depends_on_id);
+        self.run_for_issue(cmd, issue_id).await?;
+        Ok(())
+    }
 
-        let content = std::fs::read_to_string(&jsonl_path)
-            .map_err(|e| BeadsError::CommandError(format!("Failed to read JSONL: {}", e)))?;
+    async fn list_comments(&self, id: &str) -> Result<Vec<Comment>> {
+        if !self.capabilities.comments {
+            return Err(BeadsError::Unsupported("comments".to_string()));
+        }
 
-        let mut all_dependencies = Vec::new();
+        let mut cmd = self.base_command();
+        cmd.args(["comments", "--json", "--"]).arg(id);
+        let stdout = self.run_for_issue(cmd, id).await?;
 
-        for line in content.lines() {
-            if line.trim().is_empty() {
-                continue;
-            }
+        // An issue without comments may print `null`
+        let comments: Option<Vec<Comment>> = serde_json::from_slice(&stdout)?;
+        Ok(comments.unwrap_or_default())
+    }
 
-            // Parse each line as a JSON object with dependencies
-            #[derive(Deserialize)]
-            struct IssueWithDeps {
-                #[serde(default)]
-                dependencies: Vec<Dependency>,
-            }
+    async fn add_comment(&self, id: &str, comment: CommentCreate) -> Result<Comment> {
+        if !self.capabilities.comments {
+            return Err(BeadsError::Unsupported("comments".to_string()));
+        }
 
-            if let Ok(issue) = serde_json::from_str::<IssueWithDeps>(line) {
-                all_dependencies.extend(issue.dependencies);
-            }
+        let mut cmd = self.base_command();
+        if let Some(author) = &comment.author {
+            cmd.arg("--actor").arg(author);
         }
+        // `--` keeps comment text starting with a dash from being read as a flag
+        cmd.args(["comments", "add", "--json", "--"])
+            .arg(id)
+            .arg(&comment.text);
+        let stdout = self.run_for_issue(cmd, id).await?;
 
-        Ok(all_dependencies)
+        Ok(serde_json::from_slice(&stdout)?)
     }
 
-    /// Find the .beads directory by walking up from the current directory
-    fn find_beads_dir(&self) -> Result<std::path::PathBuf> {
-        if let Some(db_path) = &self.db_path {
-            let path = std::path::Path::new(db_path);
-            if let Some(parent) = path.parent() {
-                return Ok(parent.to_path_buf());
-            }
+    async fn get_status_summary(&self) -> Result<serde_json::Value> {
+        if !self.capabilities.status {
+            return Err(BeadsError::Unsupported("status --json".to_string()));
         }
 
-        let mut current = std::env::current_dir()
-            .map_err(|e| BeadsError::CommandError(format!("Failed to get current dir: {}", e)))?;
+        let mut cmd = self.base_command();
+        cmd.arg("status").arg("--json");
+        let stdout = self.run(cmd).await?;
 
-        loop {
-            let beads_dir = current.join(".beads");
-            if beads_dir.is_dir() {
-                return Ok(beads_dir);
-            }
+        let summary: serde_json::Value = serde_json::from_slice(&stdout)?;
+        Ok(summary)
+    }
 
-            if !current.pop() {
-                return Err(BeadsError::CommandError(
-                    "No .beads directory found".to_string(),
-                ));
-            }
+    /// Reads the JSONL file directly because `bd list --json` does not
+    /// include dependencies.
+    async fn list_all_dependencies(&self) -> Result<Vec<Dependency>> {
+        let jsonl_path = self.find_beads_dir()?.join("issues.jsonl");
+        if !jsonl_path.exists() {
+            return Ok(vec![]);
         }
+
+        tokio::task::spawn_blocking(move || jsonl::read_dependencies(&jsonl_path))
+            .await
+            .map_err(|e| BeadsError::CommandError(format!("Failed to read JSONL: {}", e)))?
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        self.watch_paths()
+            .ok()
+            .map(|paths| files_fingerprint(&paths))
     }
 }
 
@@ -833,6 +1653,66 @@ mod tests {
         assert_eq!(IssueType::Gate.as_str(), "gate");
     }
 
+    #[test]
+    fn test_status_css_class() {
+        assert_eq!(Status::InProgress.as_css_class(), "in-progress");
+        assert_eq!(Status::Closed.as_css_class(), "closed");
+        assert_eq!(Status::Unknown("x y".to_string()).as_css_class(), "unknown");
+    }
+
+    #[test]
+    fn test_dependency_type_relation_labels() {
+        assert_eq!(
+            DependencyType::Blocks.relation_labels(),
+            Some(("Blocked by", "Blocks"))
+        );
+        assert_eq!(
+            DependencyType::DiscoveredFrom.relation_labels(),
+            Some(("Discovered from", "Discovered"))
+        );
+        assert_eq!(DependencyType::ParentChild.relation_labels(), None);
+    }
+
+    #[test]
+    fn test_show_links_become_typed_edges() {
+        let mut value = serde_json::json!({
+            "dependencies": [
+                {"id": "t-2", "title": "Blocker", "status": "open", "dependency_type": "blocks"},
+                {"issue_id": "t-1", "depends_on_id": "t-3", "type": "related"}
+            ],
+            "dependents": [
+                {"id": "t-4", "title": "Found later", "dependency_type": "discovered-from"}
+            ],
+            "dependents_missing": null
+        });
+
+        let deps: Vec<Dependency> = ShowLink::take(&mut value, "dependencies")
+            .unwrap()
+            .into_iter()
+            .map(|l| l.into_dependency("t-1", true))
+            .collect();
+        let dependents: Vec<Dependency> = ShowLink::take(&mut value, "dependents")
+            .unwrap()
+            .into_iter()
+            .map(|l| l.into_dependency("t-1", false))
+            .collect();
+
+        assert_eq!(deps[0].issue_id, "t-1");
+        assert_eq!(deps[0].depends_on_id, "t-2");
+        assert_eq!(deps[0].dep_type, DependencyType::Blocks);
+        assert_eq!(deps[1].depends_on_id, "t-3");
+        assert_eq!(deps[1].dep_type, DependencyType::Related);
+        assert_eq!(dependents[0].issue_id, "t-4");
+        assert_eq!(dependents[0].depends_on_id, "t-1");
+        assert_eq!(dependents[0].dep_type, DependencyType::DiscoveredFrom);
+        assert!(value.get("dependencies").is_none());
+        assert!(
+            ShowLink::take(&mut value, "dependents_missing")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_issue_type_css_class() {
         assert_eq!(IssueType::Task.as_css_class(), "task");
@@ -973,6 +1853,9 @@ mod tests {
             close_reason: None,
             estimate: Some(8),
             dependencies: vec![],
+            deleted_at: None,
+            deleted_by: None,
+            dependents: vec![],
         };
 
         let serialized = serde_json::to_string(&issue).unwrap();
@@ -992,6 +1875,7 @@ mod tests {
             message: "Status changed from Open to In Progress".to_string(),
             old_status: Some(Status::Open),
             new_status: Some(Status::InProgress),
+            actor: None,
         };
 
         let serialized = serde_json::to_string(&activity).unwrap();
@@ -1053,4 +1937,263 @@ mod tests {
         assert_eq!(dependency.created_by, deserialized.created_by);
         assert_eq!(dependency.created_at, deserialized.created_at);
     }
+
+    #[test]
+    fn test_unknown_values_round_trip() {
+        let status: Status = serde_json::from_str("\"hooked\"").unwrap();
+        assert_eq!(status, Status::Unknown("hooked".to_string()));
+        assert!(!status.is_valid());
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"hooked\"");
+
+        let issue_type: IssueType = serde_json::from_str("\"convoy\"").unwrap();
+        assert_eq!(issue_type.as_str(), "convoy");
+        assert_eq!(issue_type.as_css_class(), "unknown");
+        assert_eq!(issue_type.to_string(), "convoy");
+
+        let dep_type: DependencyType = serde_json::from_str("\"tracks\"").unwrap();
+        assert!(!dep_type.affects_workflow());
+        assert_eq!(serde_json::to_string(&dep_type).unwrap(), "\"tracks\"");
+
+        let event_type: EventType = serde_json::from_str("\"gate_opened\"").unwrap();
+        assert_eq!(event_type, EventType::Unknown("gate_opened".to_string()));
+    }
+
+    #[test]
+    fn test_issue_with_unknown_values_parses() {
+        let json = r#"[{"id":"nacre-1","title":"From the future","status":"hooked","priority":1,"issue_type":"convoy","created_at":"2026-01-01T00:00:00Z","updated_at":"2026-01-01T00:00:00Z","dependencies":[{"issue_id":"nacre-1","depends_on_id":"nacre-2","type":"tracks"}]}]"#;
+
+        let issues: Vec<Issue> = serde_json::from_str(json).unwrap();
+        assert_eq!(issues[0].status.as_str(), "hooked");
+        assert_eq!(issues[0].status.sort_order(), Status::Deferred.sort_order());
+        assert_eq!(
+            issues[0].issue_type,
+            IssueType::Unknown("convoy".to_string())
+        );
+        assert_eq!(
+            issues[0].dependencies[0].dep_type,
+            DependencyType::Unknown("tracks".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    fn fake_bd(dir: &std::path::Path, script: &str) -> Client {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("bd");
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        Client::new().with_bin(path.to_string_lossy().to_string())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_client_parses_command_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_bd(dir.path(), "echo '[]'");
+
+        assert!(client.list_issues().await.unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_client_reports_stderr_on_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_bd(dir.path(), "echo 'database is locked' >&2; exit 1");
+
+        match client.list_issues().await {
+            Err(BeadsError::CommandError(msg)) => assert!(msg.contains("database is locked")),
+            other => panic!("expected CommandError, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_client_times_out_hung_command() {
+        let dir = tempfile::tempdir().unwrap();
+        let client =
+            fake_bd(dir.path(), "sleep 10").with_timeout(std::time::Duration::from_millis(200));
+
+        let started = std::time::Instant::now();
+        let result = client.list_all_issues().await;
+
+        assert!(matches!(result, Err(BeadsError::Timeout(_))));
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_probe_detects_version_and_capabilities() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_bd(
+            dir.path(),
+            r#"case "$1" in
+  version) echo 'bd version 0.29.0 (dev)' ;;
+  list) echo '  --all  --limit int  --json' ;;
+  create) echo '  --title string  --json' ;;
+  status) echo '  --json' ;;
+  comments) echo 'Add a comment to an issue' ;;
+  *) echo "Error: unknown command \"$1\"" >&2; exit 1 ;;
+esac"#,
+        );
+
+        let info = client.probe().await.unwrap();
+
+        assert_eq!(info.version, BdVersion::new(0, 29, 0));
+        assert_eq!(info.version_line, "bd version 0.29.0 (dev)");
+        assert!(info.is_supported());
+        assert_eq!(
+            info.capabilities,
+            Capabilities {
+                list_all: true,
+                activity: false,
+                issue_activity: false,
+                create_silent: false,
+                status: true,
+                comments: true,
+            }
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_client_runs_in_project_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("project");
+        std::fs::create_dir_all(project.join(".beads")).unwrap();
+        let log = dir.path().join("calls");
+        let client = fake_bd(
+            dir.path(),
+            &format!("echo \"$PWD $*\" > {}; echo '[]'", log.display()),
+        )
+        .with_project_dir(&project)
+        .unwrap();
+
+        assert_eq!(client.project_dir(), Some(project.as_path()));
+        assert_eq!(client.find_beads_dir().unwrap(), project.join(".beads"));
+        let db = project.join(".beads").join("beads.db");
+        assert_eq!(client.db_path(), Some(db.to_string_lossy().as_ref()));
+
+        client.list_issues().await.unwrap();
+        let call = std::fs::read_to_string(&log).unwrap();
+        assert!(call.starts_with(&format!("{} --db {} ", project.display(), db.display())));
+    }
+
+    #[test]
+    fn test_project_root_holds_beads_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join(".beads").join("beads.db");
+        let client = Client::new().with_db(db.to_string_lossy().to_string());
+
+        assert_eq!(client.project_root().unwrap(), dir.path());
+    }
+
+    #[tokio::test]
+    async fn test_probe_fails_without_binary() {
+        let client = Client::new().with_bin("/nonexistent/bd".to_string());
+
+        assert!(matches!(
+            client.probe().await,
+            Err(BeadsError::CommandFailed(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_client_falls_back_for_missing_capabilities() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_bd(
+            dir.path(),
+            r#"case "$*" in
+  *--silent*) exit 1 ;;
+  create*) echo '{"id": "t-9", "title": "x"}' ;;
+esac"#,
+        )
+        .with_capabilities(Capabilities {
+            activity: false,
+            create_silent: false,
+            ..Capabilities::ALL
+        });
+
+        let id = client
+            .create_issue(IssueCreate {
+                title: "x".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(id, "t-9");
+        assert!(matches!(
+            client.get_activity().await,
+            Err(BeadsError::Unsupported(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_client_update_closes_through_bd_close() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("calls");
+        let client = fake_bd(dir.path(), &format!("echo \"$*\" >> {}", log.display()));
+
+        client
+            .update_issue(
+                "t-1",
+                IssueUpdate {
+                    assignee: Some("ada".to_string()),
+                    add_labels: vec!["ui".to_string()],
+                    status: Some(Status::Closed),
+                    close_reason: Some("Shipped".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let calls = std::fs::read_to_string(&log).unwrap();
+        let calls: Vec<&str> = calls.lines().collect();
+        assert_eq!(
+            calls,
+            [
+                "update --assignee ada --add-label ui -- t-1",
+                "close --reason Shipped -- t-1"
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_client_create_rolls_back_when_link_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("calls");
+        let client = fake_bd(
+            dir.path(),
+            &format!(
+                r#"echo "$*" >> {}
+case "$*" in
+  create*) echo t-9 ;;
+  dep*) echo 'Error: issue t-404 not found' >&2; exit 1 ;;
+esac"#,
+                log.display()
+            ),
+        );
+
+        let result = client
+            .create_issue(IssueCreate {
+                title: "Child".to_string(),
+                parent: Some("t-1".to_string()),
+                dependencies: vec![NewDependency {
+                    depends_on_id: "t-404".to_string(),
+                    dep_type: DependencyType::Blocks,
+                }],
+                ..Default::default()
+            })
+            .await;
+
+        assert!(result.is_err());
+        let calls = std::fs::read_to_string(&log).unwrap();
+        let calls: Vec<&str> = calls.lines().collect();
+        assert_eq!(calls[1], "dep add --type parent-child -- t-9 t-1");
+        assert_eq!(calls.last(), Some(&"delete --force -- t-9"));
+    }
 }
@@ -0,0 +1,167 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+/// Oldest `bd` release nacre is known to work with
+pub const MIN_BD_VERSION: BdVersion = BdVersion::new(0, 20, 0);
+
+/// A `major.minor.patch` version as printed by `bd version`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct BdVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl BdVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Find the first version number in `bd version` output, e.g.
+    /// `bd version 0.29.0 (dev)` or `v0.30.1`. A missing patch counts as 0.
+    pub fn parse(output: &str) -> Option<Self> {
+        output.split_whitespace().find_map(|word| {
+            let word = word.trim_start_matches('v');
+            let numbers = word.split(['-', '+']).next()?;
+            let mut parts = numbers.split('.').map(|p| p.parse::<u32>().ok());
+            let major = parts.next()??;
+            let minor = parts.next()??;
+            let patch = parts.next().unwrap_or(Some(0))?;
+            Some(Self::new(major, minor, patch))
+        })
+    }
+}
+
+impl fmt::Display for BdVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Which optional `bd` features the installed binary supports.
+///
+/// Each flag is detected from the command's `--help` output. The client falls
+/// back to simpler commands when one is missing, and views that depend on the
+/// data show a notice instead of failing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    /// `bd list --all --limit 0`, closed issues without a result cap
+    pub list_all: bool,
+    /// `bd activity --json`, the event feed behind cycle time and the heatmap
+    pub activity: bool,
+    /// `bd activity --issue`, the history of a single issue
+    pub issue_activity: bool,
+    /// `bd create --silent`, printing only the new issue ID
+    pub create_silent: bool,
+    /// `bd status --json`, the project summary used by metrics
+    pub status: bool,
+    /// `bd comments`, reading and adding issue comments
+    pub comments: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl Capabilities {
+    /// Everything supported, assumed until `bd` has been probed
+    pub const ALL: Self = Self {
+        list_all: true,
+        activity: true,
+        issue_activity: true,
+        create_silent: true,
+        status: true,
+        comments: true,
+    };
+
+    /// Name, command and support of every capability, for display
+    pub fn entries(&self) -> Vec<(&'static str, &'static str, bool)> {
+        vec![
+            ("List all issues", "list --all --limit 0", self.list_all),
+            ("Activity feed", "activity --json", self.activity),
+            ("Issue history", "activity --issue", self.issue_activity),
+            ("Quiet create", "create --silent", self.create_silent),
+            ("Status summary", "status --json", self.status),
+            ("Comments", "comments", self.comments),
+        ]
+    }
+}
+
+/// What startup probing found out about `bd`
+#[derive(Debug, Clone, Serialize)]
+pub struct BdInfo {
+    /// Binary that was run (`BD_BIN`, or `bd` on the `PATH`)
+    pub bin_path: String,
+    pub version: BdVersion,
+    /// First line of `bd version`, as printed
+    pub version_line: String,
+    /// Database the project resolves to, if one was found
+    pub db_path: Option<PathBuf>,
+    pub capabilities: Capabilities,
+}
+
+impl BdInfo {
+    pub fn is_supported(&self) -> bool {
+        self.version >= MIN_BD_VERSION
+    }
+}
+
+/// Whether `--help` output documents every one of `flags`.
+///
+/// Matches whole flags only, so `--all` is not satisfied by `--all-branches`.
+pub fn help_mentions(help: &str, flags: &[&str]) -> bool {
+    flags.iter().all(|flag| {
+        help.match_indices(flag).any(|(idx, _)| {
+            let before = help[..idx].chars().next_back();
+            let after = help[idx + flag.len()..].chars().next();
+            let boundary = |c: Option<char>| {
+                c.is_none_or(|c| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            };
+            boundary(before) && boundary(after)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version_formats() {
+        assert_eq!(
+            BdVersion::parse("bd version 0.29.0 (dev)"),
+            Some(BdVersion::new(0, 29, 0))
+        );
+        assert_eq!(
+            BdVersion::parse("bd v1.2.3-rc1\n"),
+            Some(BdVersion::new(1, 2, 3))
+        );
+        assert_eq!(BdVersion::parse("bd 0.31"), Some(BdVersion::new(0, 31, 0)));
+        assert_eq!(BdVersion::parse("bd version dev"), None);
+    }
+
+    #[test]
+    fn test_version_ordering() {
+        assert!(BdVersion::new(0, 9, 9) < MIN_BD_VERSION);
+        assert!(BdVersion::new(1, 0, 0) > BdVersion::new(0, 99, 99));
+        assert_eq!(MIN_BD_VERSION.to_string(), "0.20.0");
+    }
+
+    #[test]
+    fn test_help_mentions_whole_flags() {
+        let help = "Flags:\n  -a, --all-branches   everything\n      --limit int   max\n";
+
+        assert!(help_mentions(help, &["--limit"]));
+        assert!(!help_mentions(help, &["--all"]));
+        assert!(!help_mentions(help, &["--limit", "--all"]));
+        assert!(help_mentions("  --all, --limit=0", &["--all", "--limit"]));
+    }
+}
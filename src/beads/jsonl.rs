@@ -0,0 +1,379 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use serde::{Deserialize, de::DeserializeOwned};
+
+use super::{
+    Activity, BeadsBackend, BeadsError, Client, Comment, CommentCreate, Dependency, DependencyType,
+    Issue, IssueCreate, IssueUpdate, Result, Status, dependents_of, files_fingerprint,
+};
+
+/// Read backend that parses `.beads/issues.jsonl` directly.
+///
+/// Reads never spawn `bd`: the file is parsed once and kept until its
+/// modification time or size changes. Writes, activity and the status
+/// summary still go through the wrapped `Client`.
+pub struct JsonlBackend {
+    client: Client,
+    path: PathBuf,
+    cache: RwLock<Option<CachedIssues>>,
+}
+
+struct CachedIssues {
+    modified: SystemTime,
+    len: u64,
+    issues: Arc<Vec<Issue>>,
+}
+
+impl JsonlBackend {
+    /// Locate `issues.jsonl` through the client's `.beads` directory
+    pub fn new(client: Client) -> Result<Self> {
+        let path = client.find_beads_dir()?.join("issues.jsonl");
+        Ok(Self::from_path(client, path))
+    }
+
+    pub fn from_path(client: Client, path: PathBuf) -> Self {
+        Self {
+            client,
+            path,
+            cache: RwLock::new(None),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the parsed issues, re-reading the file only when it changed
+    async fn issues(&self) -> Result<Arc<Vec<Issue>>> {
+        let metadata = match std::fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Arc::new(Vec::new()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let modified = metadata.modified()?;
+        let len = metadata.len();
+
+        if let Some(cached) = self.cache.read().unwrap().as_ref()
+            && cached.modified == modified
+            && cached.len == len
+        {
+            return Ok(cached.issues.clone());
+        }
+
+        let path = self.path.clone();
+        let issues = tokio::task::spawn_blocking(move || read_issues(&path))
+            .await
+            .map_err(|e| BeadsError::CommandError(format!("Failed to read JSONL: {}", e)))??;
+        let issues = Arc::new(issues);
+        *self.cache.write().unwrap() = Some(CachedIssues {
+            modified,
+            len,
+            issues: issues.clone(),
+        });
+        Ok(issues)
+    }
+
+    /// Ask `bd` to export pending changes so the next read sees our write
+    async fn flush(&self) {
+        if let Err(e) = self.client.flush().await {
+            tracing::debug!(error = %e, "Failed to flush beads JSONL export");
+        }
+    }
+}
+
+/// Parse every issue in a beads JSONL export.
+///
+/// Lines that fail to parse are skipped (and logged) so a single odd record
+/// does not take the whole dashboard down.
+pub fn read_issues(path: &Path) -> Result<Vec<Issue>> {
+    read_records(path)
+}
+
+/// Every dependency in a beads JSONL export.
+///
+/// Only the `dependencies` of each line are parsed, so a record that is not
+/// a valid issue still contributes its edges.
+pub fn read_dependencies(path: &Path) -> Result<Vec<Dependency>> {
+    #[derive(Deserialize)]
+    struct IssueWithDeps {
+        #[serde(default)]
+        dependencies: Vec<Dependency>,
+    }
+
+    Ok(read_records::<IssueWithDeps>(path)?
+        .into_iter()
+        .flat_map(|issue| issue.dependencies)
+        .collect())
+}
+
+fn read_records<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| BeadsError::CommandError(format!("Failed to read JSONL: {}", e)))?;
+
+    let mut records = Vec::new();
+    for (line_no, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<T>(line) {
+            Ok(record) => records.push(record),
+            Err(e) => {
+                tracing::debug!(line = line_no + 1, error = %e, "Skipping unparsable JSONL line")
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+#[async_trait]
+impl BeadsBackend for JsonlBackend {
+    async fn list_issues(&self) -> Result<Vec<Issue>> {
+        Ok(self
+            .issues()
+            .await?
+            .iter()
+            .filter(|i| i.status != Status::Closed && i.status != Status::Tombstone)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_all_issues(&self) -> Result<Vec<Issue>> {
+        Ok(self
+            .issues()
+            .await?
+            .iter()
+            .filter(|i| i.status != Status::Tombstone)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<Issue> {
+        let issues = self.issues().await?;
+        let mut issue = issues
+            .iter()
+            .find(|i| i.id == id)
+            .cloned()
+            .ok_or_else(|| BeadsError::NotFound(id.to_string()))?;
+        issue.dependents = dependents_of(id, issues.iter().flat_map(|i| &i.dependencies));
+        Ok(issue)
+    }
+
+    async fn update_issue(&self, id: &str, update: IssueUpdate) -> Result<()> {
+        self.client.update_issue(id, update).await?;
+        self.flush().await;
+        Ok(())
+    }
+
+    async fn create_issue(&self, create: IssueCreate) -> Result<String> {
+        let id = self.client.create_issue(create).await?;
+        self.flush().await;
+        Ok(id)
+    }
+
+    async fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.client.close_issue(id, reason).await?;
+        self.flush().await;
+        Ok(())
+    }
+
+    async fn reopen_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.client.reopen_issue(id, reason).await?;
+        self.flush().await;
+        Ok(())
+    }
+
+    async fn delete_issue(&self, id: &str) -> Result<()> {
+        self.client.delete_issue(id).await?;
+        self.flush().await;
+        Ok(())
+    }
+
+    async fn restore_issue(&self, id: &str) -> Result<()> {
+        self.client.restore_issue(id).await?;
+        self.flush().await;
+        Ok(())
+    }
+
+    async fn list_tombstones(&self) -> Result<Vec<Issue>> {
+        Ok(self
+            .issues()
+            .await?
+            .iter()
+            .filter(|i| i.status == Status::Tombstone)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_activity(&self) -> Result<Vec<Activity>> {
+        self.client.get_activity().await
+    }
+
+    async fn get_issue_activity(&self, id: &str) -> Result<Vec<Activity>> {
+        self.client.get_issue_activity(id).await
+    }
+
+    async fn add_dependency(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &DependencyType,
+    ) -> Result<()> {
+        self.client
+            .add_dependency(issue_id, depends_on_id, dep_type)
+            .await?;
+        self.flush().await;
+        Ok(())
+    }
+
+    async fn remove_dependency(&self, issue_id: &str, depends_on_id: &str) -> Result<()> {
+        self.client
+            .remove_dependency(issue_id, depends_on_id)
+            .await?;
+        self.flush().await;
+        Ok(())
+    }
+
+    async fn list_comments(&self, id: &str) -> Result<Vec<Comment>> {
+        self.client.list_comments(id).await
+    }
+
+    async fn add_comment(&self, id: &str, comment: CommentCreate) -> Result<Comment> {
+        let comment = self.client.add_comment(id, comment).await?;
+        self.flush().await;
+        Ok(comment)
+    }
+
+    async fn get_status_summary(&self) -> Result<serde_json::Value> {
+        self.client.get_status_summary().await
+    }
+
+    async fn list_all_dependencies(&self) -> Result<Vec<Dependency>> {
+        Ok(self
+            .issues()
+            .await?
+            .iter()
+            .flat_map(|i| i.dependencies.iter().cloned())
+            .collect())
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(files_fingerprint(std::slice::from_ref(&self.path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::DependencyType;
+    use std::io::Write;
+
+    const FIXTURE: &str = r#"{"id":"nacre-1","title":"Epic","status":"open","priority":1,"issue_type":"epic","created_at":"2025-12-27T21:25:14.603402+01:00","updated_at":"2025-12-28T20:57:26.1652+01:00","labels":["ui"]}
+{"id":"nacre-1.1","title":"Child","status":"in_progress","priority":2,"issue_type":"task","assignee":"agent","created_at":"2025-12-27T21:25:14+01:00","updated_at":"2025-12-28T20:57:26+01:00","dependencies":[{"issue_id":"nacre-1.1","depends_on_id":"nacre-1","type":"parent-child","created_at":"2025-12-27T21:25:14+01:00","created_by":"l1x"}]}
+{"id":"nacre-2","title":"Done","status":"closed","priority":2,"issue_type":"bug","created_at":"2025-12-27T21:25:14+01:00","updated_at":"2025-12-28T20:57:26+01:00","closed_at":"2025-12-28T20:57:26+01:00","close_reason":"fixed"}
+{"id":"nacre-3","title":"Gone","status":"tombstone","priority":2,"issue_type":"task","created_at":"2025-12-27T21:25:14+01:00","updated_at":"2025-12-28T20:57:26+01:00","deleted_at":"2025-12-28T20:57:26+01:00","deleted_by":"batch delete"}
+not json at all
+"#;
+
+    fn backend_with(content: &str) -> (tempfile::TempDir, JsonlBackend) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("issues.jsonl");
+        std::fs::write(&path, content).unwrap();
+        let backend = JsonlBackend::from_path(Client::new(), path);
+        (dir, backend)
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_excludes_closed_and_tombstones() {
+        let (_dir, backend) = backend_with(FIXTURE);
+
+        let open: Vec<String> = backend
+            .list_issues()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        assert_eq!(open, vec!["nacre-1", "nacre-1.1"]);
+
+        let all = backend.list_all_issues().await.unwrap();
+        assert_eq!(all.len(), 3);
+        assert!(all.iter().all(|i| i.status != Status::Tombstone));
+    }
+
+    #[tokio::test]
+    async fn test_issue_fields_and_dependencies_are_loaded() {
+        let (_dir, backend) = backend_with(FIXTURE);
+
+        let child = backend.get_issue("nacre-1.1").await.unwrap();
+        assert_eq!(child.assignee.as_deref(), Some("agent"));
+        assert_eq!(child.dependencies.len(), 1);
+        assert_eq!(child.dependencies[0].dep_type, DependencyType::ParentChild);
+
+        let epic = backend.get_issue("nacre-1").await.unwrap();
+        assert_eq!(epic.labels, Some(vec!["ui".to_string()]));
+
+        let deps = backend.list_all_dependencies().await.unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].depends_on_id, "nacre-1");
+
+        let tombstones = backend.list_tombstones().await.unwrap();
+        assert_eq!(tombstones[0].deleted_by.as_deref(), Some("batch delete"));
+        assert!(tombstones[0].deleted_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_not_found() {
+        let (_dir, backend) = backend_with(FIXTURE);
+
+        assert!(matches!(
+            backend.get_issue("nacre-404").await,
+            Err(BeadsError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let backend = JsonlBackend::from_path(Client::new(), dir.path().join("issues.jsonl"));
+
+        assert!(backend.list_all_issues().await.unwrap().is_empty());
+        assert!(backend.list_all_dependencies().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reloads_when_file_changes() {
+        let (_dir, backend) = backend_with(FIXTURE);
+        assert_eq!(backend.list_all_issues().await.unwrap().len(), 3);
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(backend.path())
+            .unwrap();
+        writeln!(
+            file,
+            r#"{{"id":"nacre-4","title":"New","status":"open","issue_type":"task","created_at":"2025-12-29T10:00:00Z","updated_at":"2025-12-29T10:00:00Z"}}"#
+        )
+        .unwrap();
+
+        assert_eq!(backend.list_all_issues().await.unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_read_dependencies_keeps_edges_of_invalid_issues() {
+        let (_dir, backend) = backend_with(
+            r#"{"id":"nacre-5","status":"open","dependencies":[{"issue_id":"nacre-5","depends_on_id":"nacre-1","type":"blocks"}]}
+"#,
+        );
+
+        assert!(read_issues(backend.path()).unwrap().is_empty());
+        let dependencies = read_dependencies(backend.path()).unwrap();
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].depends_on_id, "nacre-1");
+    }
+}
@@ -0,0 +1,682 @@
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use super::{
+    Activity, BeadsBackend, BeadsError, Comment, CommentCreate, Dependency, DependencyType,
+    EventType, Issue, IssueCreate, IssueType, IssueUpdate, Result, Status, dependents_of,
+};
+
+/// In-memory backend holding issues and activity in a `RwLock`.
+///
+/// Used to drive the whole router in tests without a `bd` binary or a
+/// `.beads` database. Writes behave like their `bd` counterparts: they bump
+/// `updated_at`, set `closed_at` and record activity entries.
+pub struct MemoryBackend {
+    prefix: String,
+    data: RwLock<MemoryData>,
+}
+
+#[derive(Default)]
+struct MemoryData {
+    issues: Vec<Issue>,
+    activity: Vec<Activity>,
+    comments: Vec<Comment>,
+    next_id: u32,
+    /// Bumped on every write, used as the fingerprint
+    revision: u64,
+}
+
+impl Default for MemoryBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryBackend {
+    pub fn new() -> Self {
+        Self {
+            prefix: "mem".to_string(),
+            data: RwLock::new(MemoryData {
+                next_id: 1,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Seed the backend with issues
+    pub fn with_issues(self, issues: Vec<Issue>) -> Self {
+        self.data.write().unwrap().issues = issues;
+        self
+    }
+
+    /// Seed the backend with activity entries
+    pub fn with_activity(self, activity: Vec<Activity>) -> Self {
+        self.data.write().unwrap().activity = activity;
+        self
+    }
+
+    /// Prefix for generated issue IDs (default `mem`)
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+}
+
+impl MemoryData {
+    fn record(&mut self, r#type: EventType, issue_id: &str, message: String) -> &mut Activity {
+        self.revision += 1;
+        self.activity.push(Activity {
+            timestamp: OffsetDateTime::now_utc(),
+            r#type,
+            issue_id: issue_id.to_string(),
+            message,
+            old_status: None,
+            new_status: None,
+            actor: None,
+        });
+        self.activity.last_mut().unwrap()
+    }
+
+    /// Move an issue to `status`, recording `event` with the transition
+    fn set_status(&mut self, id: &str, status: Status, event: EventType) -> Result<()> {
+        let issue = self
+            .issues
+            .iter_mut()
+            .find(|i| i.id == id)
+            .ok_or_else(|| BeadsError::NotFound(id.to_string()))?;
+        let old_status = std::mem::replace(&mut issue.status, status.clone());
+        issue.updated_at = OffsetDateTime::now_utc();
+
+        let message = format!("{} {} → {}", id, old_status.as_str(), status.as_str());
+        let activity = self.record(event, id, message);
+        activity.old_status = Some(old_status);
+        activity.new_status = Some(status);
+        Ok(())
+    }
+
+    /// Move an issue to `status` the way `bd` does: closing sets `closed_at`
+    /// and `close_reason`, leaving `closed` clears them again
+    fn change_status(&mut self, id: &str, status: Status, reason: Option<&str>) -> Result<()> {
+        let now = OffsetDateTime::now_utc();
+        let issue = self
+            .issues
+            .iter_mut()
+            .find(|i| i.id == id)
+            .ok_or_else(|| BeadsError::NotFound(id.to_string()))?;
+
+        let old_status = std::mem::replace(&mut issue.status, status.clone());
+        let event = if status == Status::Closed {
+            issue.closed_at = Some(now);
+            issue.close_reason = reason.map(str::to_string);
+            EventType::Closed
+        } else if old_status == Status::Closed {
+            issue.closed_at = None;
+            issue.close_reason = None;
+            EventType::Reopened
+        } else {
+            EventType::StatusChanged
+        };
+        issue.updated_at = now;
+
+        let message = match (&event, reason) {
+            (EventType::StatusChanged, _) => {
+                format!("{} {} → {}", id, old_status.as_str(), status.as_str())
+            }
+            (_, Some(reason)) => format!("{} {}: {}", id, event.as_str(), reason),
+            (_, None) => format!("{} {}", id, event.as_str()),
+        };
+        let activity = self.record(event, id, message);
+        activity.old_status = Some(old_status);
+        activity.new_status = Some(status);
+        Ok(())
+    }
+}
+
+fn parse_issue_type(value: &str) -> Result<IssueType> {
+    match IssueType::from(value) {
+        IssueType::Unknown(_) => Err(BeadsError::CommandError(format!(
+            "Invalid issue type: {}",
+            value
+        ))),
+        issue_type => Ok(issue_type),
+    }
+}
+
+#[async_trait]
+impl BeadsBackend for MemoryBackend {
+    async fn list_issues(&self) -> Result<Vec<Issue>> {
+        let data = self.data.read().unwrap();
+        Ok(data
+            .issues
+            .iter()
+            .filter(|i| i.status != Status::Closed && i.status != Status::Tombstone)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_all_issues(&self) -> Result<Vec<Issue>> {
+        let data = self.data.read().unwrap();
+        Ok(data
+            .issues
+            .iter()
+            .filter(|i| i.status != Status::Tombstone)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<Issue> {
+        let data = self.data.read().unwrap();
+        let mut issue = data
+            .issues
+            .iter()
+            .find(|i| i.id == id)
+            .cloned()
+            .ok_or_else(|| BeadsError::NotFound(id.to_string()))?;
+        issue.dependents = dependents_of(id, data.issues.iter().flat_map(|i| &i.dependencies));
+        Ok(issue)
+    }
+
+    async fn update_issue(&self, id: &str, update: IssueUpdate) -> Result<()> {
+        let mut data = self.data.write().unwrap();
+        let now = OffsetDateTime::now_utc();
+        let issue = data
+            .issues
+            .iter_mut()
+            .find(|i| i.id == id)
+            .ok_or_else(|| BeadsError::NotFound(id.to_string()))?;
+
+        if let Some(title) = update.title {
+            issue.title = title;
+        }
+        let status = update.status.filter(|status| *status != issue.status);
+        if let Some(priority) = update.priority {
+            issue.priority = Some(priority);
+        }
+        if let Some(description) = update.description {
+            issue.description = Some(description);
+        }
+        if let Some(assignee) = update.assignee {
+            issue.assignee = (!assignee.is_empty()).then_some(assignee);
+        }
+        if !update.add_labels.is_empty() || !update.remove_labels.is_empty() {
+            let labels = issue.labels.get_or_insert_default();
+            for label in update.add_labels {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+            labels.retain(|l| !update.remove_labels.contains(l));
+        }
+        if let Some(acceptance) = update.acceptance_criteria {
+            issue.acceptance_criteria = Some(acceptance);
+        }
+        if let Some(estimate) = update.estimate {
+            issue.estimate = Some(estimate);
+        }
+        if let Some(issue_type) = update.issue_type {
+            issue.issue_type = issue_type;
+        }
+        if status.is_none()
+            && issue.status == Status::Closed
+            && let Some(reason) = &update.close_reason
+        {
+            issue.close_reason = Some(reason.clone());
+        }
+        issue.updated_at = now;
+
+        match status {
+            Some(status) => data.change_status(id, status, update.close_reason.as_deref())?,
+            None => {
+                data.record(EventType::Updated, id, format!("{} updated", id));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn create_issue(&self, create: IssueCreate) -> Result<String> {
+        let issue_type = match &create.issue_type {
+            Some(t) => parse_issue_type(t)?,
+            None => IssueType::default(),
+        };
+
+        let mut data = self.data.write().unwrap();
+        // Check every link first so a failure leaves nothing behind
+        for (depends_on_id, _) in create.links() {
+            if !data.issues.iter().any(|i| i.id == depends_on_id) {
+                return Err(BeadsError::NotFound(depends_on_id.to_string()));
+            }
+        }
+        let id = format!("{}-{}", self.prefix, data.next_id);
+        data.next_id += 1;
+        let dependencies = create
+            .links()
+            .map(|(depends_on_id, dep_type)| Dependency {
+                issue_id: id.clone(),
+                depends_on_id: depends_on_id.to_string(),
+                dep_type,
+                created_at: None,
+                created_by: None,
+            })
+            .collect();
+
+        let now = OffsetDateTime::now_utc();
+        data.issues.push(Issue {
+            id: id.clone(),
+            title: create.title,
+            status: Status::Open,
+            priority: Some(create.priority.unwrap_or(2)),
+            issue_type,
+            created_at: now,
+            updated_at: now,
+            closed_at: None,
+            assignee: create.assignee,
+            labels: (!create.labels.is_empty()).then_some(create.labels),
+            description: create.description,
+            acceptance_criteria: create.acceptance_criteria,
+            close_reason: None,
+            estimate: create.estimate,
+            dependencies,
+            deleted_at: None,
+            deleted_by: None,
+            dependents: vec![],
+        });
+        data.record(EventType::Created, &id, format!("{} created", id));
+
+        Ok(id)
+    }
+
+    async fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.data
+            .write()
+            .unwrap()
+            .change_status(id, Status::Closed, reason)
+    }
+
+    async fn reopen_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.data
+            .write()
+            .unwrap()
+            .change_status(id, Status::Open, reason)
+    }
+
+    async fn delete_issue(&self, id: &str) -> Result<()> {
+        let mut data = self.data.write().unwrap();
+        data.set_status(id, Status::Tombstone, EventType::Deleted)?;
+        if let Some(issue) = data.issues.iter_mut().find(|i| i.id == id) {
+            issue.deleted_at = Some(issue.updated_at);
+        }
+        Ok(())
+    }
+
+    async fn restore_issue(&self, id: &str) -> Result<()> {
+        let mut data = self.data.write().unwrap();
+        data.set_status(id, Status::Open, EventType::StatusChanged)?;
+        if let Some(issue) = data.issues.iter_mut().find(|i| i.id == id) {
+            issue.deleted_at = None;
+            issue.deleted_by = None;
+        }
+        Ok(())
+    }
+
+    async fn list_tombstones(&self) -> Result<Vec<Issue>> {
+        let data = self.data.read().unwrap();
+        Ok(data
+            .issues
+            .iter()
+            .filter(|i| i.status == Status::Tombstone)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_activity(&self) -> Result<Vec<Activity>> {
+        Ok(self.data.read().unwrap().activity.clone())
+    }
+
+    async fn get_issue_activity(&self, id: &str) -> Result<Vec<Activity>> {
+        let data = self.data.read().unwrap();
+        if !data.issues.iter().any(|i| i.id == id) {
+            return Err(BeadsError::NotFound(id.to_string()));
+        }
+        Ok(data
+            .activity
+            .iter()
+            .filter(|a| a.issue_id == id)
+            .cloned()
+            .collect())
+    }
+
+    async fn add_dependency(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &DependencyType,
+    ) -> Result<()> {
+        let mut data = self.data.write().unwrap();
+        if !data.issues.iter().any(|i| i.id == depends_on_id) {
+            return Err(BeadsError::NotFound(depends_on_id.to_string()));
+        }
+        let issue = data
+            .issues
+            .iter_mut()
+            .find(|i| i.id == issue_id)
+            .ok_or_else(|| BeadsError::NotFound(issue_id.to_string()))?;
+
+        issue
+            .dependencies
+            .retain(|d| d.depends_on_id != depends_on_id);
+        issue.dependencies.push(Dependency {
+            issue_id: issue_id.to_string(),
+            depends_on_id: depends_on_id.to_string(),
+            dep_type: dep_type.clone(),
+            created_at: Some(OffsetDateTime::now_utc()),
+            created_by: None,
+        });
+        data.record(
+            EventType::DependencyAdded,
+            issue_id,
+            format!("{} depends on {}", issue_id, depends_on_id),
+        );
+        Ok(())
+    }
+
+    async fn remove_dependency(&self, issue_id: &str, depends_on_id: &str) -> Result<()> {
+        let mut data = self.data.write().unwrap();
+        let issue = data
+            .issues
+            .iter_mut()
+            .find(|i| i.id == issue_id)
+            .ok_or_else(|| BeadsError::NotFound(issue_id.to_string()))?;
+
+        let before = issue.dependencies.len();
+        issue
+            .dependencies
+            .retain(|d| d.depends_on_id != depends_on_id);
+        if issue.dependencies.len() == before {
+            return Err(BeadsError::NotFound(format!(
+                "{} → {}",
+                issue_id, depends_on_id
+            )));
+        }
+        data.record(
+            EventType::DependencyRemoved,
+            issue_id,
+            format!("{} no longer depends on {}", issue_id, depends_on_id),
+        );
+        Ok(())
+    }
+
+    async fn list_comments(&self, id: &str) -> Result<Vec<Comment>> {
+        let data = self.data.read().unwrap();
+        if !data.issues.iter().any(|i| i.id == id) {
+            return Err(BeadsError::NotFound(id.to_string()));
+        }
+        Ok(data
+            .comments
+            .iter()
+            .filter(|c| c.issue_id == id)
+            .cloned()
+            .collect())
+    }
+
+    async fn add_comment(&self, id: &str, comment: CommentCreate) -> Result<Comment> {
+        let mut data = self.data.write().unwrap();
+        if !data.issues.iter().any(|i| i.id == id) {
+            return Err(BeadsError::NotFound(id.to_string()));
+        }
+
+        let comment = Comment {
+            id: data.comments.len() as i64 + 1,
+            issue_id: id.to_string(),
+            author: comment.author.unwrap_or_else(|| "nacre".to_string()),
+            text: comment.text,
+            created_at: OffsetDateTime::now_utc(),
+        };
+        data.comments.push(comment.clone());
+        data.record(EventType::Commented, id, format!("{} commented", id));
+
+        Ok(comment)
+    }
+
+    async fn get_status_summary(&self) -> Result<serde_json::Value> {
+        let data = self.data.read().unwrap();
+        let count = |status: Status| data.issues.iter().filter(|i| i.status == status).count();
+        Ok(serde_json::json!({
+            "summary": {
+                "total_issues": data.issues.len(),
+                "open_issues": count(Status::Open),
+                "in_progress_issues": count(Status::InProgress),
+                "blocked_issues": count(Status::Blocked),
+                "closed_issues": count(Status::Closed),
+            }
+        }))
+    }
+
+    async fn list_all_dependencies(&self) -> Result<Vec<Dependency>> {
+        let data = self.data.read().unwrap();
+        Ok(data
+            .issues
+            .iter()
+            .flat_map(|i| i.dependencies.iter().cloned())
+            .collect())
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(self.data.read().unwrap().revision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create(title: &str) -> IssueCreate {
+        IssueCreate {
+            title: title.to_string(),
+            issue_type: Some("bug".to_string()),
+            priority: Some(1),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_assigns_sequential_ids() {
+        let backend = MemoryBackend::new().with_prefix("t");
+
+        assert_eq!(backend.create_issue(create("first")).await.unwrap(), "t-1");
+        assert_eq!(backend.create_issue(create("second")).await.unwrap(), "t-2");
+
+        let issue = backend.get_issue("t-2").await.unwrap();
+        assert_eq!(issue.title, "second");
+        assert_eq!(issue.issue_type, IssueType::Bug);
+        assert_eq!(issue.status, Status::Open);
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_unknown_type() {
+        let backend = MemoryBackend::new();
+        let mut request = create("bad");
+        request.issue_type = Some("nonsense".to_string());
+
+        assert!(backend.create_issue(request).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_close_sets_closed_at_and_hides_from_list() {
+        let backend = MemoryBackend::new();
+        let id = backend.create_issue(create("to close")).await.unwrap();
+
+        backend
+            .update_issue(
+                &id,
+                IssueUpdate {
+                    status: Some(Status::Closed),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(backend.list_issues().await.unwrap().is_empty());
+        let all = backend.list_all_issues().await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert!(all[0].closed_at.is_some());
+
+        let activity = backend.get_activity().await.unwrap();
+        let status_change = activity
+            .iter()
+            .find(|a| a.r#type == EventType::Closed)
+            .unwrap();
+        assert_eq!(status_change.old_status, Some(Status::Open));
+        assert_eq!(status_change.new_status, Some(Status::Closed));
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_includes_dependents() {
+        let backend = MemoryBackend::new().with_prefix("t");
+        let blocker = backend.create_issue(create("blocker")).await.unwrap();
+        let blocked = backend.create_issue(create("blocked")).await.unwrap();
+        backend.data.write().unwrap().issues[1]
+            .dependencies
+            .push(Dependency {
+                issue_id: blocked.clone(),
+                depends_on_id: blocker.clone(),
+                dep_type: DependencyType::Blocks,
+                created_at: None,
+                created_by: None,
+            });
+
+        let issue = backend.get_issue(&blocker).await.unwrap();
+        assert_eq!(issue.dependents.len(), 1);
+        assert_eq!(issue.dependents[0].issue_id, blocked);
+        assert!(
+            backend
+                .get_issue(&blocked)
+                .await
+                .unwrap()
+                .dependents
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_comments_are_listed_per_issue() {
+        let backend = MemoryBackend::new();
+        let first = backend.create_issue(create("first")).await.unwrap();
+        let second = backend.create_issue(create("second")).await.unwrap();
+
+        let comment = backend
+            .add_comment(
+                &first,
+                CommentCreate {
+                    text: "Started on this".to_string(),
+                    author: Some("agent-1".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(comment.author, "agent-1");
+        assert_eq!(backend.list_comments(&first).await.unwrap().len(), 1);
+        assert!(backend.list_comments(&second).await.unwrap().is_empty());
+        assert!(matches!(
+            backend.list_comments("missing").await,
+            Err(BeadsError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_edits_labels_assignee_and_close_reason() {
+        let backend = MemoryBackend::new();
+        let id = backend.create_issue(create("Triage me")).await.unwrap();
+
+        backend
+            .update_issue(
+                &id,
+                IssueUpdate {
+                    assignee: Some("ada".to_string()),
+                    add_labels: vec!["ui".to_string(), "api".to_string()],
+                    issue_type: Some(IssueType::Bug),
+                    estimate: Some(90),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        backend
+            .update_issue(
+                &id,
+                IssueUpdate {
+                    assignee: Some(String::new()),
+                    remove_labels: vec!["ui".to_string()],
+                    status: Some(Status::Closed),
+                    close_reason: Some("Fixed upstream".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let issue = backend.get_issue(&id).await.unwrap();
+        assert_eq!(issue.assignee, None);
+        assert_eq!(issue.labels, Some(vec!["api".to_string()]));
+        assert_eq!(issue.issue_type, IssueType::Bug);
+        assert_eq!(issue.estimate, Some(90));
+        assert_eq!(issue.close_reason.as_deref(), Some("Fixed upstream"));
+    }
+
+    #[tokio::test]
+    async fn test_update_status_closes_and_reopens_like_bd() {
+        let backend = MemoryBackend::new();
+        let id = backend.create_issue(create("Ship it")).await.unwrap();
+        let set_status = |status: Status| IssueUpdate {
+            status: Some(status),
+            close_reason: Some("Done".to_string()),
+            ..Default::default()
+        };
+
+        backend
+            .update_issue(&id, set_status(Status::Closed))
+            .await
+            .unwrap();
+        let issue = backend.get_issue(&id).await.unwrap();
+        assert!(issue.closed_at.is_some());
+        assert_eq!(issue.close_reason.as_deref(), Some("Done"));
+
+        backend
+            .update_issue(&id, set_status(Status::InProgress))
+            .await
+            .unwrap();
+        let issue = backend.get_issue(&id).await.unwrap();
+        assert_eq!(issue.status, Status::InProgress);
+        assert_eq!(issue.closed_at, None);
+        assert_eq!(issue.close_reason, None);
+
+        let events: Vec<EventType> = backend
+            .get_issue_activity(&id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|a| a.r#type)
+            .collect();
+        assert!(events.contains(&EventType::Closed));
+        assert!(events.contains(&EventType::Reopened));
+        assert!(!events.contains(&EventType::StatusChanged));
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_issue_is_not_found() {
+        let backend = MemoryBackend::new();
+        let update = IssueUpdate {
+            title: Some("x".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            backend.update_issue("missing", update).await,
+            Err(BeadsError::NotFound(_))
+        ));
+    }
+}
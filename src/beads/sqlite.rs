@@ -0,0 +1,661 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rusqlite::{Connection, OpenFlags, Row};
+use time::OffsetDateTime;
+
+use super::{
+    Activity, BeadsBackend, BeadsError, Client, Comment, CommentCreate, Dependency, DependencyType,
+    EventType, Issue, IssueCreate, IssueType, IssueUpdate, Result, Status, dependents_of,
+    files_fingerprint,
+};
+
+/// Read-only backend that queries the beads SQLite database directly.
+///
+/// Issues, labels, dependencies and events are read with plain SQL, so
+/// `get_issue` returns full dependency data and activity does not need
+/// `bd activity`. Writes and the status summary go through the wrapped
+/// `Client`.
+///
+/// Every query opens its own read-only connection on the blocking thread
+/// pool, so queries run in parallel and never stall the async runtime.
+pub struct SqliteBackend {
+    client: Client,
+    path: PathBuf,
+    /// Optional `issues` columns present in this database's schema
+    columns: Arc<HashSet<String>>,
+}
+
+impl From<rusqlite::Error> for BeadsError {
+    fn from(e: rusqlite::Error) -> Self {
+        BeadsError::CommandError(format!("SQLite error: {}", e))
+    }
+}
+
+/// Columns that older beads schemas may not have; selected as NULL if missing
+const OPTIONAL_COLUMNS: &[&str] = &[
+    "acceptance_criteria",
+    "assignee",
+    "close_reason",
+    "estimated_minutes",
+    "deleted_at",
+    "deleted_by",
+];
+
+fn connect(path: &Path) -> Result<Connection> {
+    Ok(Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?)
+}
+
+impl SqliteBackend {
+    /// Open the database the client points at (`BEADS_DB`, or the one named
+    /// in `.beads/metadata.json`)
+    pub fn new(client: Client) -> Result<Self> {
+        let path = client.database_path()?;
+        Self::open(client, path)
+    }
+
+    pub fn open(client: Client, path: PathBuf) -> Result<Self> {
+        let conn = connect(&path)?;
+        let columns = {
+            let mut stmt = conn.prepare("SELECT name FROM pragma_table_info('issues')")?;
+            stmt.query_map([], |row| row.get::<_, String>(0))?
+                .collect::<std::result::Result<HashSet<_>, _>>()?
+        };
+        if !columns.contains("id") {
+            return Err(BeadsError::CommandError(format!(
+                "{} is not a beads database",
+                path.display()
+            )));
+        }
+
+        Ok(Self {
+            client,
+            path,
+            columns: Arc::new(columns),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Run `query` against a fresh connection on the blocking thread pool
+    async fn query<T, F>(&self, query: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection, &HashSet<String>) -> Result<T> + Send + 'static,
+    {
+        let path = self.path.clone();
+        let columns = Arc::clone(&self.columns);
+        tokio::task::spawn_blocking(move || query(&connect(&path)?, &columns))
+            .await
+            .map_err(|e| BeadsError::CommandError(format!("SQLite query failed: {}", e)))?
+    }
+}
+
+/// Events matching `filter`, oldest first
+fn select_activity(
+    conn: &Connection,
+    filter: &str,
+    params: &[&dyn rusqlite::ToSql],
+) -> Result<Vec<Activity>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT issue_id, event_type, actor, old_value, new_value, comment, created_at \
+         FROM events {} ORDER BY created_at, id",
+        filter
+    ))?;
+
+    let rows = stmt.query_map(params, |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+            row.get::<_, Option<String>>(3)?,
+            row.get::<_, Option<String>>(4)?,
+            row.get::<_, Option<String>>(5)?,
+            row.get::<_, Option<String>>(6)?,
+        ))
+    })?;
+
+    let mut activities = Vec::new();
+    for row in rows {
+        let (issue_id, event_type, actor, old_value, new_value, comment, created_at) = row?;
+        let Some(timestamp) = created_at.as_deref().and_then(parse_timestamp) else {
+            continue;
+        };
+        let r#type = event_type_from_db(&event_type);
+
+        let (old_status, new_status) = if r#type == EventType::StatusChanged {
+            (
+                status_from_event_value(old_value.as_deref()),
+                status_from_event_value(new_value.as_deref()),
+            )
+        } else {
+            (None, None)
+        };
+
+        let message = match (comment.filter(|c| !c.is_empty()), &actor) {
+            (Some(comment), _) => format!("{} {}", issue_id, comment),
+            (None, Some(actor)) => format!("{} {} by {}", issue_id, event_type, actor),
+            (None, None) => format!("{} {}", issue_id, event_type),
+        };
+
+        activities.push(Activity {
+            timestamp,
+            r#type,
+            issue_id,
+            message,
+            old_status,
+            new_status,
+            actor,
+        });
+    }
+
+    Ok(activities)
+}
+
+/// Issues matching `filter`, with the labels and dependencies of just those
+/// issues
+fn select_issues(
+    conn: &Connection,
+    columns: &HashSet<String>,
+    filter: &str,
+    params: &[&dyn rusqlite::ToSql],
+) -> Result<Vec<Issue>> {
+    let optional: Vec<String> = OPTIONAL_COLUMNS
+        .iter()
+        .map(|c| {
+            if columns.contains(*c) {
+                c.to_string()
+            } else {
+                format!("NULL AS {}", c)
+            }
+        })
+        .collect();
+    let sql = format!(
+        "SELECT id, title, description, status, priority, issue_type, created_at, updated_at, \
+         closed_at, {} FROM issues {} ORDER BY id",
+        optional.join(", "),
+        filter
+    );
+
+    let mut issues = {
+        let mut stmt = conn.prepare(&sql)?;
+        stmt.query_map(params, issue_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+    };
+
+    let of_selected = format!("WHERE issue_id IN (SELECT id FROM issues {})", filter);
+    let mut labels = load_labels(conn, &of_selected, params)?;
+    let mut dependencies = load_dependencies(conn, &of_selected, params)?;
+    for issue in &mut issues {
+        issue.labels = labels.remove(&issue.id);
+        issue.dependencies = dependencies.remove(&issue.id).unwrap_or_default();
+    }
+
+    Ok(issues)
+}
+
+/// Parse a timestamp as stored by the Go SQLite driver.
+///
+/// Accepts RFC 3339 as well as SQLite's `YYYY-MM-DD HH:MM:SS[.fff][±HH:MM]`
+/// form; timestamps without an offset are taken as UTC.
+pub fn parse_timestamp(value: &str) -> Option<OffsetDateTime> {
+    use time::format_description::well_known::Rfc3339;
+
+    let value = value.trim();
+    if let Ok(ts) = OffsetDateTime::parse(value, &Rfc3339) {
+        return Some(ts);
+    }
+
+    let normalized = value.replacen(' ', "T", 1);
+    if let Ok(ts) = OffsetDateTime::parse(&normalized, &Rfc3339) {
+        return Some(ts);
+    }
+    OffsetDateTime::parse(&format!("{}Z", normalized), &Rfc3339).ok()
+}
+
+fn timestamp_column(row: &Row, name: &str) -> rusqlite::Result<Option<OffsetDateTime>> {
+    Ok(row
+        .get::<_, Option<String>>(name)?
+        .as_deref()
+        .and_then(parse_timestamp))
+}
+
+/// Empty strings are how beads stores "not set" for text columns
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty())
+}
+
+fn issue_from_row(row: &Row) -> rusqlite::Result<Issue> {
+    let created_at = timestamp_column(row, "created_at")?.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+    Ok(Issue {
+        id: row.get("id")?,
+        title: row.get("title")?,
+        status: Status::from(row.get::<_, String>("status")?.as_str()),
+        priority: row.get("priority")?,
+        issue_type: IssueType::from(row.get::<_, String>("issue_type")?.as_str()),
+        created_at,
+        updated_at: timestamp_column(row, "updated_at")?.unwrap_or(created_at),
+        closed_at: timestamp_column(row, "closed_at")?,
+        assignee: non_empty(row.get("assignee")?),
+        labels: None,
+        description: non_empty(row.get("description")?),
+        acceptance_criteria: non_empty(row.get("acceptance_criteria")?),
+        close_reason: non_empty(row.get("close_reason")?),
+        estimate: row.get("estimated_minutes")?,
+        dependencies: vec![],
+        deleted_at: timestamp_column(row, "deleted_at")?,
+        deleted_by: non_empty(row.get("deleted_by")?),
+        dependents: vec![],
+    })
+}
+
+/// Labels matching `filter`, by issue
+fn load_labels(
+    conn: &Connection,
+    filter: &str,
+    params: &[&dyn rusqlite::ToSql],
+) -> Result<HashMap<String, Vec<String>>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT issue_id, label FROM labels {} ORDER BY issue_id, label",
+        filter
+    ))?;
+    let mut labels: HashMap<String, Vec<String>> = HashMap::new();
+    for row in stmt.query_map(params, |row| Ok((row.get(0)?, row.get(1)?)))? {
+        let (issue_id, label): (String, String) = row?;
+        labels.entry(issue_id).or_default().push(label);
+    }
+    Ok(labels)
+}
+
+/// Dependencies matching `filter`, by dependent issue
+fn load_dependencies(
+    conn: &Connection,
+    filter: &str,
+    params: &[&dyn rusqlite::ToSql],
+) -> Result<HashMap<String, Vec<Dependency>>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT issue_id, depends_on_id, type, created_at, created_by FROM dependencies {} \
+         ORDER BY issue_id, depends_on_id",
+        filter
+    ))?;
+    let mut dependencies: HashMap<String, Vec<Dependency>> = HashMap::new();
+    for row in stmt.query_map(params, |row| {
+        Ok(Dependency {
+            issue_id: row.get(0)?,
+            depends_on_id: row.get(1)?,
+            dep_type: DependencyType::from(row.get::<_, String>(2)?.as_str()),
+            created_at: row
+                .get::<_, Option<String>>(3)?
+                .as_deref()
+                .and_then(parse_timestamp),
+            created_by: non_empty(row.get(4)?),
+        })
+    })? {
+        let dep = row?;
+        dependencies
+            .entry(dep.issue_id.clone())
+            .or_default()
+            .push(dep);
+    }
+    Ok(dependencies)
+}
+
+/// Map an `events.event_type` value onto the activity event kinds
+fn event_type_from_db(value: &str) -> EventType {
+    match value {
+        "created" => EventType::Created,
+        "updated" => EventType::Updated,
+        "status_changed" => EventType::StatusChanged,
+        "commented" => EventType::Commented,
+        "closed" => EventType::Closed,
+        "reopened" => EventType::Reopened,
+        "dependency_added" => EventType::DependencyAdded,
+        "dependency_removed" => EventType::DependencyRemoved,
+        "label_added" => EventType::LabelAdded,
+        "label_removed" => EventType::LabelRemoved,
+        "compacted" => EventType::Compacted,
+        "deleted" => EventType::Deleted,
+        other => EventType::Unknown(other.to_string()),
+    }
+}
+
+/// Extract a status from an event's old/new value.
+///
+/// Beads stores either the bare status or a JSON snapshot of the changed fields.
+fn status_from_event_value(value: Option<&str>) -> Option<Status> {
+    let value = value?.trim();
+    let raw = match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(serde_json::Value::Object(map)) => map.get("status")?.as_str()?.to_string(),
+        Ok(serde_json::Value::String(s)) => s,
+        _ => value.to_string(),
+    };
+    Some(Status::from(raw.as_str()))
+}
+
+/// Comments on an issue, oldest first
+fn select_comments(conn: &Connection, id: &str) -> Result<Vec<Comment>> {
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?1)",
+        [id],
+        |row| row.get(0),
+    )?;
+    if !exists {
+        return Err(BeadsError::NotFound(id.to_string()));
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT id, issue_id, author, text, created_at FROM comments \
+         WHERE issue_id = ?1 ORDER BY created_at, id",
+    )?;
+    let comments = stmt
+        .query_map([id], |row| {
+            Ok(Comment {
+                id: row.get(0)?,
+                issue_id: row.get(1)?,
+                author: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                text: row.get(3)?,
+                created_at: row
+                    .get::<_, Option<String>>(4)?
+                    .as_deref()
+                    .and_then(parse_timestamp)
+                    .unwrap_or(OffsetDateTime::UNIX_EPOCH),
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(comments)
+}
+
+#[async_trait]
+impl BeadsBackend for SqliteBackend {
+    async fn list_issues(&self) -> Result<Vec<Issue>> {
+        self.query(|conn, columns| {
+            select_issues(
+                conn,
+                columns,
+                "WHERE status NOT IN ('closed', 'tombstone')",
+                &[],
+            )
+        })
+        .await
+    }
+
+    async fn list_all_issues(&self) -> Result<Vec<Issue>> {
+        self.query(|conn, columns| select_issues(conn, columns, "WHERE status != 'tombstone'", &[]))
+            .await
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<Issue> {
+        let id = id.to_string();
+        self.query(move |conn, columns| {
+            let mut issue = select_issues(conn, columns, "WHERE id = ?1", &[&id])?
+                .into_iter()
+                .next()
+                .ok_or_else(|| BeadsError::NotFound(id.clone()))?;
+            let dependents = load_dependencies(conn, "WHERE depends_on_id = ?1", &[&id])?;
+            issue.dependents = dependents_of(&id, dependents.values().flatten());
+            Ok(issue)
+        })
+        .await
+    }
+
+    async fn update_issue(&self, id: &str, update: IssueUpdate) -> Result<()> {
+        self.client.update_issue(id, update).await
+    }
+
+    async fn create_issue(&self, create: IssueCreate) -> Result<String> {
+        self.client.create_issue(create).await
+    }
+
+    async fn close_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.client.close_issue(id, reason).await
+    }
+
+    async fn reopen_issue(&self, id: &str, reason: Option<&str>) -> Result<()> {
+        self.client.reopen_issue(id, reason).await
+    }
+
+    async fn delete_issue(&self, id: &str) -> Result<()> {
+        self.client.delete_issue(id).await
+    }
+
+    async fn restore_issue(&self, id: &str) -> Result<()> {
+        self.client.restore_issue(id).await
+    }
+
+    async fn list_tombstones(&self) -> Result<Vec<Issue>> {
+        self.query(|conn, columns| select_issues(conn, columns, "WHERE status = 'tombstone'", &[]))
+            .await
+    }
+
+    async fn get_activity(&self) -> Result<Vec<Activity>> {
+        self.query(|conn, _| select_activity(conn, "", &[])).await
+    }
+
+    async fn get_issue_activity(&self, id: &str) -> Result<Vec<Activity>> {
+        let id = id.to_string();
+        self.query(move |conn, _| select_activity(conn, "WHERE issue_id = ?1", &[&id]))
+            .await
+    }
+
+    async fn add_dependency(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &DependencyType,
+    ) -> Result<()> {
+        self.client
+            .add_dependency(issue_id, depends_on_id, dep_type)
+            .await
+    }
+
+    async fn remove_dependency(&self, issue_id: &str, depends_on_id: &str) -> Result<()> {
+        self.client.remove_dependency(issue_id, depends_on_id).await
+    }
+
+    async fn list_comments(&self, id: &str) -> Result<Vec<Comment>> {
+        let id = id.to_string();
+        self.query(move |conn, _| select_comments(conn, &id)).await
+    }
+
+    async fn add_comment(&self, id: &str, comment: CommentCreate) -> Result<Comment> {
+        self.client.add_comment(id, comment).await
+    }
+
+    async fn get_status_summary(&self) -> Result<serde_json::Value> {
+        self.client.get_status_summary().await
+    }
+
+    async fn list_all_dependencies(&self) -> Result<Vec<Dependency>> {
+        self.query(|conn, _| {
+            Ok(load_dependencies(conn, "", &[])?
+                .into_values()
+                .flatten()
+                .collect())
+        })
+        .await
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        let mut wal = self.path.clone().into_os_string();
+        wal.push("-wal");
+        Some(files_fingerprint(&[self.path.clone(), PathBuf::from(wal)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "
+        CREATE TABLE issues (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            acceptance_criteria TEXT NOT NULL DEFAULT '',
+            status TEXT NOT NULL DEFAULT 'open',
+            priority INTEGER NOT NULL DEFAULT 2,
+            issue_type TEXT NOT NULL DEFAULT 'task',
+            assignee TEXT,
+            estimated_minutes INTEGER,
+            created_at DATETIME NOT NULL,
+            updated_at DATETIME NOT NULL,
+            closed_at DATETIME
+        );
+        CREATE TABLE dependencies (
+            issue_id TEXT NOT NULL,
+            depends_on_id TEXT NOT NULL,
+            type TEXT NOT NULL DEFAULT 'blocks',
+            created_at DATETIME NOT NULL,
+            created_by TEXT NOT NULL
+        );
+        CREATE TABLE labels (issue_id TEXT NOT NULL, label TEXT NOT NULL);
+        CREATE TABLE comments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            issue_id TEXT NOT NULL,
+            author TEXT NOT NULL,
+            text TEXT NOT NULL,
+            created_at DATETIME NOT NULL
+        );
+        CREATE TABLE events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            issue_id TEXT NOT NULL,
+            event_type TEXT NOT NULL,
+            actor TEXT NOT NULL,
+            old_value TEXT,
+            new_value TEXT,
+            comment TEXT,
+            created_at DATETIME NOT NULL
+        );
+        INSERT INTO issues VALUES
+            ('nacre-1', 'Epic', 'Body', '', 'open', 1, 'epic', 'l1x', 90,
+             '2025-12-27 21:25:14.603402+01:00', '2025-12-28 20:57:26+01:00', NULL),
+            ('nacre-2', 'Task', '', 'It works', 'closed', 2, 'task', '', NULL,
+             '2025-12-27T21:25:14Z', '2025-12-28T20:57:26Z', '2025-12-28 20:57:26'),
+            ('nacre-3', 'Gone', '', '', 'tombstone', 2, 'task', NULL, NULL,
+             '2025-12-27T21:25:14Z', '2025-12-28T20:57:26Z', NULL);
+        INSERT INTO dependencies VALUES
+            ('nacre-2', 'nacre-1', 'parent-child', '2025-12-27 21:25:14', 'l1x');
+        INSERT INTO labels VALUES ('nacre-1', 'ui'), ('nacre-1', 'backend');
+        INSERT INTO comments (issue_id, author, text, created_at) VALUES
+            ('nacre-1', 'agent-1', 'Second', '2025-12-28 10:00:00'),
+            ('nacre-1', 'l1x', 'First', '2025-12-27 22:00:00');
+        INSERT INTO events (issue_id, event_type, actor, old_value, new_value, comment, created_at) VALUES
+            ('nacre-2', 'created', 'l1x', NULL, NULL, NULL, '2025-12-27 21:25:14'),
+            ('nacre-2', 'status_changed', 'l1x', '{\"status\":\"open\"}', '{\"status\":\"in_progress\"}', NULL, '2025-12-28 10:00:00'),
+            ('nacre-2', 'closed', 'l1x', NULL, NULL, 'Done', '2025-12-28 20:57:26'),
+            ('nacre-2', 'future_event', 'l1x', NULL, NULL, NULL, '2025-12-28 20:58:00');
+    ";
+
+    fn backend() -> (tempfile::TempDir, SqliteBackend) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("beads.db");
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch(SCHEMA)
+            .unwrap();
+        let backend = SqliteBackend::open(Client::new(), path).unwrap();
+        (dir, backend)
+    }
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        assert!(parse_timestamp("2025-12-27T21:25:14.603402+01:00").is_some());
+        assert!(parse_timestamp("2025-12-27 21:25:14.603402+01:00").is_some());
+        assert_eq!(
+            parse_timestamp("2025-12-27 21:25:14"),
+            Some(time::macros::datetime!(2025-12-27 21:25:14 UTC))
+        );
+        assert!(parse_timestamp("yesterday").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_filters_by_status() {
+        let (_dir, backend) = backend();
+
+        let open = backend.list_issues().await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].id, "nacre-1");
+
+        let all = backend.list_all_issues().await.unwrap();
+        assert_eq!(all.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_comments_oldest_first() {
+        let (_dir, backend) = backend();
+
+        let comments = backend.list_comments("nacre-1").await.unwrap();
+        let texts: Vec<&str> = comments.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["First", "Second"]);
+        assert_eq!(comments[1].author, "agent-1");
+
+        assert!(backend.list_comments("nacre-2").await.unwrap().is_empty());
+        assert!(matches!(
+            backend.list_comments("nacre-404").await,
+            Err(BeadsError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_includes_labels_and_dependencies() {
+        let (_dir, backend) = backend();
+
+        let epic = backend.get_issue("nacre-1").await.unwrap();
+        assert_eq!(
+            epic.labels,
+            Some(vec!["backend".to_string(), "ui".to_string()])
+        );
+        assert_eq!(epic.assignee.as_deref(), Some("l1x"));
+        assert_eq!(epic.estimate, Some(90));
+        assert_eq!(epic.dependents.len(), 1);
+        assert_eq!(epic.dependents[0].issue_id, "nacre-2");
+
+        let task = backend.get_issue("nacre-2").await.unwrap();
+        assert_eq!(task.assignee, None);
+        assert_eq!(task.acceptance_criteria.as_deref(), Some("It works"));
+        assert!(task.closed_at.is_some());
+        assert_eq!(task.dependencies.len(), 1);
+        assert_eq!(task.dependencies[0].dep_type, DependencyType::ParentChild);
+        assert!(task.dependents.is_empty());
+
+        assert!(matches!(
+            backend.get_issue("nacre-404").await,
+            Err(BeadsError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_activity_from_events() {
+        let (_dir, backend) = backend();
+
+        let activity = backend.get_activity().await.unwrap();
+        assert_eq!(activity.len(), 4);
+        assert_eq!(activity[0].r#type, EventType::Created);
+        assert_eq!(activity[1].old_status, Some(Status::Open));
+        assert_eq!(activity[1].new_status, Some(Status::InProgress));
+        assert_eq!(activity[2].message, "nacre-2 Done");
+        assert_eq!(
+            activity[3].r#type,
+            EventType::Unknown("future_event".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_beads_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("other.db");
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE things (x INTEGER);")
+            .unwrap();
+
+        assert!(SqliteBackend::open(Client::new(), path).is_err());
+    }
+}
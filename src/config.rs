@@ -0,0 +1,347 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Deserializer};
+use time::{OffsetDateTime, UtcOffset};
+use time_tz::{OffsetDateTimeExt, TimeZone, Tz};
+
+use crate::beads::Status;
+
+/// Name of the configuration file, in the project root or `~/.config/nacre`
+pub const CONFIG_FILE: &str = "nacre.toml";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Could not read {}: {source}", path.display())]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid {}: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+/// Settings from `nacre.toml`. Everything is optional; command-line flags
+/// override the server settings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    /// `bd` binary, instead of `BD_BIN`
+    pub bd_bin: Option<String>,
+    /// Beads database, instead of `BEADS_DB`
+    pub db_path: Option<String>,
+    /// Directories of markdown documents shown on `/prds`, relative to the
+    /// project root
+    pub doc_dirs: Vec<PathBuf>,
+    /// Theme until the visitor picks one, e.g. `catppuccin-mocha`
+    pub theme: Option<String>,
+    /// Zone that dates are shown and counted per day in: an IANA name such
+    /// as `Europe/Berlin`, which follows daylight saving time, or a fixed
+    /// offset such as `+02:00`; UTC if unset
+    pub timezone: Option<Timezone>,
+    /// Number of days the dashboard and metrics charts cover
+    #[serde(deserialize_with = "deserialize_days")]
+    pub metrics_days: u32,
+    pub board: BoardConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BoardConfig {
+    /// Statuses shown as board columns, left to right. Issues with any other
+    /// status still get a column after these.
+    pub columns: Vec<Status>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            host: None,
+            port: None,
+            bd_bin: None,
+            db_path: None,
+            doc_dirs: vec![PathBuf::from("docs/prds")],
+            theme: None,
+            timezone: None,
+            metrics_days: 7,
+            board: BoardConfig::default(),
+        }
+    }
+}
+
+impl Default for BoardConfig {
+    fn default() -> Self {
+        Self {
+            columns: vec![
+                Status::Open,
+                Status::InProgress,
+                Status::Blocked,
+                Status::Deferred,
+                Status::Closed,
+            ],
+        }
+    }
+}
+
+impl Config {
+    /// Read the first of `<root>/nacre.toml` and `$XDG_CONFIG_HOME/nacre/nacre.toml`
+    /// (`~/.config/nacre/nacre.toml`) that exists, or use the defaults.
+    /// Relative `doc_dirs` and `db_path` are resolved against `root`.
+    pub fn load(root: &Path) -> Result<Self, ConfigError> {
+        let mut config = match Self::find(root) {
+            Some(path) => {
+                tracing::info!("Using {}", path.display());
+                Self::from_file(&path)?
+            }
+            None => Self::default(),
+        };
+        config.doc_dirs = config.doc_dirs.iter().map(|dir| root.join(dir)).collect();
+        config.db_path = config
+            .db_path
+            .map(|db| root.join(db).to_string_lossy().to_string());
+        Ok(config)
+    }
+
+    /// Path of the configuration file `load` would read
+    pub fn find(root: &Path) -> Option<PathBuf> {
+        let user_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")));
+        std::iter::once(root.join(CONFIG_FILE))
+            .chain(user_dir.map(|dir| dir.join("nacre").join(CONFIG_FILE)))
+            .find(|path| path.is_file())
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&text).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// Keys that only take effect in the configuration nacre starts with,
+    /// and that `self`, a project's own configuration, sets differently
+    pub fn ignored_per_project(&self, server: &Config) -> Vec<&'static str> {
+        fn overrides<T: PartialEq>(project: &Option<T>, server: &Option<T>) -> bool {
+            project.is_some() && project != server
+        }
+        [
+            ("host", overrides(&self.host, &server.host)),
+            ("port", overrides(&self.port, &server.port)),
+            ("bd_bin", overrides(&self.bd_bin, &server.bd_bin)),
+            ("theme", overrides(&self.theme, &server.theme)),
+            ("timezone", overrides(&self.timezone, &server.timezone)),
+        ]
+        .into_iter()
+        .filter_map(|(key, differs)| differs.then_some(key))
+        .collect()
+    }
+}
+
+/// A zone from the tz database, or a fixed offset from UTC
+#[derive(Debug, Clone, Copy)]
+pub enum Timezone {
+    Named(&'static Tz),
+    Fixed(UtcOffset),
+}
+
+impl Timezone {
+    /// `UTC`, `Z`, an offset such as `+02:00`, or an IANA name such as
+    /// `Europe/Berlin`
+    pub fn parse(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("utc") || value == "Z" {
+            return Some(Self::Fixed(UtcOffset::UTC));
+        }
+        let format = time::macros::format_description!("[offset_hour]:[offset_minute]");
+        if let Ok(offset) = UtcOffset::parse(value, &format) {
+            return Some(Self::Fixed(offset));
+        }
+        time_tz::timezones::get_by_name(value).map(Self::Named)
+    }
+
+    /// `at` as a date and time in this zone
+    pub fn convert(&self, at: OffsetDateTime) -> OffsetDateTime {
+        match self {
+            Self::Named(tz) => at.to_timezone(*tz),
+            Self::Fixed(offset) => at.to_offset(*offset),
+        }
+    }
+}
+
+impl PartialEq for Timezone {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Named(a), Self::Named(b)) => a.name() == b.name(),
+            (Self::Fixed(a), Self::Fixed(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timezone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "invalid timezone '{}', expected a name like Europe/Berlin, UTC or an offset \
+                 like +02:00",
+                value
+            ))
+        })
+    }
+}
+
+fn deserialize_days<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    match u32::deserialize(deserializer)? {
+        0 => Err(serde::de::Error::custom("metrics_days must be at least 1")),
+        days => Ok(days),
+    }
+}
+
+/// How pages show dates and themes, shared by every project
+struct Display {
+    timezone: Timezone,
+    theme: String,
+}
+
+static DISPLAY: OnceLock<Display> = OnceLock::new();
+
+/// Use `config`'s timezone and theme on every page. Only the first call has
+/// an effect; until then dates are in UTC and the theme follows the browser.
+pub fn init_display(config: &Config) {
+    let _ = DISPLAY.set(Display {
+        timezone: config.timezone.unwrap_or(Timezone::Fixed(UtcOffset::UTC)),
+        theme: config.theme.clone().unwrap_or_default(),
+    });
+}
+
+/// `at` in the zone that dates are shown and bucketed per day in
+pub fn to_local(at: OffsetDateTime) -> OffsetDateTime {
+    match DISPLAY.get() {
+        Some(display) => display.timezone.convert(at),
+        None => at.to_offset(UtcOffset::UTC),
+    }
+}
+
+/// Configured default theme, empty to follow the browser's preference
+pub fn default_theme() -> &'static str {
+    DISPLAY.get().map_or("", |d| d.theme.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse(
+            r#"
+            port = 4000
+            bd_bin = "/opt/bd"
+            doc_dirs = ["docs/prds", "docs/adr"]
+            timezone = "-05:30"
+            metrics_days = 14
+
+            [board]
+            columns = ["open", "in_progress", "review", "closed"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.port, Some(4000));
+        assert_eq!(config.host, None);
+        assert_eq!(config.bd_bin.as_deref(), Some("/opt/bd"));
+        assert_eq!(config.doc_dirs.len(), 2);
+        assert_eq!(
+            config.timezone,
+            Some(Timezone::Fixed(UtcOffset::from_hms(-5, -30, 0).unwrap()))
+        );
+        assert_eq!(config.metrics_days, 14);
+        assert_eq!(
+            config.board.columns,
+            vec![
+                Status::Open,
+                Status::InProgress,
+                Status::Unknown("review".to_string()),
+                Status::Closed,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_config_uses_defaults() {
+        let config = Config::parse("").unwrap();
+
+        assert_eq!(config.doc_dirs, vec![PathBuf::from("docs/prds")]);
+        assert_eq!(config.timezone, None);
+        assert_eq!(config.metrics_days, 7);
+        assert_eq!(config.board.columns.len(), 5);
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        assert!(Config::parse("prot = 4000").is_err());
+        assert!(Config::parse("timezone = \"Mars/Olympus_Mons\"").is_err());
+        assert!(Config::parse("metrics_days = 0").is_err());
+    }
+
+    #[test]
+    fn test_named_timezone_follows_daylight_saving() {
+        let berlin = Config::parse("timezone = \"Europe/Berlin\"")
+            .unwrap()
+            .timezone
+            .unwrap();
+
+        let winter = berlin.convert(time::macros::datetime!(2025-01-15 23:30 UTC));
+        let summer = berlin.convert(time::macros::datetime!(2025-07-15 22:30 UTC));
+        assert_eq!(winter.offset(), UtcOffset::from_hms(1, 0, 0).unwrap());
+        assert_eq!(winter.date(), time::macros::date!(2025 - 01 - 16));
+        assert_eq!(summer.offset(), UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(summer.date(), time::macros::date!(2025 - 07 - 16));
+    }
+
+    #[test]
+    fn test_ignored_per_project_lists_server_wide_keys() {
+        let server = Config::parse("port = 3000\ntheme = \"nord\"").unwrap();
+        // Same port, no theme of its own, and project settings are all fine
+        let project = Config::parse(
+            "port = 3000\nbd_bin = \"/opt/bd\"\ntimezone = \"Europe/Berlin\"\nmetrics_days = 30",
+        )
+        .unwrap();
+
+        assert_eq!(project.ignored_per_project(&server), ["bd_bin", "timezone"]);
+        assert!(Config::default().ignored_per_project(&server).is_empty());
+    }
+
+    #[test]
+    fn test_load_prefers_project_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(CONFIG_FILE),
+            "doc_dirs = [\"notes\"]\ndb_path = \".beads/other.db\"\n",
+        )
+        .unwrap();
+
+        let config = Config::load(dir.path()).unwrap();
+
+        assert_eq!(config.doc_dirs, vec![dir.path().join("notes")]);
+        assert_eq!(
+            config.db_path.map(PathBuf::from),
+            Some(dir.path().join(".beads/other.db"))
+        );
+    }
+}
@@ -16,24 +16,94 @@ pub enum AppError {
 
     #[error("Invalid request: {0}")]
     BadRequest(String),
+
+    #[error("Dependency cycle: {}", .0.join(" → "))]
+    DependencyCycle(Vec<String>),
+
+    #[error("Epic still has open children: {}", .0.join(", "))]
+    OpenChildren(Vec<String>),
+
+    /// The request does not fit the issue's current state
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// `If-Match` named an older version; carries the current one
+    #[error("Issue {} changed since it was loaded", .0.id)]
+    PreconditionFailed(Box<crate::beads::Issue>),
+}
+
+impl AppError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::NotFound(_) | AppError::Beads(BeadsError::NotFound(_)) => {
+                StatusCode::NOT_FOUND
+            }
+            AppError::Beads(BeadsError::Timeout(_)) => StatusCode::GATEWAY_TIMEOUT,
+            AppError::Beads(BeadsError::Unsupported(_)) => StatusCode::NOT_IMPLEMENTED,
+            AppError::Beads(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Conflict(_) | AppError::DependencyCycle(_) | AppError::OpenChildren(_) => {
+                StatusCode::CONFLICT
+            }
+            AppError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+        }
+    }
+
+    /// What a client may be told; internal failures stay generic
+    pub fn public_message(&self) -> String {
+        match self {
+            AppError::NotFound(msg) | AppError::Beads(BeadsError::NotFound(msg)) => {
+                format!("Not found: {}", msg)
+            }
+            AppError::Beads(BeadsError::Timeout(_)) => "The beads command timed out".to_string(),
+            AppError::Beads(BeadsError::Unsupported(what)) => {
+                format!("The installed bd does not support {}", what)
+            }
+            AppError::Beads(_) => "An internal error occurred".to_string(),
+            AppError::BadRequest(msg) => format!("Bad request: {}", msg),
+            AppError::Conflict(msg) => format!("Conflict: {}", msg),
+            AppError::DependencyCycle(_) => {
+                "Adding this dependency would create a cycle".to_string()
+            }
+            AppError::OpenChildren(_) => "This epic still has open children".to_string(),
+            AppError::PreconditionFailed(_) => "The issue was changed by someone else".to_string(),
+        }
+    }
+
+    /// Structured data clients need to recover, sent next to the message
+    pub fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            // Clients need the path to show which edge to remove
+            AppError::DependencyCycle(path) => Some(serde_json::json!({ "cycle": path })),
+            AppError::OpenChildren(ids) => Some(serde_json::json!({ "open_children": ids })),
+            // Send the server version so the client can merge and retry
+            AppError::PreconditionFailed(current) => {
+                Some(serde_json::json!({ "current": current }))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, user_message) = match &self {
-            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, format!("Not found: {}", msg)),
-            AppError::Beads(BeadsError::NotFound(msg)) => {
-                (StatusCode::NOT_FOUND, format!("Not found: {}", msg))
-            }
-            AppError::Beads(_) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "An internal error occurred".to_string(),
-            ),
-            AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, format!("Bad request: {}", msg)),
+        let status = self.status();
+        let Some(mut body) = self.details() else {
+            tracing::error!("{}", self);
+            return (status, self.public_message()).into_response();
         };
 
-        tracing::error!("{}", self);
-        (status, user_message).into_response()
+        tracing::warn!("{}", self);
+        body["error"] = self.public_message().into();
+        let mut response = (status, axum::Json(body)).into_response();
+        if let AppError::PreconditionFailed(current) = &self
+            && let Ok(etag) = current.etag().parse()
+        {
+            response
+                .headers_mut()
+                .insert(axum::http::header::ETAG, etag);
+        }
+        response
     }
 }
 
@@ -57,6 +127,34 @@ mod tests {
         assert_eq!(body_str, "An internal error occurred");
     }
 
+    #[tokio::test]
+    async fn test_timeout_is_gateway_timeout() {
+        let err = AppError::Beads(BeadsError::Timeout(std::time::Duration::from_secs(30)));
+        let response = err.into_response();
+
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_is_not_implemented() {
+        let err = AppError::Beads(BeadsError::Unsupported("activity --json".to_string()));
+        let response = err.into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn test_dependency_cycle_is_conflict_with_path() {
+        let path = vec!["t-1".to_string(), "t-2".to_string(), "t-1".to_string()];
+        let response = AppError::DependencyCycle(path).into_response();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let body_bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(body["cycle"], serde_json::json!(["t-1", "t-2", "t-1"]));
+    }
+
     #[tokio::test]
     async fn test_not_found_error_is_specific() {
         let err = AppError::NotFound("Issue 123".to_string());
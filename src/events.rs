@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::sync::{Notify, broadcast};
+
+use crate::beads::{Dependency, DependencyType, Issue, Status};
+use crate::snapshot::ProjectSnapshot;
+
+/// How often the watcher checks the beads files for changes
+pub const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Events buffered per subscriber before it is told to resync
+const CHANNEL_CAPACITY: usize = 256;
+
+/// A change to the project data, derived by diffing successive snapshots
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChangeEvent {
+    IssueCreated {
+        id: String,
+        title: String,
+        status: Status,
+    },
+    IssueUpdated {
+        id: String,
+    },
+    StatusChanged {
+        id: String,
+        from: Status,
+        to: Status,
+    },
+    IssueRemoved {
+        id: String,
+    },
+    DependencyAdded {
+        issue_id: String,
+        depends_on_id: String,
+        dep_type: DependencyType,
+    },
+    DependencyRemoved {
+        issue_id: String,
+        depends_on_id: String,
+        dep_type: DependencyType,
+    },
+}
+
+impl ChangeEvent {
+    /// SSE event name, matching the serialized `type` tag
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChangeEvent::IssueCreated { .. } => "issue_created",
+            ChangeEvent::IssueUpdated { .. } => "issue_updated",
+            ChangeEvent::StatusChanged { .. } => "status_changed",
+            ChangeEvent::IssueRemoved { .. } => "issue_removed",
+            ChangeEvent::DependencyAdded { .. } => "dependency_added",
+            ChangeEvent::DependencyRemoved { .. } => "dependency_removed",
+        }
+    }
+}
+
+/// A change event tagged with the snapshot version it leads to
+#[derive(Debug, Clone)]
+pub struct VersionedEvent {
+    pub version: u64,
+    pub event: ChangeEvent,
+}
+
+/// Compute the events that turn `old` into `new`
+pub fn diff_snapshots(old: &ProjectSnapshot, new: &ProjectSnapshot) -> Vec<ChangeEvent> {
+    let mut events = Vec::new();
+
+    for issue in &new.issues {
+        match old.get(&issue.id) {
+            None => events.push(ChangeEvent::IssueCreated {
+                id: issue.id.clone(),
+                title: issue.title.clone(),
+                status: issue.status.clone(),
+            }),
+            Some(previous) if previous.status != issue.status => {
+                events.push(ChangeEvent::StatusChanged {
+                    id: issue.id.clone(),
+                    from: previous.status.clone(),
+                    to: issue.status.clone(),
+                })
+            }
+            Some(previous) if issue_changed(previous, issue) => {
+                events.push(ChangeEvent::IssueUpdated {
+                    id: issue.id.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    for issue in &old.issues {
+        if new.get(&issue.id).is_none() {
+            events.push(ChangeEvent::IssueRemoved {
+                id: issue.id.clone(),
+            });
+        }
+    }
+
+    let old_edges = dependency_edges(old);
+    let new_edges = dependency_edges(new);
+    for (key, dep) in &new_edges {
+        if !old_edges.contains_key(key) {
+            events.push(ChangeEvent::DependencyAdded {
+                issue_id: dep.issue_id.clone(),
+                depends_on_id: dep.depends_on_id.clone(),
+                dep_type: dep.dep_type.clone(),
+            });
+        }
+    }
+    for (key, dep) in &old_edges {
+        if !new_edges.contains_key(key) {
+            events.push(ChangeEvent::DependencyRemoved {
+                issue_id: dep.issue_id.clone(),
+                depends_on_id: dep.depends_on_id.clone(),
+                dep_type: dep.dep_type.clone(),
+            });
+        }
+    }
+
+    events
+}
+
+/// Dependencies keyed by (issue, depends on, type), in a stable order
+fn dependency_edges(snapshot: &ProjectSnapshot) -> BTreeMap<(&str, &str, &str), &Dependency> {
+    snapshot
+        .dependencies
+        .iter()
+        .map(|d| {
+            (
+                (
+                    d.issue_id.as_str(),
+                    d.depends_on_id.as_str(),
+                    d.dep_type.as_str(),
+                ),
+                d,
+            )
+        })
+        .collect()
+}
+
+fn issue_changed(old: &Issue, new: &Issue) -> bool {
+    old.updated_at != new.updated_at
+        || old.title != new.title
+        || old.priority != new.priority
+        || old.assignee != new.assignee
+        || old.description != new.description
+        || old.labels != new.labels
+}
+
+/// Fan-out point for change events.
+///
+/// The watcher publishes here, every `/api/events` stream subscribes, and
+/// write handlers call `wake` so their own changes go out without waiting for
+/// the next poll.
+pub struct EventHub {
+    sender: broadcast::Sender<VersionedEvent>,
+    wake: Notify,
+}
+
+impl Default for EventHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            sender,
+            wake: Notify::new(),
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<VersionedEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Send events to current subscribers; events with no listener are dropped
+    pub fn publish(&self, version: u64, events: Vec<ChangeEvent>) {
+        for event in events {
+            let _ = self.sender.send(VersionedEvent { version, event });
+        }
+    }
+
+    /// Ask the watcher to check for changes now
+    pub fn wake(&self) {
+        self.wake.notify_one();
+    }
+}
+
+/// Poll the snapshot cache and publish the diff whenever the data changes.
+///
+/// The cache only rebuilds when the beads files changed on disk (or after a
+/// write through nacre), so an idle project costs a few `stat` calls per tick.
+pub fn spawn_watcher(state: crate::SharedAppState) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut previous: Option<Arc<ProjectSnapshot>> = None;
+        loop {
+            match state.snapshot().await {
+                Ok(snapshot) => {
+                    if let Some(old) = &previous
+                        && old.version != snapshot.version
+                    {
+                        let events = diff_snapshots(old, &snapshot);
+                        tracing::debug!(count = events.len(), "Publishing change events");
+                        state.events.publish(snapshot.version, events);
+                    }
+                    previous = Some(snapshot);
+                }
+                Err(e) => tracing::debug!(error = %e, "Failed to refresh snapshot"),
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(WATCH_INTERVAL) => {}
+                _ = state.events.wake.notified() => {}
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::{BeadsBackend, IssueCreate, IssueType, IssueUpdate, MemoryBackend};
+    use crate::snapshot::SnapshotCache;
+    use time::OffsetDateTime;
+
+    fn issue(id: &str, dependencies: Vec<Dependency>) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {}", id),
+            status: Status::Open,
+            priority: Some(2),
+            issue_type: IssueType::Task,
+            created_at: OffsetDateTime::UNIX_EPOCH,
+            updated_at: OffsetDateTime::UNIX_EPOCH,
+            closed_at: None,
+            assignee: None,
+            labels: None,
+            description: None,
+            acceptance_criteria: None,
+            close_reason: None,
+            estimate: None,
+            dependencies,
+            deleted_at: None,
+            deleted_by: None,
+            dependents: vec![],
+        }
+    }
+
+    fn blocks(issue_id: &str, depends_on_id: &str) -> Dependency {
+        Dependency {
+            issue_id: issue_id.to_string(),
+            depends_on_id: depends_on_id.to_string(),
+            dep_type: DependencyType::Blocks,
+            created_at: None,
+            created_by: None,
+        }
+    }
+
+    async fn snapshot_of(issues: Vec<Issue>) -> Arc<ProjectSnapshot> {
+        let backend = MemoryBackend::new().with_issues(issues);
+        SnapshotCache::new().get(&backend).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_diff_detects_issue_changes() {
+        let backend =
+            MemoryBackend::new().with_issues(vec![issue("t-1", vec![]), issue("t-2", vec![])]);
+        let cache = SnapshotCache::new();
+        let before = cache.get(&backend).await.unwrap();
+
+        backend
+            .update_issue(
+                "t-1",
+                IssueUpdate {
+                    status: Some(Status::InProgress),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        backend
+            .update_issue(
+                "t-2",
+                IssueUpdate {
+                    title: Some("Renamed".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let id = backend
+            .create_issue(IssueCreate {
+                title: "Fresh".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let after = cache.get(&backend).await.unwrap();
+
+        assert_eq!(
+            diff_snapshots(&before, &after),
+            vec![
+                ChangeEvent::StatusChanged {
+                    id: "t-1".to_string(),
+                    from: Status::Open,
+                    to: Status::InProgress,
+                },
+                ChangeEvent::IssueUpdated {
+                    id: "t-2".to_string()
+                },
+                ChangeEvent::IssueCreated {
+                    id,
+                    title: "Fresh".to_string(),
+                    status: Status::Open,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diff_detects_removals_and_dependencies() {
+        let before = snapshot_of(vec![
+            issue("t-1", vec![blocks("t-1", "t-2")]),
+            issue("t-2", vec![]),
+            issue("t-3", vec![]),
+        ])
+        .await;
+        let after = snapshot_of(vec![
+            issue("t-1", vec![]),
+            issue("t-2", vec![blocks("t-2", "t-1")]),
+        ])
+        .await;
+
+        assert_eq!(
+            diff_snapshots(&before, &after),
+            vec![
+                ChangeEvent::IssueRemoved {
+                    id: "t-3".to_string()
+                },
+                ChangeEvent::DependencyAdded {
+                    issue_id: "t-2".to_string(),
+                    depends_on_id: "t-1".to_string(),
+                    dep_type: DependencyType::Blocks,
+                },
+                ChangeEvent::DependencyRemoved {
+                    issue_id: "t-1".to_string(),
+                    depends_on_id: "t-2".to_string(),
+                    dep_type: DependencyType::Blocks,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diff_of_identical_snapshots_is_empty() {
+        let snapshot = snapshot_of(vec![issue("t-1", vec![blocks("t-1", "t-2")])]).await;
+        assert!(diff_snapshots(&snapshot, &snapshot).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_event_name_matches_type_tag() {
+        let event = ChangeEvent::StatusChanged {
+            id: "t-1".to_string(),
+            from: Status::Open,
+            to: Status::Closed,
+        };
+        let json = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(json["type"], event.name());
+        assert_eq!(json["to"], "closed");
+    }
+
+    #[tokio::test]
+    async fn test_hub_delivers_published_events() {
+        let hub = EventHub::new();
+        let mut receiver = hub.subscribe();
+
+        hub.publish(
+            7,
+            vec![ChangeEvent::IssueRemoved {
+                id: "t-1".to_string(),
+            }],
+        );
+
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(received.version, 7);
+        assert_eq!(received.event.name(), "issue_removed");
+    }
+}
@@ -1,20 +1,43 @@
+pub mod activity;
+pub mod agents;
 pub mod board;
+pub mod bulk;
+pub mod comments;
+pub mod dependencies;
+pub mod events;
 pub mod general;
 pub mod graph;
 pub mod landing;
 pub mod metrics;
+pub mod overview;
 pub mod prds;
+pub mod projects;
+pub mod ready;
+pub mod rpc;
 pub mod tasks;
+pub mod trash;
 
+pub use activity::issue_activity;
+pub use agents::{agents_page, claim, complete, forget_agent, heartbeat, release};
 pub use board::board;
+pub use bulk::bulk_update;
+pub use comments::{add_comment, list_comments};
+pub use dependencies::{add_dependency, remove_dependency};
+pub use events::events;
 pub use general::{
-    graph, graph_epic, health_check, palette, serve_autumnus_dark, serve_autumnus_light, serve_css,
-    serve_favicon, serve_js, serve_theme,
+    about, graph, graph_epic, health_check, palette, serve_autumnus_dark, serve_autumnus_light,
+    serve_css, serve_favicon, serve_js, serve_theme,
 };
 pub use graph::graph_data;
 pub use landing::landing;
 pub use metrics::metrics_handler;
+pub use overview::{overview, overview_api};
 pub use prds::{prd_view, prds_list};
+pub use projects::{list_projects, projects_index};
+pub use ready::{ready, ready_api};
+pub use rpc::rpc;
 pub use tasks::{
-    create_task, edit_task, list_tasks, new_task_form, task_detail, tasks_list, update_task,
+    close_task, create_task, edit_task, get_task, list_tasks, new_task_form, reopen_task,
+    task_detail, tasks_list, update_task,
 };
+pub use trash::{delete_task, restore_task, trash};
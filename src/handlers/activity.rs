@@ -0,0 +1,130 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use time::OffsetDateTime;
+
+use crate::beads::{Activity, EventType};
+use crate::templates::TimelineEntry;
+
+pub async fn issue_activity(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+) -> crate::AppResult<Json<Vec<TimelineEntry>>> {
+    let (issue, activity) = tokio::join!(
+        state.backend.get_issue(&id),
+        state.backend.get_issue_activity(&id)
+    );
+    Ok(Json(build_timeline(issue?.created_at, activity?)))
+}
+
+/// Turn an issue's activity into a timeline, oldest first.
+///
+/// Each status transition carries how long the issue sat in the status it
+/// left, counted from the previous transition or from creation.
+pub fn build_timeline(
+    created_at: OffsetDateTime,
+    mut activity: Vec<Activity>,
+) -> Vec<TimelineEntry> {
+    activity.sort_by_key(|a| a.timestamp);
+
+    let mut status_since = created_at;
+    activity
+        .into_iter()
+        .map(|a| {
+            let transition = a.old_status.as_ref().zip(a.new_status.as_ref());
+            let duration_secs = transition.map(|_| {
+                let secs = (a.timestamp - status_since).whole_seconds().max(0);
+                status_since = a.timestamp;
+                secs
+            });
+            let summary = match (&a.r#type, transition) {
+                (EventType::Created, _) => "Created".to_string(),
+                (EventType::StatusChanged, Some((from, to))) => {
+                    format!("{} → {}", from.as_str(), to.as_str())
+                }
+                // Messages lead with the issue ID, which the page already shows
+                _ => a
+                    .message
+                    .strip_prefix(a.issue_id.as_str())
+                    .map(str::trim_start)
+                    .unwrap_or(&a.message)
+                    .to_string(),
+            };
+            TimelineEntry {
+                timestamp: a.timestamp,
+                kind: a.r#type,
+                summary,
+                actor: a.actor,
+                duration_secs,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::Status;
+    use time::macros::datetime;
+
+    fn event(
+        timestamp: OffsetDateTime,
+        r#type: EventType,
+        message: &str,
+        change: Option<(Status, Status)>,
+    ) -> Activity {
+        let (old_status, new_status) = change.unzip();
+        Activity {
+            timestamp,
+            r#type,
+            issue_id: "nc-1".to_string(),
+            message: message.to_string(),
+            old_status,
+            new_status,
+            actor: None,
+        }
+    }
+
+    #[test]
+    fn test_timeline_measures_time_in_each_status() {
+        let created = datetime!(2026-01-05 09:00 UTC);
+        let activity = vec![
+            event(
+                datetime!(2026-01-07 12:30 UTC),
+                EventType::Closed,
+                "nc-1 closed: shipped",
+                Some((Status::InProgress, Status::Closed)),
+            ),
+            event(created, EventType::Created, "nc-1 created", None),
+            event(
+                datetime!(2026-01-05 11:15 UTC),
+                EventType::StatusChanged,
+                "nc-1 open → in_progress",
+                Some((Status::Open, Status::InProgress)),
+            ),
+            event(
+                datetime!(2026-01-06 10:00 UTC),
+                EventType::LabelAdded,
+                "nc-1 added label ui",
+                None,
+            ),
+        ];
+
+        let timeline = build_timeline(created, activity);
+
+        let summaries: Vec<&str> = timeline.iter().map(|e| e.summary.as_str()).collect();
+        assert_eq!(
+            summaries,
+            [
+                "Created",
+                "open → in_progress",
+                "added label ui",
+                "closed: shipped"
+            ]
+        );
+        assert_eq!(timeline[1].duration_label().as_deref(), Some("2h 15m"));
+        assert_eq!(timeline[2].duration_secs, None);
+        assert_eq!(timeline[3].duration_label().as_deref(), Some("2d 1h"));
+    }
+}
@@ -0,0 +1,209 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+
+use super::ready::ReadyParams;
+use crate::agents::AgentSession;
+use crate::beads::{Issue, IssueUpdate, Status};
+use crate::templates::{AgentSessionView, AgentsTemplate};
+
+#[derive(Debug, Deserialize)]
+pub struct ClaimRequest {
+    /// Recorded as the assignee of the claimed issue
+    pub agent: String,
+    #[serde(flatten)]
+    pub filters: ReadyParams,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReleaseRequest {
+    pub agent: String,
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompleteRequest {
+    pub agent: String,
+    pub id: String,
+    pub reason: Option<String>,
+}
+
+/// Hand the highest-priority ready issue to an agent.
+///
+/// Claims run one at a time under the write lock and re-read each candidate
+/// before taking it, so two agents never get the same issue. Issues already
+/// assigned to someone else are left alone. Responds with the claimed issue,
+/// or `204 No Content` when nothing matches.
+pub async fn claim(
+    State(state): State<crate::SharedAppState>,
+    Json(request): Json<ClaimRequest>,
+) -> crate::AppResult<Response> {
+    let agent = agent_name(&request.agent)?;
+
+    let _guard = state.writes.lock().await;
+    let snapshot = state.snapshot().await?;
+    let candidates: Vec<String> = snapshot
+        .ready_issues()
+        .into_iter()
+        .filter(|i| request.filters.matches(i))
+        .filter(|i| i.assignee.as_deref().is_none_or(|a| a == agent))
+        .map(|i| i.id.clone())
+        .collect();
+
+    for id in candidates {
+        // The snapshot may predate a change made outside nacre
+        let current = state.backend.get_issue(&id).await?;
+        if current.status != Status::Open || current.assignee.as_deref().is_some_and(|a| a != agent)
+        {
+            continue;
+        }
+
+        let update = IssueUpdate {
+            status: Some(Status::InProgress),
+            assignee: Some(agent.to_string()),
+            ..Default::default()
+        };
+        state.backend.update_issue(&id, update).await?;
+        state.data_changed();
+        tracing::info!("{} claimed {}", agent, id);
+        state.agents.heartbeat(agent, Some(id.clone())).await;
+        return Ok(Json(state.backend.get_issue(&id).await?).into_response());
+    }
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Give a claimed issue back: open again and unassigned
+pub async fn release(
+    State(state): State<crate::SharedAppState>,
+    Json(request): Json<ReleaseRequest>,
+) -> crate::AppResult<StatusCode> {
+    let agent = agent_name(&request.agent)?;
+
+    let _guard = state.writes.lock().await;
+    claimed_by(&state, &request.id, agent).await?;
+    let update = IssueUpdate {
+        status: Some(Status::Open),
+        assignee: Some(String::new()),
+        ..Default::default()
+    };
+    state.backend.update_issue(&request.id, update).await?;
+    state.data_changed();
+    tracing::info!("{} released {}", agent, request.id);
+    state.agents.finish(agent).await;
+    Ok(StatusCode::OK)
+}
+
+/// Close a claimed issue
+pub async fn complete(
+    State(state): State<crate::SharedAppState>,
+    Json(request): Json<CompleteRequest>,
+) -> crate::AppResult<StatusCode> {
+    let agent = agent_name(&request.agent)?;
+
+    let _guard = state.writes.lock().await;
+    claimed_by(&state, &request.id, agent).await?;
+    let reason = request
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+    state.backend.close_issue(&request.id, reason).await?;
+    state.data_changed();
+    tracing::info!("{} completed {}", agent, request.id);
+    state.agents.finish(agent).await;
+    Ok(StatusCode::OK)
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HeartbeatRequest {
+    /// Issue the agent is working on, if any
+    pub issue_id: Option<String>,
+}
+
+/// Keep an agent's session live; agents should call this well within the
+/// registry's `stale_after`. The body may be omitted, which keeps the issue
+/// the session already had.
+pub async fn heartbeat(
+    State(state): State<crate::SharedAppState>,
+    Path(name): Path<String>,
+    request: Option<Json<HeartbeatRequest>>,
+) -> crate::AppResult<Json<AgentSession>> {
+    let agent = agent_name(&name)?;
+    let issue_id = request
+        .and_then(|Json(request)| request.issue_id)
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty());
+    Ok(Json(state.agents.heartbeat(agent, issue_id).await))
+}
+
+pub async fn forget_agent(
+    State(state): State<crate::SharedAppState>,
+    Path(name): Path<String>,
+) -> crate::AppResult<StatusCode> {
+    if state.agents.remove(&name).await {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(crate::AppError::NotFound(format!("Agent {}", name)))
+    }
+}
+
+pub async fn agents_page(
+    State(state): State<crate::SharedAppState>,
+) -> crate::AppResult<AgentsTemplate> {
+    let snapshot = state.snapshot().await?;
+    let sessions = state
+        .agents
+        .sessions()
+        .into_iter()
+        .map(|session| {
+            let issue = session.issue_id.as_deref().and_then(|id| snapshot.get(id));
+            AgentSessionView {
+                live: state.agents.is_live(&session),
+                // Only worth releasing while the agent still holds the issue
+                holds_issue: issue.is_some_and(|i| {
+                    i.status == Status::InProgress
+                        && i.assignee.as_deref() == Some(session.name.as_str())
+                }),
+                issue_title: issue.map(|i| i.title.clone()),
+                session,
+            }
+        })
+        .collect();
+
+    Ok(AgentsTemplate {
+        project_name: state.project_name.clone(),
+        page_title: "Agents".to_string(),
+        active_nav: "agents",
+        app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
+        sessions,
+        stale_after_secs: state.agents.stale_after.as_secs(),
+        on_expiry: state.agents.on_expiry,
+    })
+}
+
+fn agent_name(agent: &str) -> crate::AppResult<&str> {
+    let agent = agent.trim();
+    if agent.is_empty() {
+        return Err(crate::AppError::BadRequest(
+            "agent must not be empty".to_string(),
+        ));
+    }
+    Ok(agent)
+}
+
+/// The issue, if `agent` holds it in progress
+async fn claimed_by(state: &crate::AppState, id: &str, agent: &str) -> crate::AppResult<Issue> {
+    let issue = state.backend.get_issue(id).await?;
+    if issue.status != Status::InProgress || issue.assignee.as_deref() != Some(agent) {
+        return Err(crate::AppError::Conflict(format!(
+            "{} is not claimed by {}",
+            id, agent
+        )));
+    }
+    Ok(issue)
+}
@@ -2,7 +2,7 @@ use crate::beads;
 use crate::templates::*;
 use axum::extract::{Query, State};
 use serde::Deserialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 #[derive(Deserialize, Default)]
 pub struct BoardQuery {
@@ -15,46 +15,14 @@ pub async fn board(
     Query(query): Query<BoardQuery>,
 ) -> crate::AppResult<BoardTemplate> {
     // Always load all issues for assignee list and dependency resolution
-    let every_issue = state.client.list_all_issues()?;
+    let snapshot = state.snapshot().await?;
+    let every_issue = &snapshot.issues;
 
     // The visible issues for board columns
-    let all_issues: Vec<beads::Issue> = if query.include_closed {
-        every_issue.clone()
-    } else {
-        every_issue
-            .iter()
-            .filter(|i| i.status != beads::Status::Closed)
-            .cloned()
-            .collect()
-    };
-
-    // Build a set of closed issue IDs for blocked-status calculation
-    let closed_ids: HashSet<&str> = every_issue
-        .iter()
-        .filter(|i| i.status == beads::Status::Closed)
-        .map(|i| i.id.as_str())
-        .collect();
-
-    // Build a map of issue ID → status for dependency resolution
-    let status_map: HashMap<&str, &beads::Status> = every_issue
-        .iter()
-        .map(|i| (i.id.as_str(), &i.status))
-        .collect();
+    let all_issues = snapshot.issues(query.include_closed);
 
-    // Load all dependencies to determine which issues are blocked
-    let all_deps = state.client.list_all_dependencies().unwrap_or_default();
-
-    // An issue is "blocked" if it has a workflow dependency on a non-closed issue
-    let mut blocked_ids: HashSet<String> = HashSet::new();
-    for dep in &all_deps {
-        if dep.dep_type.affects_workflow() {
-            let blocker_closed = closed_ids.contains(dep.depends_on_id.as_str())
-                || !status_map.contains_key(dep.depends_on_id.as_str());
-            if !blocker_closed {
-                blocked_ids.insert(dep.issue_id.clone());
-            }
-        }
-    }
+    // Issues with a workflow dependency on a non-closed issue
+    let blocked_ids = &snapshot.blocked_ids;
 
     // Collect unique assignees from ALL issues so filter is always available
     let mut assignees: Vec<String> = every_issue
@@ -75,69 +43,43 @@ pub async fn board(
             .collect()
     };
 
-    let columns = vec![
-        BoardColumn {
-            name: "Open".to_string(),
-            status: "open".to_string(),
-            issues: wrap(
-                all_issues
-                    .iter()
-                    .filter(|i| i.status == beads::Status::Open)
-                    .cloned()
-                    .collect(),
-            ),
-        },
-        BoardColumn {
-            name: "In Progress".to_string(),
-            status: "in_progress".to_string(),
-            issues: wrap(
-                all_issues
-                    .iter()
-                    .filter(|i| i.status == beads::Status::InProgress)
-                    .cloned()
-                    .collect(),
-            ),
-        },
-        BoardColumn {
-            name: "Blocked".to_string(),
-            status: "blocked".to_string(),
-            issues: wrap(
-                all_issues
-                    .iter()
-                    .filter(|i| i.status == beads::Status::Blocked)
-                    .cloned()
-                    .collect(),
-            ),
-        },
-        BoardColumn {
-            name: "Deferred".to_string(),
-            status: "deferred".to_string(),
-            issues: wrap(
-                all_issues
-                    .iter()
-                    .filter(|i| i.status == beads::Status::Deferred)
-                    .cloned()
-                    .collect(),
-            ),
-        },
-        BoardColumn {
-            name: "Closed".to_string(),
-            status: "closed".to_string(),
-            issues: wrap(
-                all_issues
-                    .iter()
-                    .filter(|i| i.status == beads::Status::Closed)
-                    .cloned()
-                    .collect(),
-            ),
-        },
-    ];
+    let column = |status: &beads::Status| BoardColumn {
+        name: status.to_string(),
+        status: status.as_str().to_string(),
+        issues: wrap(
+            all_issues
+                .iter()
+                .filter(|i| &i.status == status)
+                .cloned()
+                .collect(),
+        ),
+    };
+
+    // Columns in the order configured in nacre.toml
+    let mut columns: Vec<BoardColumn> = state.config.board.columns.iter().map(column).collect();
+
+    // Statuses without a configured column (pinned, or ones a newer bd
+    // added) get a column of their own rather than disappearing from the board
+    let mut extra_statuses: Vec<&beads::Status> = all_issues
+        .iter()
+        .map(|i| &i.status)
+        .filter(|s| **s != beads::Status::Tombstone && !state.config.board.columns.contains(s))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    extra_statuses.sort_by(|a, b| {
+        a.sort_order()
+            .cmp(&b.sort_order())
+            .then_with(|| a.as_str().cmp(b.as_str()))
+    });
+    columns.extend(extra_statuses.into_iter().map(column));
 
     Ok(BoardTemplate {
         project_name: state.project_name.clone(),
         page_title: "Board".to_string(),
         active_nav: "board",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         columns,
         assignees,
         include_closed: query.include_closed,
@@ -0,0 +1,135 @@
+use axum::{Json, extract::State};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::beads::{BeadsError, IssueType, IssueUpdate};
+
+/// Upper bound on issues per bulk request; each one is a separate `bd` call
+const MAX_BULK_ISSUES: usize = 500;
+
+#[derive(Deserialize)]
+pub struct BulkUpdateRequest {
+    pub ids: Vec<String>,
+    pub update: IssueUpdate,
+    /// Close epics even though some of their children are still open
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Serialize)]
+pub struct BulkItemResult {
+    pub id: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct BulkUpdateResponse {
+    pub updated: usize,
+    pub failed: usize,
+    pub results: Vec<BulkItemResult>,
+}
+
+/// Apply one patch to many issues.
+///
+/// Issues are updated one after another, and a failure is reported for that
+/// issue without stopping the rest of the batch. Closing an epic with open
+/// children fails for that epic, as on the single-issue endpoint, unless
+/// `force` is set; children closed in the same batch do not count.
+pub async fn bulk_update(
+    State(state): State<crate::SharedAppState>,
+    Json(request): Json<BulkUpdateRequest>,
+) -> crate::AppResult<Json<BulkUpdateResponse>> {
+    if request.ids.is_empty() {
+        return Err(crate::AppError::BadRequest(
+            "No issues selected".to_string(),
+        ));
+    }
+    if request.ids.len() > MAX_BULK_ISSUES {
+        return Err(crate::AppError::BadRequest(format!(
+            "At most {} issues can be updated at once",
+            MAX_BULK_ISSUES
+        )));
+    }
+    super::tasks::validate_update(&request.update)?;
+
+    let mut ids = request.ids;
+    let mut seen = HashSet::new();
+    ids.retain(|id| seen.insert(id.clone()));
+
+    let _guard = state.writes.lock().await;
+    let snapshot = if request.update.closes() && !(request.force || request.update.force) {
+        Some(state.snapshot().await?)
+    } else {
+        None
+    };
+    let issue = |id: &str| snapshot.as_deref().and_then(|s| s.get(id));
+
+    // Close epics last, so their children in the same batch are closed first
+    let (epics, others): (Vec<&String>, Vec<&String>) = ids
+        .iter()
+        .partition(|id| issue(id).is_some_and(|i| i.issue_type == IssueType::Epic));
+    let mut closed = HashSet::new();
+    let mut errors = HashMap::new();
+    for id in others.into_iter().chain(epics) {
+        let open_children: Vec<String> = match (&snapshot, issue(id)) {
+            (Some(snapshot), Some(issue)) => super::tasks::open_children(snapshot, issue)
+                .into_iter()
+                .filter(|child| !closed.contains(child))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let error = if !open_children.is_empty() {
+            Some(crate::AppError::OpenChildren(open_children).to_string())
+        } else {
+            match state.backend.update_issue(id, request.update.clone()).await {
+                Ok(()) => None,
+                Err(e) => Some(item_error(id, &e)),
+            }
+        };
+        match error {
+            Some(error) => {
+                errors.insert(id.clone(), error);
+            }
+            None => {
+                closed.insert(id.clone());
+            }
+        }
+    }
+
+    let results: Vec<BulkItemResult> = ids
+        .into_iter()
+        .map(|id| {
+            let error = errors.remove(&id);
+            BulkItemResult {
+                ok: error.is_none(),
+                id,
+                error,
+            }
+        })
+        .collect();
+
+    let updated = results.iter().filter(|r| r.ok).count();
+    if updated > 0 {
+        state.data_changed();
+    }
+    Ok(Json(BulkUpdateResponse {
+        updated,
+        failed: results.len() - updated,
+        results,
+    }))
+}
+
+/// What a client may learn about a failed item, mirroring `AppError`
+fn item_error(id: &str, error: &BeadsError) -> String {
+    match error {
+        BeadsError::NotFound(_) => "Not found".to_string(),
+        BeadsError::Timeout(_) => "The beads command timed out".to_string(),
+        BeadsError::Unsupported(what) => format!("The installed bd does not support {}", what),
+        _ => {
+            tracing::error!("Bulk update of {} failed: {}", id, error);
+            "An internal error occurred".to_string()
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::beads::{Comment, CommentCreate};
+
+pub async fn list_comments(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+) -> crate::AppResult<Json<Vec<Comment>>> {
+    Ok(Json(state.backend.list_comments(&id).await?))
+}
+
+pub async fn add_comment(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+    Json(comment): Json<CommentCreate>,
+) -> crate::AppResult<(StatusCode, Json<Comment>)> {
+    if comment.text.trim().is_empty() {
+        return Err(crate::AppError::BadRequest(
+            "Comment text is empty".to_string(),
+        ));
+    }
+
+    let comment = state.backend.add_comment(&id, comment).await?;
+    state.data_changed();
+    Ok((StatusCode::CREATED, Json(comment)))
+}
@@ -0,0 +1,79 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+
+use crate::beads::DependencyType;
+use crate::snapshot::find_cycle;
+
+#[derive(Deserialize, schemars::JsonSchema)]
+pub struct DependencyRequest {
+    pub depends_on_id: String,
+    /// `blocks` (default), `parent-child`, `related`, `discovered-from`, ...
+    #[serde(rename = "type", default)]
+    pub dep_type: DependencyType,
+}
+
+/// Make the issue depend on another, refusing edges that would close a
+/// blocks/parent-child cycle
+pub async fn add_dependency(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+    Json(request): Json<DependencyRequest>,
+) -> crate::AppResult<StatusCode> {
+    if !request.dep_type.is_valid() {
+        return Err(crate::AppError::BadRequest(format!(
+            "Unknown dependency type: {}",
+            request.dep_type.as_str()
+        )));
+    }
+    if request.depends_on_id == id {
+        return Err(crate::AppError::BadRequest(
+            "An issue cannot depend on itself".to_string(),
+        ));
+    }
+
+    // Hold the write lock from the cycle check until the edge is written, so
+    // two requests cannot each add one half of a cycle
+    let _guard = state.writes.lock().await;
+    let snapshot = state.snapshot().await?;
+    for issue_id in [&id, &request.depends_on_id] {
+        if snapshot.get(issue_id).is_none() {
+            return Err(crate::AppError::NotFound(format!("Task {}", issue_id)));
+        }
+    }
+    if request.dep_type.forbids_cycles()
+        && let Some(path) = find_cycle(&snapshot.dependencies, &id, &request.depends_on_id)
+    {
+        return Err(crate::AppError::DependencyCycle(path));
+    }
+
+    state
+        .backend
+        .add_dependency(&id, &request.depends_on_id, &request.dep_type)
+        .await?;
+    state.data_changed();
+    Ok(StatusCode::CREATED)
+}
+
+#[derive(Deserialize)]
+pub struct RemoveDependencyQuery {
+    pub depends_on_id: String,
+}
+
+/// Remove the issue's dependency on `?depends_on_id=`, whatever its type
+pub async fn remove_dependency(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+    Query(request): Query<RemoveDependencyQuery>,
+) -> crate::AppResult<StatusCode> {
+    let _guard = state.writes.lock().await;
+    state
+        .backend
+        .remove_dependency(&id, &request.depends_on_id)
+        .await?;
+    state.data_changed();
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,31 @@
+use std::convert::Infallible;
+
+use axum::extract::State;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::{Stream, StreamExt};
+
+/// Server-Sent Events stream of project changes.
+///
+/// Each change is sent as an event named after its `type` with the JSON
+/// payload as data and the snapshot version as the event ID. A client that
+/// falls too far behind gets a `resync` event and should reload.
+pub async fn events(
+    State(state): State<crate::SharedAppState>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(state.events.subscribe()).map(|message| {
+        Ok(match message {
+            Ok(versioned) => Event::default()
+                .event(versioned.event.name())
+                .id(versioned.version.to_string())
+                .json_data(&versioned.event)
+                .unwrap_or_else(|_| Event::default().event("resync")),
+            Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                Event::default().event("resync").data(skipped.to_string())
+            }
+        })
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
@@ -107,11 +107,7 @@ pub async fn graph(
     State(state): State<crate::SharedAppState>,
     Query(query): Query<GraphQuery>,
 ) -> crate::AppResult<GraphTemplate> {
-    let all_issues = if query.include_closed {
-        state.client.list_all_issues()?
-    } else {
-        state.client.list_issues()?
-    };
+    let all_issues = state.snapshot().await?.issues(query.include_closed);
 
     // Get all epics for the selector
     let epics: Vec<EpicSummary> = all_issues
@@ -132,6 +128,7 @@ pub async fn graph(
         page_title: "Graph".to_string(),
         active_nav: "graph",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         epics,
         tree_html: String::new(),
         include_closed: query.include_closed,
@@ -144,11 +141,7 @@ pub async fn graph_epic(
     Path(epic_id): Path<String>,
     Query(query): Query<GraphQuery>,
 ) -> crate::AppResult<GraphTemplate> {
-    let all_issues = if query.include_closed {
-        state.client.list_all_issues()?
-    } else {
-        state.client.list_issues()?
-    };
+    let all_issues = state.snapshot().await?.issues(query.include_closed);
 
     // Get all epics for the selector
     let epics: Vec<EpicSummary> = all_issues
@@ -176,13 +169,14 @@ pub async fn graph_epic(
 
     // Build nested tree for org-chart visualization
     let tree = super::tasks::build_nested_tree(&descendants);
-    let tree_html = render_tree_html(&tree);
+    let tree_html = render_tree_html(&tree, &state.base_path);
 
     Ok(GraphTemplate {
         project_name: state.project_name.clone(),
         page_title: "Graph".to_string(),
         active_nav: "graph",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         epics,
         tree_html,
         include_closed: query.include_closed,
@@ -196,5 +190,19 @@ pub async fn palette(State(state): State<crate::SharedAppState>) -> PaletteTempl
         page_title: "Design System".to_string(),
         active_nav: "palette",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
+    }
+}
+
+pub async fn about(State(state): State<crate::SharedAppState>) -> AboutTemplate {
+    AboutTemplate {
+        project_name: state.project_name.clone(),
+        page_title: "About".to_string(),
+        active_nav: "about",
+        app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
+        bd: state.bd.clone(),
+        capabilities: state.capabilities(),
+        min_bd_version: crate::beads::MIN_BD_VERSION.to_string(),
     }
 }
@@ -2,7 +2,7 @@ use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use serde::Serialize;
 use std::collections::HashSet;
 
-use crate::beads::{Dependency, DependencyType, Issue, Status};
+use crate::beads::{Dependency, DependencyType, Issue};
 
 /// A node in the dependency graph representing an issue
 #[derive(Debug, Serialize)]
@@ -139,16 +139,11 @@ fn build_graph_data(issues: &[Issue], all_dependencies: &[Dependency]) -> GraphD
 pub async fn graph_data(
     State(state): State<crate::SharedAppState>,
 ) -> crate::AppResult<impl IntoResponse> {
-    let all_issues = state.client.list_issues()?;
-    let all_dependencies = state.client.list_all_dependencies().unwrap_or_default();
+    // The snapshot already leaves out tombstones
+    let snapshot = state.snapshot().await?;
+    let active_issues = snapshot.open_issues();
 
-    // Filter out tombstone issues
-    let active_issues: Vec<Issue> = all_issues
-        .into_iter()
-        .filter(|i| i.status != Status::Tombstone)
-        .collect();
-
-    let graph = build_graph_data(&active_issues, &all_dependencies);
+    let graph = build_graph_data(&active_issues, &snapshot.dependencies);
 
     Ok((StatusCode::OK, Json(graph)))
 }
@@ -156,7 +151,7 @@ pub async fn graph_data(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::beads::IssueType;
+    use crate::beads::{IssueType, Status};
     use time::OffsetDateTime;
 
     fn make_issue(id: &str, issue_type: IssueType, status: Status) -> Issue {
@@ -176,6 +171,9 @@ mod tests {
             close_reason: None,
             estimate: None,
             dependencies: vec![],
+            deleted_at: None,
+            deleted_by: None,
+            dependents: vec![],
         }
     }
 
@@ -60,24 +60,26 @@ pub fn get_issues_by_status(issues: &[Issue], status: Status, limit: usize) -> V
 pub async fn landing(
     State(state): State<crate::SharedAppState>,
 ) -> crate::AppResult<LandingTemplate> {
-    // Use list_all_issues to include closed for accurate stats
-    let all_issues = state.client.list_all_issues()?;
+    // Include closed issues for accurate stats
+    let snapshot = state.snapshot().await?;
+    let all_issues = &snapshot.issues;
 
     // Use pure functions for all calculations
-    let now = OffsetDateTime::now_utc();
-    let dates = generate_date_range(now);
+    let now = crate::config::to_local(OffsetDateTime::now_utc());
+    let dates = generate_date_range(now, state.config.metrics_days);
 
-    let stats = calculate_project_stats(&all_issues);
-    let epics = build_epic_progress_list(&all_issues);
-    let blocked = get_issues_by_status(&all_issues, Status::Blocked, 5);
-    let in_progress = get_issues_by_status(&all_issues, Status::InProgress, 5);
-    let tickets_chart = build_tickets_chart(&all_issues, &dates);
+    let stats = calculate_project_stats(all_issues);
+    let epics = build_epic_progress_list(all_issues);
+    let blocked = get_issues_by_status(all_issues, Status::Blocked, 5);
+    let in_progress = get_issues_by_status(all_issues, Status::InProgress, 5);
+    let tickets_chart = build_tickets_chart(all_issues, &dates);
 
     Ok(LandingTemplate {
         project_name: state.project_name.clone(),
         page_title: String::new(),
         active_nav: "dashboard",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         stats,
         epics,
         blocked,
@@ -107,6 +109,9 @@ mod tests {
             close_reason: None,
             estimate: None,
             dependencies: vec![],
+            deleted_at: None,
+            deleted_by: None,
+            dependents: vec![],
         }
     }
 
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use time::OffsetDateTime;
 use tracing::debug;
 
-use crate::beads::{self, Activity, Issue, Status};
+use crate::beads::{Activity, Issue, Status};
 use crate::templates::*;
 
 // ============================================================================
@@ -141,9 +141,9 @@ pub fn calculate_status_counts(issues: &[Issue], now: OffsetDateTime) -> StatusC
     }
 }
 
-/// Generate date range for chart data (last 7 days including today)
-pub fn generate_date_range(now: OffsetDateTime) -> Vec<time::Date> {
-    let start_dt = now - time::Duration::days(6);
+/// Generate date range for chart data (the last `days` days including today)
+pub fn generate_date_range(now: OffsetDateTime, days: u32) -> Vec<time::Date> {
+    let start_dt = now - time::Duration::days(i64::from(days.max(1)) - 1);
     let mut dates: Vec<time::Date> = Vec::new();
     let mut curr = start_dt.date();
     while curr <= now.date() {
@@ -157,6 +157,11 @@ pub fn generate_date_range(now: OffsetDateTime) -> Vec<time::Date> {
     dates
 }
 
+/// Calendar day of `at` in the configured timezone, which chart dates use
+fn local_date(at: OffsetDateTime) -> time::Date {
+    crate::config::to_local(at).date()
+}
+
 /// Format dates as labels for charts
 pub fn format_date_labels(dates: &[time::Date]) -> Vec<String> {
     let date_format = time::format_description::parse("[month].[day]").unwrap();
@@ -180,12 +185,12 @@ pub fn build_tickets_chart(issues: &[Issue], dates: &[time::Date]) -> ChartData
     }
 
     for issue in issues {
-        let created_date = issue.created_at.date();
+        let created_date = local_date(issue.created_at);
         if created_date >= start_date && created_date <= end_date {
             *created_by_day.entry(created_date).or_insert(0) += 1;
         }
         if let Some(closed_at) = issue.closed_at {
-            let resolved_date = closed_at.date();
+            let resolved_date = local_date(closed_at);
             if resolved_date >= start_date && resolved_date <= end_date {
                 *resolved_by_day.entry(resolved_date).or_insert(0) += 1;
             }
@@ -230,7 +235,7 @@ pub fn build_lead_time_chart(issues: &[Issue], dates: &[time::Date]) -> ChartDat
 
     for issue in issues {
         if let Some(closed_at) = issue.closed_at {
-            let close_date = closed_at.date();
+            let close_date = local_date(closed_at);
             if close_date >= start_date && close_date <= end_date {
                 let lead_time_hours = (closed_at - issue.created_at).whole_minutes() as f64 / 60.0;
                 lead_times_by_day
@@ -292,7 +297,7 @@ pub fn build_cycle_time_chart(
 
     for issue in issues {
         if let Some(closed_at) = issue.closed_at {
-            let close_date = closed_at.date();
+            let close_date = local_date(closed_at);
             if close_date >= start_date
                 && close_date <= end_date
                 && let Some(started_at) = started_times.get(&issue.id)
@@ -383,7 +388,7 @@ pub fn build_throughput_chart(issues: &[Issue], dates: &[time::Date]) -> ChartDa
 
     for issue in issues {
         if let Some(closed_at) = issue.closed_at {
-            let resolved_date = closed_at.date();
+            let resolved_date = local_date(closed_at);
             if resolved_date >= start_date && resolved_date <= end_date {
                 *resolved_by_day.entry(resolved_date).or_insert(0) += 1;
             }
@@ -475,60 +480,32 @@ pub fn build_activity_heatmap(activities: &[Activity], issues: &[Issue]) -> Heat
 // Handler - thin orchestration layer
 // ============================================================================
 
-enum MetricsData {
-    Issues(beads::Result<Vec<Issue>>),
-    Activities(beads::Result<Vec<Activity>>),
-    Summary(beads::Result<serde_json::Value>),
-}
-
 pub async fn metrics_handler(
     State(state): State<crate::SharedAppState>,
 ) -> crate::AppResult<MetricsTemplate> {
-    // Run all 3 CLI calls in parallel using JoinSet with spawn_blocking
-    let mut set: tokio::task::JoinSet<MetricsData> = tokio::task::JoinSet::new();
-
-    let client = state.client.clone();
-    // Use list_all_issues to include closed for accurate metrics
-    set.spawn_blocking(move || MetricsData::Issues(client.list_all_issues()));
-
-    let client = state.client.clone();
-    set.spawn_blocking(move || MetricsData::Activities(client.get_activity()));
-
-    let client = state.client.clone();
-    set.spawn_blocking(move || MetricsData::Summary(client.get_status_summary()));
-
-    let mut all_issues = Vec::new();
-    let mut activities = Vec::new();
-    let mut summary = serde_json::Value::Null;
-
-    while let Some(res) = set.join_next().await {
-        match res.map_err(|e| crate::AppError::BadRequest(format!("Task join failed: {e}")))? {
-            MetricsData::Issues(data) => all_issues = data?,
-            MetricsData::Activities(data) => match data {
-                Ok(acts) => activities = acts,
-                Err(e) => {
-                    debug!(error = %e, "Failed to fetch activities");
-                    activities = Vec::new();
-                }
-            },
-            MetricsData::Summary(data) => summary = data.unwrap_or(serde_json::Value::Null),
-        }
-    }
+    // Load the snapshot (includes closed issues for accurate metrics) and the
+    // status summary concurrently
+    let (snapshot, summary) = tokio::join!(state.snapshot(), state.backend.get_status_summary());
+    let snapshot = snapshot?;
+    let summary = summary.unwrap_or(serde_json::Value::Null);
+
+    let all_issues = &snapshot.issues;
+    let activities = &snapshot.activity;
 
     // Use pure functions for all calculations
-    let now = OffsetDateTime::now_utc();
-    let dates = generate_date_range(now);
+    let now = crate::config::to_local(OffsetDateTime::now_utc());
+    let dates = generate_date_range(now, state.config.metrics_days);
 
-    let started_times = build_started_times_map(&activities);
+    let started_times = build_started_times_map(activities);
     debug!(
         total_activities = activities.len(),
         in_progress_count = started_times.len(),
         "Cycle time: parsed activities"
     );
 
-    let cycle_stats = calculate_cycle_times(&all_issues, &started_times);
-    let lead_stats = calculate_lead_times(&all_issues);
-    let status_counts = calculate_status_counts(&all_issues, now);
+    let cycle_stats = calculate_cycle_times(all_issues, &started_times);
+    let lead_stats = calculate_lead_times(all_issues);
+    let status_counts = calculate_status_counts(all_issues, now);
 
     debug!(
         total_issues = all_issues.len(),
@@ -544,17 +521,18 @@ pub async fn metrics_handler(
     let throughput_per_day = status_counts.closed_last_7_days as f64 / 7.0;
 
     // Build charts using pure functions
-    let tickets_chart = build_tickets_chart(&all_issues, &dates);
-    let lead_time_chart = build_lead_time_chart(&all_issues, &dates);
-    let cycle_time_chart = build_cycle_time_chart(&all_issues, &started_times, &dates);
-    let throughput_chart = build_throughput_chart(&all_issues, &dates);
-    let activity_heatmap = build_activity_heatmap(&activities, &all_issues);
+    let tickets_chart = build_tickets_chart(all_issues, &dates);
+    let lead_time_chart = build_lead_time_chart(all_issues, &dates);
+    let cycle_time_chart = build_cycle_time_chart(all_issues, &started_times, &dates);
+    let throughput_chart = build_throughput_chart(all_issues, &dates);
+    let activity_heatmap = build_activity_heatmap(activities, all_issues);
 
     Ok(MetricsTemplate {
         project_name: state.project_name.clone(),
         page_title: "Metrics".to_string(),
         active_nav: "metrics",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         avg_lead_time_hours,
         avg_cycle_time_mins: cycle_stats.avg_mins,
         throughput_per_day,
@@ -572,6 +550,7 @@ pub async fn metrics_handler(
         p90_cycle_time_mins: cycle_stats.p90_mins,
         p100_cycle_time_mins: cycle_stats.p100_mins,
         activity_heatmap,
+        activity_available: state.capabilities().activity,
     })
 }
 
@@ -602,6 +581,9 @@ mod tests {
             close_reason: None,
             estimate: None,
             dependencies: vec![],
+            deleted_at: None,
+            deleted_by: None,
+            dependents: vec![],
         }
     }
 
@@ -617,6 +599,7 @@ mod tests {
             message: "status changed".to_string(),
             old_status: Some(Status::Open),
             new_status: Some(new_status),
+            actor: None,
         }
     }
 
@@ -680,6 +663,7 @@ mod tests {
             message: "created".to_string(),
             old_status: None,
             new_status: Some(Status::Open),
+            actor: None,
         }];
 
         let started_times = build_started_times_map(&activities);
@@ -785,7 +769,7 @@ mod tests {
     #[test]
     fn test_generate_date_range() {
         let now = time::macros::datetime!(2026-01-07 12:00:00 UTC);
-        let dates = generate_date_range(now);
+        let dates = generate_date_range(now, 7);
 
         assert_eq!(dates.len(), 7);
         assert_eq!(dates[0], time::macros::date!(2026 - 01 - 01));
@@ -802,6 +786,7 @@ mod tests {
             message: "changed".to_string(),
             old_status: Some(Status::Open),
             new_status: Some(Status::InProgress),
+            actor: None,
         }];
 
         let issues = vec![make_test_issue(
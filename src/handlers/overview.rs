@@ -0,0 +1,158 @@
+use axum::{Json, extract::State};
+use time::OffsetDateTime;
+
+use crate::beads::{Issue, Status};
+use crate::handlers::landing::{
+    build_epic_progress_list, calculate_project_stats, get_issues_by_status,
+};
+use crate::handlers::metrics::{
+    build_throughput_chart, calculate_status_counts, generate_date_range,
+};
+use crate::templates::*;
+
+// ============================================================================
+// Pure Functions - testable without mocking
+// ============================================================================
+
+/// Add up the statistics of several projects
+pub fn sum_project_stats<'a>(stats: impl IntoIterator<Item = &'a ProjectStats>) -> ProjectStats {
+    stats
+        .into_iter()
+        .fold(ProjectStats::default(), |total, s| ProjectStats {
+            total: total.total + s.total,
+            open: total.open + s.open,
+            in_progress: total.in_progress + s.in_progress,
+            blocked: total.blocked + s.blocked,
+            closed: total.closed + s.closed,
+        })
+}
+
+/// Overview row of a project whose issues loaded
+pub fn project_overview(
+    name: &str,
+    base_path: &str,
+    issues: &[Issue],
+    now: OffsetDateTime,
+) -> ProjectOverview {
+    ProjectOverview {
+        name: name.to_string(),
+        url: format!("{}/", base_path),
+        stats: calculate_project_stats(issues),
+        closed_last_7_days: calculate_status_counts(issues, now).closed_last_7_days,
+        epics: build_epic_progress_list(issues),
+        error: None,
+    }
+}
+
+// ============================================================================
+// Handlers - thin orchestration layer
+// ============================================================================
+
+/// Load every project and aggregate them. A project that fails to load gets
+/// a row with the error instead of taking the whole overview down.
+async fn build_overview(projects: &crate::app::Projects) -> Overview {
+    let now = crate::config::to_local(OffsetDateTime::now_utc());
+    // Projects may configure different windows; the chart follows the first
+    let days = projects.iter().next().map_or(7, |s| s.config.metrics_days);
+    let dates = generate_date_range(now, days);
+
+    let mut rows = Vec::new();
+    let mut blocked = Vec::new();
+    // Closed issues of every project, for the combined throughput chart
+    let mut closed = Vec::new();
+    for state in projects.iter() {
+        let snapshot = match state.snapshot().await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                tracing::warn!(project = %state.project_name, error = %e, "Could not load project");
+                rows.push(ProjectOverview {
+                    name: state.project_name.clone(),
+                    url: format!("{}/", state.base_path),
+                    stats: ProjectStats::default(),
+                    closed_last_7_days: 0,
+                    epics: Vec::new(),
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+        let issues = &snapshot.issues;
+
+        rows.push(project_overview(
+            &state.project_name,
+            &state.base_path,
+            issues,
+            now,
+        ));
+        blocked.extend(
+            get_issues_by_status(issues, Status::Blocked, usize::MAX)
+                .into_iter()
+                .map(|issue| OverviewBlocked {
+                    project: state.project_name.clone(),
+                    url: format!("{}/tasks/{}", state.base_path, issue.id),
+                    issue,
+                }),
+        );
+        closed.extend(issues.iter().filter(|i| i.closed_at.is_some()).cloned());
+    }
+    blocked.sort_by_key(|b| (b.issue.priority.unwrap_or(2), b.issue.created_at));
+
+    let closed_last_7_days = rows.iter().map(|r| r.closed_last_7_days).sum();
+    Overview {
+        totals: sum_project_stats(rows.iter().map(|r| &r.stats)),
+        closed_last_7_days,
+        throughput_per_day: closed_last_7_days as f64 / 7.0,
+        throughput_chart: build_throughput_chart(&closed, &dates),
+        blocked,
+        projects: rows,
+    }
+}
+
+pub async fn overview(
+    State(projects): State<crate::SharedProjects>,
+) -> crate::AppResult<OverviewTemplate> {
+    Ok(OverviewTemplate {
+        project_name: "All projects".to_string(),
+        page_title: "Overview".to_string(),
+        active_nav: "overview",
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        base_path: String::new(),
+        overview: build_overview(&projects).await,
+    })
+}
+
+pub async fn overview_api(State(projects): State<crate::SharedProjects>) -> Json<Overview> {
+    Json(build_overview(&projects).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_project_stats() {
+        let a = ProjectStats {
+            total: 4,
+            open: 1,
+            in_progress: 1,
+            blocked: 1,
+            closed: 1,
+        };
+        let b = ProjectStats {
+            total: 3,
+            open: 2,
+            in_progress: 0,
+            blocked: 1,
+            closed: 0,
+        };
+
+        let totals = sum_project_stats([&a, &b]);
+
+        assert_eq!(totals.total, 7);
+        assert_eq!(totals.open, 3);
+        assert_eq!(totals.in_progress, 1);
+        assert_eq!(totals.blocked, 2);
+        assert_eq!(totals.closed, 1);
+        assert_eq!(sum_project_stats([]).total, 0);
+    }
+}
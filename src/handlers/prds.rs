@@ -1,14 +1,22 @@
+use std::path::PathBuf;
+
 use axum::extract::{Path, State};
 
 use crate::templates::*;
 
-/// Get list of PRD files sorted by modification time (most recent first)
-fn get_prd_files() -> Vec<(String, std::time::SystemTime)> {
+/// Markdown files in the document directories, sorted by modification time
+/// (most recent first). A name in an earlier directory hides the same name
+/// in later ones.
+fn get_prd_files(dirs: &[PathBuf]) -> Vec<(String, std::time::SystemTime)> {
     let mut files_with_time: Vec<(String, std::time::SystemTime)> = Vec::new();
-    if let Ok(entries) = std::fs::read_dir("docs/prds") {
+    for dir in dirs {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
         for entry in entries.flatten() {
             if let Ok(name) = entry.file_name().into_string()
                 && name.ends_with(".md")
+                && !files_with_time.iter().any(|(f, _)| *f == name)
             {
                 let modified = entry
                     .metadata()
@@ -19,12 +27,19 @@ fn get_prd_files() -> Vec<(String, std::time::SystemTime)> {
         }
     }
     // Sort by most recently modified first
-    files_with_time.sort_by(|a, b| b.1.cmp(&a.1));
+    files_with_time.sort_by_key(|f| std::cmp::Reverse(f.1));
     files_with_time
 }
 
+/// The first document directory that has `filename`
+fn find_prd(dirs: &[PathBuf], filename: &str) -> Option<PathBuf> {
+    dirs.iter()
+        .map(|dir| dir.join(filename))
+        .find(|path| path.is_file())
+}
+
 pub async fn prds_list(State(state): State<crate::SharedAppState>) -> PrdsTemplate {
-    let files = get_prd_files();
+    let files = get_prd_files(&state.config.doc_dirs);
     let prds: Vec<PrdSummary> = files
         .into_iter()
         .map(|(filename, modified)| PrdSummary {
@@ -39,6 +54,7 @@ pub async fn prds_list(State(state): State<crate::SharedAppState>) -> PrdsTempla
         page_title: "PRDs".to_string(),
         active_nav: "prds",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         prds,
         content: String::new(),
     }
@@ -56,13 +72,13 @@ pub async fn prd_view(
         return Err(crate::AppError::BadRequest("Invalid filename".to_string()));
     }
 
-    let path = format!("docs/prds/{}", filename);
-    let markdown_input =
-        std::fs::read_to_string(&path).map_err(|_| crate::AppError::NotFound(filename.clone()))?;
+    let markdown_input = find_prd(&state.config.doc_dirs, &filename)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .ok_or_else(|| crate::AppError::NotFound(filename.clone()))?;
 
     let html_output = crate::markdown::render(&markdown_input);
 
-    let files = get_prd_files();
+    let files = get_prd_files(&state.config.doc_dirs);
     let prds: Vec<PrdSummary> = files
         .into_iter()
         .map(|(f, modified)| PrdSummary {
@@ -77,6 +93,7 @@ pub async fn prd_view(
         page_title: filename.clone(),
         active_nav: "prds",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         prds,
         content: html_output,
     })
@@ -0,0 +1,28 @@
+use axum::{Json, extract::State, response::Redirect};
+use serde::Serialize;
+
+/// A project served under `/p/<name>`
+#[derive(Serialize)]
+pub struct ProjectLink {
+    pub name: String,
+    pub base_path: String,
+}
+
+/// `/` of a multi-project app: the overview of all projects
+pub async fn projects_index() -> Redirect {
+    Redirect::temporary("/overview")
+}
+
+pub async fn list_projects(
+    State(projects): State<crate::SharedProjects>,
+) -> Json<Vec<ProjectLink>> {
+    Json(
+        projects
+            .iter()
+            .map(|state| ProjectLink {
+                name: state.project_name.clone(),
+                base_path: state.base_path.clone(),
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeSet;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::Deserialize;
+
+use crate::beads::Issue;
+use crate::templates::ReadyTemplate;
+
+/// Filters for the ready queue; empty values, as sent by the page's "any"
+/// options, filter nothing
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct ReadyParams {
+    pub assignee: Option<String>,
+    pub label: Option<String>,
+    /// Issue type, e.g. `bug` or `task`
+    #[serde(rename = "type")]
+    pub issue_type: Option<String>,
+}
+
+impl ReadyParams {
+    pub fn matches(&self, issue: &Issue) -> bool {
+        let wanted = |filter: &Option<String>| filter.clone().filter(|f| !f.is_empty());
+
+        wanted(&self.assignee).is_none_or(|assignee| issue.assignee.as_ref() == Some(&assignee))
+            && wanted(&self.label).is_none_or(|label| {
+                issue
+                    .labels
+                    .as_ref()
+                    .is_some_and(|labels| labels.contains(&label))
+            })
+            && wanted(&self.issue_type).is_none_or(|t| issue.issue_type.as_str() == t)
+    }
+}
+
+pub async fn ready(
+    State(state): State<crate::SharedAppState>,
+    Query(params): Query<ReadyParams>,
+) -> crate::AppResult<ReadyTemplate> {
+    let snapshot = state.snapshot().await?;
+    let ready = snapshot.ready_issues();
+
+    // Offer only the values that narrow down the current queue
+    let assignees: BTreeSet<String> = ready.iter().filter_map(|i| i.assignee.clone()).collect();
+    let labels: BTreeSet<String> = ready
+        .iter()
+        .flat_map(|i| i.labels.iter().flatten().cloned())
+        .collect();
+    let issue_types: BTreeSet<String> = ready
+        .iter()
+        .map(|i| i.issue_type.as_str().to_string())
+        .collect();
+
+    Ok(ReadyTemplate {
+        project_name: state.project_name.clone(),
+        page_title: "Ready".to_string(),
+        active_nav: "ready",
+        app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
+        items: ready
+            .into_iter()
+            .filter(|i| params.matches(i))
+            .cloned()
+            .collect(),
+        assignees: assignees.into_iter().collect(),
+        labels: labels.into_iter().collect(),
+        issue_types: issue_types.into_iter().collect(),
+        filter_assignee: params.assignee.unwrap_or_default(),
+        filter_label: params.label.unwrap_or_default(),
+        filter_type: params.issue_type.unwrap_or_default(),
+    })
+}
+
+pub async fn ready_api(
+    State(state): State<crate::SharedAppState>,
+    Query(params): Query<ReadyParams>,
+) -> crate::AppResult<Json<Vec<Issue>>> {
+    let snapshot = state.snapshot().await?;
+    Ok(Json(
+        snapshot
+            .ready_issues()
+            .into_iter()
+            .filter(|i| params.matches(i))
+            .cloned()
+            .collect(),
+    ))
+}
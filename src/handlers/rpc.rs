@@ -0,0 +1,15 @@
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+/// JSON-RPC over HTTP, one message (or batch) per request body
+pub async fn rpc(State(state): State<crate::SharedAppState>, body: String) -> Response {
+    match crate::mcp::handle_text(&state, &body).await {
+        Some(response) => Json(response).into_response(),
+        // Notifications only
+        None => StatusCode::ACCEPTED.into_response(),
+    }
+}
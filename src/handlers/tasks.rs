@@ -8,9 +8,10 @@ use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 
 use crate::beads;
+use crate::snapshot::ProjectSnapshot;
 use crate::templates::{
-    EditIssueTemplate, EpicWithProgress, NestedTreeNode, NewIssueTemplate, TaskDetailTemplate,
-    TasksTemplate, TreeNode,
+    CommentView, EditIssueTemplate, EpicWithProgress, IssueOption, LinkedIssue, NestedTreeNode,
+    NewIssueTemplate, RelationGroup, TaskDetailTemplate, TasksTemplate, TreeNode,
 };
 
 #[derive(Deserialize, Default)]
@@ -23,11 +24,7 @@ pub async fn tasks_list(
     State(state): State<crate::SharedAppState>,
     Query(query): Query<TasksQuery>,
 ) -> crate::AppResult<TasksTemplate> {
-    let all_issues = if query.include_closed {
-        state.client.list_all_issues()?
-    } else {
-        state.client.list_issues()?
-    };
+    let all_issues = state.snapshot().await?.issues(query.include_closed);
     let nodes = build_issue_tree(&all_issues);
 
     Ok(TasksTemplate {
@@ -35,6 +32,7 @@ pub async fn tasks_list(
         page_title: "Tasks".to_string(),
         active_nav: "tasks",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         nodes,
         include_closed: query.include_closed,
     })
@@ -44,14 +42,57 @@ pub async fn task_detail(
     State(state): State<crate::SharedAppState>,
     Path(id): Path<String>,
 ) -> crate::AppResult<TaskDetailTemplate> {
-    let all_issues = state.client.list_all_issues()?;
+    // The snapshot has no reverse edges, so fetch the issue itself for its
+    // relationships and take the linked issues' statuses from the snapshot
+    let comments_enabled = state.capabilities().comments;
+    let (snapshot, detail, comments, activity) = tokio::join!(
+        state.snapshot(),
+        state.backend.get_issue(&id),
+        async {
+            if comments_enabled {
+                state.backend.list_comments(&id).await
+            } else {
+                Ok(Vec::new())
+            }
+        },
+        state.backend.get_issue_activity(&id),
+    );
+    let snapshot = snapshot?;
+    let all_issues = &snapshot.issues;
 
     // Find the issue (any type, not just epics)
-    let issue = all_issues
-        .iter()
-        .find(|i| i.id == id)
+    let issue = snapshot
+        .get(&id)
         .ok_or_else(|| crate::AppError::NotFound(format!("Task {}", id)))?;
 
+    let relations = match detail {
+        Ok(detail) => build_relations(&detail, |linked| snapshot.get(linked)),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to load relationships for {}", id);
+            Vec::new()
+        }
+    };
+    let comments = comments
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to load comments for {}", id);
+            Vec::new()
+        })
+        .into_iter()
+        .map(|c| CommentView {
+            author: c.author,
+            created_at: c.created_at,
+            body_html: crate::markdown::render(&c.text),
+        })
+        .collect();
+
+    let timeline = match activity {
+        Ok(activity) => super::activity::build_timeline(issue.created_at, activity),
+        Err(e) => {
+            tracing::debug!(error = %e, "Failed to load activity for {}", id);
+            Vec::new()
+        }
+    };
+
     // Build tree for just this task and its descendants
     let prefix = format!("{}.", id);
     let descendants: Vec<beads::Issue> = all_issues
@@ -93,12 +134,83 @@ pub async fn task_detail(
         page_title: id.clone(),
         active_nav: "tasks-detail",
         app_version: state.app_version.clone(),
-        task: EpicWithProgress::from_epic(issue, &all_issues, false),
+        base_path: state.base_path.clone(),
+        task: EpicWithProgress::from_epic(issue, all_issues, false),
         children_tree: tree_nodes,
         can_expand,
+        relations,
+        comments,
+        comments_enabled,
+        timeline,
+        link_candidates: snapshot
+            .issues
+            .iter()
+            .filter(|i| i.id != id)
+            .map(|i| IssueOption {
+                id: i.id.clone(),
+                title: i.title.clone(),
+            })
+            .collect(),
+        dependency_types: &beads::DependencyType::KNOWN,
     })
 }
 
+/// Order of the relationship headings on the detail page
+const RELATION_ORDER: [&str; 9] = [
+    "Blocked by",
+    "Blocks",
+    "Related",
+    "Duplicates",
+    "Duplicated by",
+    "Supersedes",
+    "Superseded by",
+    "Discovered from",
+    "Discovered",
+];
+
+/// Group an issue's dependencies and dependents under relationship headings.
+///
+/// Parent-child edges are left out since the children tree already shows
+/// them. `lookup` supplies the linked issues' current title and status.
+pub fn build_relations<'a>(
+    issue: &beads::Issue,
+    lookup: impl Fn(&str) -> Option<&'a beads::Issue>,
+) -> Vec<RelationGroup> {
+    let outgoing = issue.dependencies.iter().filter_map(|dep| {
+        let (label, _) = dep.dep_type.relation_labels()?;
+        Some((label, dep.depends_on_id.as_str(), dep))
+    });
+    let incoming = issue.dependents.iter().filter_map(|dep| {
+        let (_, label) = dep.dep_type.relation_labels()?;
+        Some((label, dep.issue_id.as_str(), dep))
+    });
+
+    let mut seen = HashSet::new();
+    let mut groups: HashMap<&'static str, Vec<LinkedIssue>> = HashMap::new();
+    for (label, linked_id, dep) in outgoing.chain(incoming) {
+        if linked_id == issue.id || !seen.insert((label, linked_id)) {
+            continue;
+        }
+        let linked = lookup(linked_id);
+        groups.entry(label).or_default().push(LinkedIssue {
+            id: linked_id.to_string(),
+            title: linked.map_or_else(|| linked_id.to_string(), |i| i.title.clone()),
+            status: linked.map(|i| i.status.clone()),
+            issue_id: dep.issue_id.clone(),
+            depends_on_id: dep.depends_on_id.clone(),
+        });
+    }
+
+    RELATION_ORDER
+        .iter()
+        .filter_map(|label| {
+            groups
+                .remove(label)
+                .map(|issues| RelationGroup { label, issues })
+        })
+        .collect()
+}
+
 /// Build a hierarchical tree of issues for display
 pub fn build_issue_tree(all_issues: &[beads::Issue]) -> Vec<TreeNode> {
     // Build ID set for O(1) parent lookups (optimization from O(n²) to O(n))
@@ -371,23 +483,53 @@ pub async fn edit_task(
     State(state): State<crate::SharedAppState>,
     Path(id): Path<String>,
 ) -> crate::AppResult<EditIssueTemplate> {
-    let issue = state.client.get_issue(&id)?;
+    let issue = state.backend.get_issue(&id).await?;
     Ok(EditIssueTemplate {
         project_name: state.project_name.clone(),
         page_title: format!("Edit {}", id),
         active_nav: "tasks-edit",
         app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
         issue,
+        issue_types: &beads::IssueType::KNOWN,
     })
 }
 
-pub async fn new_task_form(State(state): State<crate::SharedAppState>) -> NewIssueTemplate {
-    NewIssueTemplate {
+#[derive(Deserialize)]
+pub struct NewTaskParams {
+    /// Pre-fill the form to create a subtask of this issue
+    pub parent: Option<String>,
+}
+
+pub async fn new_task_form(
+    State(state): State<crate::SharedAppState>,
+    Query(params): Query<NewTaskParams>,
+) -> crate::AppResult<NewIssueTemplate> {
+    let parent = match params.parent {
+        Some(id) => {
+            let snapshot = state.snapshot().await?;
+            let issue = snapshot
+                .get(&id)
+                .ok_or_else(|| crate::AppError::NotFound(format!("Task {}", id)))?;
+            Some(IssueOption {
+                id: issue.id.clone(),
+                title: issue.title.clone(),
+            })
+        }
+        None => None,
+    };
+    Ok(NewIssueTemplate {
         project_name: state.project_name.clone(),
-        page_title: "New Task".to_string(),
+        page_title: if parent.is_some() {
+            "New Subtask".to_string()
+        } else {
+            "New Task".to_string()
+        },
         active_nav: "tasks-new",
         app_version: state.app_version.clone(),
-    }
+        base_path: state.base_path.clone(),
+        parent,
+    })
 }
 
 // API handlers
@@ -396,22 +538,21 @@ pub async fn list_tasks(
     State(state): State<crate::SharedAppState>,
     headers: HeaderMap,
 ) -> crate::AppResult<impl IntoResponse> {
-    let issues = state.client.list_issues()?;
-
-    let max_updated_at = issues.iter().map(|i| i.updated_at).max();
-
-    let etag = if let Some(last_mod) = max_updated_at {
-        format!("\"{:x}-{}\"", last_mod.unix_timestamp(), issues.len())
-    } else {
-        format!("\"{}\"", issues.len())
-    };
+    let snapshot = state.snapshot().await?;
+    let etag = snapshot.etag();
 
     if let Some(if_none_match) = headers.get(header::IF_NONE_MATCH)
-        && if_none_match == etag.as_str()
+        && let Ok(if_none_match) = if_none_match.to_str()
+        && snapshot.matches_etag(if_none_match)
     {
-        return Ok(StatusCode::NOT_MODIFIED.into_response());
+        let mut response_headers = HeaderMap::new();
+        response_headers.insert(header::ETAG, etag.parse().unwrap());
+        return Ok((StatusCode::NOT_MODIFIED, response_headers).into_response());
     }
 
+    let issues = snapshot.open_issues();
+    let max_updated_at = issues.iter().map(|i| i.updated_at).max();
+
     let mut response_headers = HeaderMap::new();
     response_headers.insert(header::CACHE_CONTROL, "no-cache".parse().unwrap());
     response_headers.insert(header::ETAG, etag.parse().unwrap());
@@ -428,12 +569,161 @@ pub async fn list_tasks(
     Ok((response_headers, Json(issues)).into_response())
 }
 
+pub async fn get_task(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+) -> crate::AppResult<impl IntoResponse> {
+    let issue = state.backend.get_issue(&id).await?;
+    Ok(([(header::ETAG, issue.etag())], Json(issue)))
+}
+
 pub async fn update_task(
     State(state): State<crate::SharedAppState>,
     Path(id): Path<String>,
+    headers: HeaderMap,
     Json(update): Json<beads::IssueUpdate>,
 ) -> crate::AppResult<StatusCode> {
-    state.client.update_issue(&id, update)?;
+    validate_update(&update)?;
+
+    let _guard = state.writes.lock().await;
+    // Closing through an update is held to the same rule as `close_task`
+    if update.closes() && !update.force {
+        let snapshot = state.snapshot().await?;
+        if let Some(issue) = snapshot.get(&id) {
+            let open_children = open_children(&snapshot, issue);
+            if !open_children.is_empty() {
+                return Err(crate::AppError::OpenChildren(open_children));
+            }
+        }
+    }
+    if let Some(if_match) = headers.get(header::IF_MATCH) {
+        let if_match = if_match
+            .to_str()
+            .map_err(|_| crate::AppError::BadRequest("Invalid If-Match header".to_string()))?;
+        let current = state.backend.get_issue(&id).await?;
+        if !etag_matches(if_match, &current.etag()) {
+            return Err(crate::AppError::PreconditionFailed(Box::new(current)));
+        }
+    }
+    state.backend.update_issue(&id, update).await?;
+    state.data_changed();
+    Ok(StatusCode::OK)
+}
+
+/// Whether an `If-Match` header value names `etag`. Uses strong comparison,
+/// so weak tags never match.
+fn etag_matches(if_match: &str, etag: &str) -> bool {
+    if_match
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag == etag)
+}
+
+/// Reject updates `bd` would refuse or misapply
+pub(crate) fn validate_update(update: &beads::IssueUpdate) -> crate::AppResult<()> {
+    if let Some(status) = &update.status
+        && !status.is_valid()
+    {
+        return Err(crate::AppError::BadRequest(format!(
+            "Unknown status: {}",
+            status
+        )));
+    }
+    if let Some(issue_type) = &update.issue_type
+        && !issue_type.is_valid()
+    {
+        return Err(crate::AppError::BadRequest(format!(
+            "Unknown issue type: {}",
+            issue_type.as_str()
+        )));
+    }
+    if update.close_reason.is_some() && !update.closes() {
+        return Err(crate::AppError::BadRequest(
+            "close_reason can only be set when closing the issue".to_string(),
+        ));
+    }
+    if update
+        .add_labels
+        .iter()
+        .chain(&update.remove_labels)
+        .any(|label| label.trim().is_empty())
+    {
+        return Err(crate::AppError::BadRequest(
+            "Labels must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Children of an epic that are still open and keep it from being closed;
+/// always empty for other issue types
+pub(crate) fn open_children(snapshot: &ProjectSnapshot, issue: &beads::Issue) -> Vec<String> {
+    if issue.issue_type != beads::IssueType::Epic {
+        return Vec::new();
+    }
+    snapshot
+        .children_of(&issue.id)
+        .into_iter()
+        .filter(|child| child.status != beads::Status::Closed)
+        .map(|child| child.id.clone())
+        .collect()
+}
+
+#[derive(Deserialize)]
+pub struct CloseRequest {
+    pub reason: Option<String>,
+    /// Close an epic even though some of its children are still open
+    #[serde(default)]
+    pub force: bool,
+}
+
+pub async fn close_task(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+    Json(request): Json<CloseRequest>,
+) -> crate::AppResult<StatusCode> {
+    // Hold the write lock from the children check until the issue is closed,
+    // so a child reopened or added in between is not missed
+    let _guard = state.writes.lock().await;
+    let snapshot = state.snapshot().await?;
+    let issue = snapshot
+        .get(&id)
+        .ok_or_else(|| crate::AppError::NotFound(format!("Task {}", id)))?;
+
+    if !request.force {
+        let open_children = open_children(&snapshot, issue);
+        if !open_children.is_empty() {
+            return Err(crate::AppError::OpenChildren(open_children));
+        }
+    }
+
+    let reason = request
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+    state.backend.close_issue(&id, reason).await?;
+    state.data_changed();
+    Ok(StatusCode::OK)
+}
+
+#[derive(Deserialize)]
+pub struct ReopenRequest {
+    pub reason: Option<String>,
+}
+
+pub async fn reopen_task(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+    Json(request): Json<ReopenRequest>,
+) -> crate::AppResult<StatusCode> {
+    let reason = request
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+    state.backend.reopen_issue(&id, reason).await?;
+    state.data_changed();
     Ok(StatusCode::OK)
 }
 
@@ -441,6 +731,20 @@ pub async fn create_task(
     State(state): State<crate::SharedAppState>,
     Json(create): Json<beads::IssueCreate>,
 ) -> crate::AppResult<Json<serde_json::Value>> {
-    let id = state.client.create_issue(create)?;
+    if let Some(dep) = create.dependencies.iter().find(|d| !d.dep_type.is_valid()) {
+        return Err(crate::AppError::BadRequest(format!(
+            "Unknown dependency type: {}",
+            dep.dep_type.as_str()
+        )));
+    }
+    // Catch missing links before anything is created, rather than relying
+    // on the backend rolling the new issue back
+    let snapshot = state.snapshot().await?;
+    if let Some((missing, _)) = create.links().find(|(id, _)| snapshot.get(id).is_none()) {
+        return Err(crate::AppError::NotFound(format!("Task {}", missing)));
+    }
+
+    let id = state.backend.create_issue(create).await?;
+    state.data_changed();
     Ok(Json(serde_json::json!({ "id": id })))
 }
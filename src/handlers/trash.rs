@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::beads::EventType;
+use crate::templates::{TrashTemplate, TrashedIssue};
+
+pub async fn trash(State(state): State<crate::SharedAppState>) -> crate::AppResult<TrashTemplate> {
+    let (tombstones, snapshot) = tokio::join!(state.backend.list_tombstones(), state.snapshot());
+    let snapshot = snapshot?;
+
+    let mut items: Vec<TrashedIssue> = tombstones?
+        .into_iter()
+        .map(|issue| {
+            // The tombstone records the deletion itself; the capped activity
+            // feed only fills in for older `bd` versions that do not. The
+            // latest deletion wins if an issue was deleted, restored and
+            // deleted again.
+            let deletion = snapshot
+                .activity
+                .iter()
+                .filter(|a| a.issue_id == issue.id && a.r#type == EventType::Deleted)
+                .max_by_key(|a| a.timestamp);
+            TrashedIssue {
+                deleted_at: issue.deleted_at.or(deletion.map(|a| a.timestamp)),
+                deleted_by: issue
+                    .deleted_by
+                    .clone()
+                    .or_else(|| deletion.and_then(|a| a.actor.clone())),
+                issue,
+            }
+        })
+        .collect();
+    items.sort_by_key(|item| std::cmp::Reverse(item.deleted_at));
+
+    Ok(TrashTemplate {
+        project_name: state.project_name.clone(),
+        page_title: "Trash".to_string(),
+        active_nav: "trash",
+        app_version: state.app_version.clone(),
+        base_path: state.base_path.clone(),
+        items,
+    })
+}
+
+pub async fn delete_task(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+) -> crate::AppResult<StatusCode> {
+    state.backend.delete_issue(&id).await?;
+    state.data_changed();
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn restore_task(
+    State(state): State<crate::SharedAppState>,
+    Path(id): Path<String>,
+) -> crate::AppResult<StatusCode> {
+    state.backend.restore_issue(&id).await?;
+    state.data_changed();
+    Ok(StatusCode::OK)
+}
@@ -1,9 +1,14 @@
+pub mod agents;
 pub mod app;
 pub mod beads;
+pub mod config;
 pub mod error;
+pub mod events;
 pub mod handlers;
 pub mod markdown;
+pub mod mcp;
+pub mod snapshot;
 pub mod templates;
 
-pub use app::{AppState, SharedAppState, create_app};
+pub use app::{AppState, SharedAppState, SharedProjects, create_app, create_multi_app};
 pub use error::{AppError, AppResult};
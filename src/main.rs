@@ -1,44 +1,310 @@
 use argh::FromArgs;
-use nacre::{AppState, create_app};
+use nacre::agents::{AgentRegistry, ExpiryAction};
+use nacre::beads::{self, BeadsBackend};
+use nacre::config::Config;
+use nacre::{AppState, create_app, create_multi_app};
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+/// Where agent sessions are kept between restarts, relative to the project
+const AGENTS_FILE: &str = ".nacre/agents.json";
+
 #[derive(FromArgs, Debug)]
 /// Nacre: A local-first web interface for Beads.
 struct Args {
-    /// host to bind to
-    #[argh(option, default = "String::from(\"127.0.0.1\")")]
-    host: String,
+    /// host to bind to (default 127.0.0.1)
+    #[argh(option)]
+    host: Option<String>,
 
-    /// port to listen on (0 for random available port)
-    #[argh(option, short = 'p', default = "0")]
-    port: u16,
+    /// port to listen on (default 0, a random available port)
+    #[argh(option, short = 'p')]
+    port: Option<u16>,
 
     /// open the browser automatically
     #[argh(switch, short = 'o')]
     open: bool,
+
+    /// where to read issues from: "cli" (run bd), "jsonl" (parse .beads/issues.jsonl)
+    /// or "sqlite" (query the beads database; needs the sqlite feature)
+    #[argh(option, default = "String::from(\"cli\")")]
+    backend: String,
+
+    /// path of the bd binary (default BD_BIN, or bd on the PATH)
+    #[argh(option)]
+    bd_bin: Option<String>,
+
+    /// beads database to use (default BEADS_DB, or the one in .beads)
+    #[argh(option)]
+    db: Option<String>,
+
+    /// seconds a single bd command may run before it is killed
+    #[argh(option, default = "30")]
+    bd_timeout: u64,
+
+    /// seconds without a heartbeat after which an agent session is stale
+    #[argh(option, default = "300")]
+    agent_timeout: u64,
+
+    /// what to do with the issue of a stale agent: "none", "flag" (label it
+    /// stale-agent) or "release" (reopen and unassign it)
+    #[argh(option, default = "String::from(\"none\")")]
+    stale_agents: String,
+
+    /// serve the beads project in this directory under /p/<dir name>; repeat
+    /// for several projects, use name=path to pick the name
+    #[argh(option)]
+    project: Vec<String>,
+
+    #[argh(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(FromArgs, Debug)]
+#[argh(subcommand)]
+enum Command {
+    Mcp(McpArgs),
+}
+
+#[derive(FromArgs, Debug)]
+#[argh(subcommand, name = "mcp")]
+/// Serve the agent tools as JSON-RPC (MCP) over stdin/stdout instead of HTTP.
+struct McpArgs {}
+
+/// A `--project` argument: `path`, named after its directory, or `name=path`
+fn parse_project(arg: &str) -> Result<(String, PathBuf), Box<dyn std::error::Error>> {
+    let (name, path) = match arg.split_once('=') {
+        Some((name, path)) => (Some(name.to_string()), path),
+        None => (None, arg),
+    };
+    // bd runs inside the project, so relative paths would resolve twice
+    let path =
+        std::fs::canonicalize(path).map_err(|e| format!("Project directory '{}': {}", path, e))?;
+    let name = name.unwrap_or_else(|| {
+        path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    });
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(format!(
+            "Project name '{}' may only contain letters, digits, '-' and '_' (use name=path)",
+            name
+        )
+        .into());
+    }
+    Ok((name, path))
+}
+
+/// Find out which bd is installed, refusing to start without a usable one
+async fn probe_bd(client: &beads::Client) -> Result<beads::BdInfo, Box<dyn std::error::Error>> {
+    let info = match client.probe().await {
+        Ok(info) => info,
+        Err(beads::BeadsError::CommandFailed(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(format!(
+                "bd not found at '{}'. Install beads (https://github.com/steveyegge/beads) \
+                 or point BD_BIN at the binary",
+                client.bin_path()
+            )
+            .into());
+        }
+        Err(e) => {
+            return Err(format!("Could not run '{} version': {}", client.bin_path(), e).into());
+        }
+    };
+
+    if !info.is_supported() {
+        return Err(format!(
+            "bd {} is too old, nacre needs {} or newer",
+            info.version,
+            beads::MIN_BD_VERSION
+        )
+        .into());
+    }
+
+    tracing::info!("Using {} ({})", info.bin_path, info.version_line);
+    for (name, command, supported) in info.capabilities.entries() {
+        if !supported {
+            tracing::warn!("bd {} not supported, {} will be limited", command, name);
+        }
+    }
+    Ok(info)
+}
+
+fn build_backend(
+    kind: &str,
+    client: beads::Client,
+) -> Result<Arc<dyn BeadsBackend>, Box<dyn std::error::Error>> {
+    match kind {
+        "cli" => Ok(Arc::new(client)),
+        "jsonl" => {
+            let backend = beads::JsonlBackend::new(client)?;
+            tracing::info!("Reading issues from {}", backend.path().display());
+            Ok(Arc::new(backend))
+        }
+        #[cfg(feature = "sqlite")]
+        "sqlite" => {
+            let backend = beads::SqliteBackend::new(client)?;
+            tracing::info!("Reading issues from {}", backend.path().display());
+            Ok(Arc::new(backend))
+        }
+        #[cfg(not(feature = "sqlite"))]
+        "sqlite" => Err("The sqlite backend requires building nacre with --features sqlite".into()),
+        other => Err(format!(
+            "Unknown backend '{}' (expected cli, jsonl or sqlite)",
+            other
+        )
+        .into()),
+    }
 }
 
 #[tokio::main]
 async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing
+    let args: Args = argh::from_env();
+    let mcp = matches!(args.command, Some(Command::Mcp(_)));
+
+    // Initialize tracing; stdout carries the protocol in MCP mode
+    let writer = if mcp {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
     tracing_subscriber::registry()
         .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| "nacre=info,tower_http=info".into()),
         )
-        .with(tracing_subscriber::fmt::layer().with_timer(
-            tracing_subscriber::fmt::time::UtcTime::new(kiters::timestamp::get_utc_formatter()),
-        ))
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_timer(tracing_subscriber::fmt::time::UtcTime::new(
+                    kiters::timestamp::get_utc_formatter(),
+                )),
+        )
         .init();
 
-    let args: Args = argh::from_env();
-    let state = Arc::new(AppState::new());
+    // nacre.toml settings, overridden by flags. The project's own file sits
+    // next to its .beads, which may be above the current directory.
+    let mut finder = beads::Client::new();
+    if let Some(db) = &args.db {
+        finder = finder.with_db(db.clone());
+    }
+    let root = finder.project_root().unwrap_or_else(|_| PathBuf::from("."));
+    let config = Config::load(&root).map_err(|e| e.to_string())?;
+    nacre::config::init_display(&config);
+
+    let mut client =
+        beads::Client::from_config(&config).with_timeout(Duration::from_secs(args.bd_timeout));
+    if let Some(bin) = &args.bd_bin {
+        client = client.with_bin(bin.clone());
+    }
+    if let Some(db) = &args.db {
+        client = client.with_db(db.clone());
+    }
+    let bd_info = probe_bd(&client).await?;
+    let client = client.with_capabilities(bd_info.capabilities);
 
-    let app = create_app(state);
+    let on_expiry = ExpiryAction::parse(&args.stale_agents).ok_or_else(|| {
+        format!(
+            "Unknown --stale-agents '{}' (expected none, flag or release)",
+            args.stale_agents
+        )
+    })?;
+    let agents = |path: PathBuf| {
+        AgentRegistry::new()
+            .with_path(path)
+            .with_stale_after(Duration::from_secs(args.agent_timeout))
+            .with_expiry_action(on_expiry)
+    };
+
+    if args.project.is_empty() {
+        let state = Arc::new(
+            AppState::with_backend(build_backend(&args.backend, client)?)
+                .with_bd_info(bd_info)
+                .with_agents(agents(root.join(AGENTS_FILE)))
+                .with_config(config.clone()),
+        );
+
+        if mcp {
+            nacre::mcp::serve_stdio(state).await?;
+            return Ok(());
+        }
+
+        nacre::events::spawn_watcher(state.clone());
+        nacre::agents::spawn_reaper(state.clone());
+        return serve(&args, &config, create_app(state)).await;
+    }
+
+    if mcp {
+        return Err("nacre mcp serves a single project; run it without --project".into());
+    }
+    if args.db.is_some() {
+        return Err("--db names a single database; each --project uses its own".into());
+    }
+
+    // Each project gets its own client, database and agent sessions
+    let mut projects = Vec::new();
+    for arg in &args.project {
+        let (name, dir) = parse_project(arg)?;
+        if projects
+            .iter()
+            .any(|p: &nacre::SharedAppState| p.project_name == name)
+        {
+            return Err(format!("Two projects are named '{}' (use name=path)", name).into());
+        }
+        // Each project may have a nacre.toml of its own
+        let project_config = Config::load(&dir).map_err(|e| e.to_string())?;
+        let ignored = project_config.ignored_per_project(&config);
+        if !ignored.is_empty() {
+            tracing::warn!(
+                "Project {}: ignoring {} from its nacre.toml; only the configuration nacre \
+                 starts with sets them",
+                name,
+                ignored.join(", ")
+            );
+        }
+        let mut client = client.clone().with_project_dir(&dir)?;
+        if let Some(db) = &project_config.db_path {
+            client = client.with_db(db.clone());
+        }
+        tracing::info!("Project {}: {}", name, dir.display());
+        let bd_info = beads::BdInfo {
+            db_path: client.database_path().ok(),
+            ..bd_info.clone()
+        };
+        let state = Arc::new(
+            AppState::with_backend(build_backend(&args.backend, client)?)
+                .with_bd_info(bd_info)
+                .with_agents(agents(dir.join(AGENTS_FILE)))
+                .with_config(project_config)
+                .with_project(name),
+        );
+        nacre::events::spawn_watcher(state.clone());
+        nacre::agents::spawn_reaper(state.clone());
+        projects.push(state);
+    }
+
+    serve(&args, &config, create_multi_app(projects)).await
+}
 
-    let addr_str = format!("{}:{}", args.host, args.port);
+async fn serve(
+    args: &Args,
+    config: &Config,
+    app: axum::Router,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let host = args
+        .host
+        .as_deref()
+        .or(config.host.as_deref())
+        .unwrap_or("127.0.0.1");
+    let port = args.port.or(config.port).unwrap_or(0);
+    let addr_str = format!("{}:{}", host, port);
     let addr: SocketAddr = addr_str.parse()?;
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
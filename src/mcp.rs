@@ -0,0 +1,389 @@
+//! JSON-RPC 2.0 interface for agents, speaking the Model Context Protocol.
+//!
+//! The same tools are served over HTTP (`POST /api/rpc`) and over stdio
+//! (`nacre mcp`). MCP clients go through `initialize`, `tools/list` and
+//! `tools/call`; plain JSON-RPC clients may also call a tool directly, using
+//! its name as the method. Tools run the HTTP handlers, so validation and
+//! error messages match the REST API.
+
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::beads::{CommentCreate, IssueCreate, IssueUpdate};
+use crate::handlers::{self, dependencies::DependencyRequest, ready::ReadyParams};
+use crate::{AppError, AppResult, SharedAppState};
+
+/// MCP revision nacre implements
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Any failure reported by the tool itself; `data.status` has the HTTP status
+const TOOL_ERROR: i64 = -32000;
+
+#[derive(Deserialize, JsonSchema)]
+struct GetIssueArgs {
+    id: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateIssueArgs {
+    id: String,
+    #[serde(flatten)]
+    update: IssueUpdate,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct AddCommentArgs {
+    id: String,
+    #[serde(flatten)]
+    comment: CommentCreate,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct AddDependencyArgs {
+    /// The issue that gets the dependency
+    id: String,
+    #[serde(flatten)]
+    dependency: DependencyRequest,
+}
+
+struct Tool {
+    name: &'static str,
+    description: &'static str,
+    schema: fn() -> schemars::schema::RootSchema,
+}
+
+const TOOLS: &[Tool] = &[
+    Tool {
+        name: "list_ready",
+        description: "Open issues with no open blockers, highest priority and oldest first, \
+                      like `bd ready`",
+        schema: || schemars::schema_for!(ReadyParams),
+    },
+    Tool {
+        name: "get_issue",
+        description: "One issue with its description, acceptance criteria and dependencies",
+        schema: || schemars::schema_for!(GetIssueArgs),
+    },
+    Tool {
+        name: "create_issue",
+        description: "Create an issue, optionally with a parent, labels and dependencies; \
+                      returns its ID",
+        schema: || schemars::schema_for!(IssueCreate),
+    },
+    Tool {
+        name: "update_issue",
+        description: "Change fields of an issue; returns the updated issue. Closing an epic \
+                      with open children fails unless `force` is set",
+        schema: || schemars::schema_for!(UpdateIssueArgs),
+    },
+    Tool {
+        name: "add_comment",
+        description: "Add a markdown comment to an issue",
+        schema: || schemars::schema_for!(AddCommentArgs),
+    },
+    Tool {
+        name: "add_dependency",
+        description: "Make an issue depend on another; edges that would form a \
+                      blocks/parent-child cycle are refused",
+        schema: || schemars::schema_for!(AddDependencyArgs),
+    },
+];
+
+/// Tool descriptions for `tools/list`
+pub fn tool_list() -> Value {
+    let tools: Vec<Value> = TOOLS
+        .iter()
+        .map(|tool| {
+            json!({
+                "name": tool.name,
+                "description": tool.description,
+                "inputSchema": (tool.schema)(),
+            })
+        })
+        .collect();
+    json!({ "tools": tools })
+}
+
+fn arguments<T: serde::de::DeserializeOwned>(arguments: Value) -> AppResult<T> {
+    // Tools without required arguments may be called without any
+    let arguments = if arguments.is_null() {
+        json!({})
+    } else {
+        arguments
+    };
+    serde_json::from_value(arguments).map_err(|e| AppError::BadRequest(e.to_string()))
+}
+
+/// Run a tool; `None` if there is no tool by that name
+pub async fn call_tool(
+    state: &SharedAppState,
+    name: &str,
+    args: Value,
+) -> Option<AppResult<Value>> {
+    let state = State(state.clone());
+    let result = match name {
+        "list_ready" => {
+            async {
+                let Json(issues) = handlers::ready_api(state, Query(arguments(args)?)).await?;
+                Ok(json!(issues))
+            }
+            .await
+        }
+        "get_issue" => {
+            async {
+                let GetIssueArgs { id } = arguments(args)?;
+                Ok(json!(state.backend.get_issue(&id).await?))
+            }
+            .await
+        }
+        "create_issue" => {
+            async {
+                let Json(created) = handlers::create_task(state, Json(arguments(args)?)).await?;
+                Ok(created)
+            }
+            .await
+        }
+        "update_issue" => {
+            async {
+                let UpdateIssueArgs { id, update } = arguments(args)?;
+                handlers::update_task(
+                    state.clone(),
+                    Path(id.clone()),
+                    HeaderMap::new(),
+                    Json(update),
+                )
+                .await?;
+                Ok(json!(state.backend.get_issue(&id).await?))
+            }
+            .await
+        }
+        "add_comment" => {
+            async {
+                let AddCommentArgs { id, comment } = arguments(args)?;
+                let (_, Json(comment)) =
+                    handlers::add_comment(state, Path(id), Json(comment)).await?;
+                Ok(json!(comment))
+            }
+            .await
+        }
+        "add_dependency" => {
+            async {
+                let AddDependencyArgs { id, dependency } = arguments(args)?;
+                let added = json!({
+                    "issue_id": id,
+                    "depends_on_id": dependency.depends_on_id,
+                    "type": dependency.dep_type,
+                });
+                handlers::add_dependency(state, Path(id), Json(dependency)).await?;
+                Ok(added)
+            }
+            .await
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn success(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn failure(id: Value, code: i64, message: impl Into<String>, data: Option<Value>) -> Value {
+    let mut error = json!({ "code": code, "message": message.into() });
+    if let Some(data) = data {
+        error["data"] = data;
+    }
+    json!({ "jsonrpc": "2.0", "id": id, "error": error })
+}
+
+fn tool_failure(id: Value, error: &AppError) -> Value {
+    let code = match error {
+        AppError::BadRequest(_) => INVALID_PARAMS,
+        _ => TOOL_ERROR,
+    };
+    let mut data = error.details().unwrap_or_else(|| json!({}));
+    data["status"] = error.status().as_u16().into();
+    failure(id, code, error.public_message(), Some(data))
+}
+
+/// Answer one JSON-RPC message, a single request or a batch.
+///
+/// Returns `None` when nothing should be sent back, i.e. for notifications.
+pub async fn handle_message(state: &SharedAppState, message: Value) -> Option<Value> {
+    match message {
+        Value::Array(batch) if batch.is_empty() => {
+            Some(failure(Value::Null, INVALID_REQUEST, "Empty batch", None))
+        }
+        Value::Array(batch) => {
+            let mut responses = Vec::new();
+            for request in batch {
+                responses.extend(handle_request(state, request).await);
+            }
+            (!responses.is_empty()).then_some(Value::Array(responses))
+        }
+        request => handle_request(state, request).await,
+    }
+}
+
+async fn handle_request(state: &SharedAppState, request: Value) -> Option<Value> {
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        return Some(failure(id, INVALID_REQUEST, "Missing method", None));
+    };
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+    // Requests without an ID are notifications and get no response
+    let id = request.get("id").cloned()?;
+
+    let response = match method {
+        "initialize" => success(
+            id,
+            json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": {} },
+                "serverInfo": { "name": "nacre", "version": state.app_version },
+            }),
+        ),
+        "ping" => success(id, json!({})),
+        "tools/list" => success(id, tool_list()),
+        "tools/call" => {
+            let Some(name) = params.get("name").and_then(Value::as_str) else {
+                return Some(failure(id, INVALID_PARAMS, "Missing tool name", None));
+            };
+            let args = params.get("arguments").cloned().unwrap_or(Value::Null);
+            match call_tool(state, name, args).await {
+                None => failure(id, INVALID_PARAMS, format!("Unknown tool: {}", name), None),
+                // MCP reports tool failures as results the model can read
+                Some(Ok(value)) => success(
+                    id,
+                    json!({
+                        "content": [{ "type": "text", "text": value.to_string() }],
+                        "isError": false,
+                    }),
+                ),
+                Some(Err(e)) => success(
+                    id,
+                    json!({
+                        "content": [{ "type": "text", "text": e.public_message() }],
+                        "isError": true,
+                    }),
+                ),
+            }
+        }
+        tool => match call_tool(state, tool, params).await {
+            None => failure(
+                id,
+                METHOD_NOT_FOUND,
+                format!("Unknown method: {}", tool),
+                None,
+            ),
+            Some(Ok(value)) => success(id, value),
+            Some(Err(e)) => tool_failure(id, &e),
+        },
+    };
+    Some(response)
+}
+
+/// Parse and answer a raw message, reporting malformed JSON as a parse error
+pub async fn handle_text(state: &SharedAppState, text: &str) -> Option<Value> {
+    match serde_json::from_str(text) {
+        Ok(message) => handle_message(state, message).await,
+        Err(e) => Some(failure(Value::Null, PARSE_ERROR, e.to_string(), None)),
+    }
+}
+
+/// Serve newline-delimited JSON-RPC until `reader` is exhausted
+pub async fn serve<R, W>(state: SharedAppState, reader: R, mut writer: W) -> std::io::Result<()>
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_text(&state, &line).await {
+            writer.write_all(response.to_string().as_bytes()).await?;
+            writer.write_all(b"\n").await?;
+            writer.flush().await?;
+        }
+    }
+    Ok(())
+}
+
+/// Serve the tools over stdin/stdout, for `nacre mcp`
+pub async fn serve_stdio(state: SharedAppState) -> std::io::Result<()> {
+    let stdin = tokio::io::BufReader::new(tokio::io::stdin());
+    serve(state, stdin, tokio::io::stdout()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AppState;
+    use crate::beads::MemoryBackend;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_stdio_answers_requests_and_skips_notifications() {
+        let state = Arc::new(AppState::with_backend(Arc::new(MemoryBackend::new())));
+        let input = concat!(
+            r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+            "\n\n",
+            "not json\n",
+            r#"{"jsonrpc":"2.0","id":2,"method":"tools/list"}"#,
+            "\n",
+        );
+        let mut output = Vec::new();
+
+        serve(state, input.as_bytes(), &mut output).await.unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0]["result"]["protocolVersion"], PROTOCOL_VERSION);
+        assert_eq!(responses[1]["error"]["code"], PARSE_ERROR);
+        let tools = responses[2]["result"]["tools"].as_array().unwrap();
+        assert_eq!(tools.len(), TOOLS.len());
+    }
+
+    #[test]
+    fn test_tool_schemas_come_from_request_types() {
+        let list = tool_list();
+        let schema = |name: &str| {
+            list["tools"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|tool| tool["name"] == name)
+                .unwrap()["inputSchema"]
+                .clone()
+        };
+
+        let update = schema("update_issue");
+        assert_eq!(update["required"], json!(["id"]));
+        assert!(update["properties"]["add_labels"].is_object());
+        assert_eq!(
+            update["properties"]["status"]["type"],
+            json!(["string", "null"])
+        );
+
+        let create = schema("create_issue");
+        assert_eq!(create["required"], json!(["title"]));
+        assert!(create["properties"]["dependencies"].is_object());
+    }
+}
@@ -0,0 +1,596 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use tokio::sync::Mutex;
+
+use crate::beads::{self, Activity, BeadsBackend, Dependency, Issue, Status};
+
+/// Everything the read-only views need, fetched once per data version.
+///
+/// `issues` holds every non-tombstone issue including closed ones; handlers
+/// filter from there instead of asking the backend again.
+pub struct ProjectSnapshot {
+    /// Bumped whenever the data behind the snapshot actually changed
+    pub version: u64,
+    pub issues: Vec<Issue>,
+    pub dependencies: Vec<Dependency>,
+    pub activity: Vec<Activity>,
+    /// IDs of issues with a workflow dependency on an issue that is not closed
+    pub blocked_ids: HashSet<String>,
+    by_id: HashMap<String, usize>,
+    epoch: u64,
+}
+
+impl ProjectSnapshot {
+    async fn build(backend: &dyn BeadsBackend, version: u64, epoch: u64) -> beads::Result<Self> {
+        // Each call may spawn `bd`, so run them concurrently
+        let (issues, dependencies, activity) = tokio::join!(
+            backend.list_all_issues(),
+            backend.list_all_dependencies(),
+            backend.get_activity(),
+        );
+
+        let issues: Vec<Issue> = issues?
+            .into_iter()
+            .filter(|i| i.status != Status::Tombstone)
+            .collect();
+        let dependencies = dependencies.unwrap_or_else(|e| {
+            tracing::debug!(error = %e, "Failed to fetch dependencies");
+            Vec::new()
+        });
+        let activity = activity.unwrap_or_else(|e| {
+            tracing::debug!(error = %e, "Failed to fetch activities");
+            Vec::new()
+        });
+
+        let by_id = issues
+            .iter()
+            .enumerate()
+            .map(|(idx, issue)| (issue.id.clone(), idx))
+            .collect();
+        let blocked_ids = compute_blocked_ids(&issues, &dependencies);
+
+        Ok(Self {
+            version,
+            issues,
+            dependencies,
+            activity,
+            blocked_ids,
+            by_id,
+            epoch,
+        })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Issue> {
+        self.by_id.get(id).map(|&idx| &self.issues[idx])
+    }
+
+    /// Issues that are not closed, the equivalent of `list_issues`
+    pub fn open_issues(&self) -> Vec<Issue> {
+        self.issues
+            .iter()
+            .filter(|i| i.status != Status::Closed)
+            .cloned()
+            .collect()
+    }
+
+    /// Issues for a view with an "include closed" toggle
+    pub fn issues(&self, include_closed: bool) -> Vec<Issue> {
+        if include_closed {
+            self.issues.clone()
+        } else {
+            self.open_issues()
+        }
+    }
+
+    /// Direct children of an issue: parent-child dependents, plus issues
+    /// whose dotted ID extends it (`nc-3hd.1` under `nc-3hd`)
+    pub fn children_of(&self, id: &str) -> Vec<&Issue> {
+        let linked: HashSet<&str> = self
+            .dependencies
+            .iter()
+            .filter(|d| d.depends_on_id == id && d.dep_type == beads::DependencyType::ParentChild)
+            .map(|d| d.issue_id.as_str())
+            .collect();
+        self.issues
+            .iter()
+            .filter(|i| {
+                linked.contains(i.id.as_str())
+                    || i.id
+                        .rsplit_once('.')
+                        .is_some_and(|(parent, _)| parent == id)
+            })
+            .collect()
+    }
+
+    /// Open issues with no open workflow blockers, highest priority and
+    /// oldest first: the work `bd ready` would offer
+    pub fn ready_issues(&self) -> Vec<&Issue> {
+        let blocked = compute_ready_blockers(&self.issues, &self.dependencies);
+        let mut ready: Vec<&Issue> = self
+            .issues
+            .iter()
+            .filter(|i| i.status == Status::Open && !blocked.contains(i.id.as_str()))
+            .collect();
+        ready.sort_by_key(|i| (i.priority.unwrap_or(2), i.created_at));
+        ready
+    }
+
+    /// Strong entity tag for the snapshot version.
+    ///
+    /// Includes a per-process epoch so tags handed out before a restart never
+    /// match a fresh version counter.
+    pub fn etag(&self) -> String {
+        format!("\"{:x}-{:x}\"", self.epoch, self.version)
+    }
+
+    /// Whether an `If-None-Match` header value matches this snapshot
+    pub fn matches_etag(&self, if_none_match: &str) -> bool {
+        let etag = self.etag();
+        if_none_match
+            .split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == "*" || tag == etag)
+    }
+}
+
+/// An issue is blocked if it has a workflow dependency on an issue that
+/// exists and is not closed
+pub fn compute_blocked_ids(issues: &[Issue], dependencies: &[Dependency]) -> HashSet<String> {
+    let status_map: HashMap<&str, &Status> =
+        issues.iter().map(|i| (i.id.as_str(), &i.status)).collect();
+
+    dependencies
+        .iter()
+        .filter(|dep| dep.dep_type.affects_workflow())
+        .filter(|dep| {
+            status_map
+                .get(dep.depends_on_id.as_str())
+                .is_some_and(|status| **status != Status::Closed)
+        })
+        .map(|dep| dep.issue_id.clone())
+        .collect()
+}
+
+/// IDs of issues `bd ready` leaves out because of their dependencies.
+///
+/// Blocking edges hold an issue up while their target is not closed. A
+/// parent-child edge passes blocking down instead: children of a blocked
+/// parent are blocked too, however deep, but an open parent alone blocks
+/// nothing.
+fn compute_ready_blockers<'a>(
+    issues: &'a [Issue],
+    dependencies: &'a [Dependency],
+) -> HashSet<&'a str> {
+    let status_map: HashMap<&str, &Status> =
+        issues.iter().map(|i| (i.id.as_str(), &i.status)).collect();
+
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut blocked: Vec<&str> = Vec::new();
+    for dep in dependencies
+        .iter()
+        .filter(|d| d.dep_type.affects_workflow())
+    {
+        if dep.dep_type == beads::DependencyType::ParentChild {
+            children
+                .entry(dep.depends_on_id.as_str())
+                .or_default()
+                .push(dep.issue_id.as_str());
+        } else if status_map
+            .get(dep.depends_on_id.as_str())
+            .is_some_and(|status| **status != Status::Closed)
+        {
+            blocked.push(dep.issue_id.as_str());
+        }
+    }
+
+    let mut seen = HashSet::new();
+    while let Some(id) = blocked.pop() {
+        if seen.insert(id) {
+            blocked.extend(children.get(id).into_iter().flatten());
+        }
+    }
+    seen
+}
+
+/// The cycle that a new `issue_id` → `depends_on_id` edge would close.
+///
+/// Follows only edges whose type forbids cycles (blocks and parent-child).
+/// Returns the path `[issue_id, depends_on_id, ..., issue_id]`, or `None` if
+/// the new edge is safe.
+pub fn find_cycle(
+    dependencies: &[Dependency],
+    issue_id: &str,
+    depends_on_id: &str,
+) -> Option<Vec<String>> {
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    for dep in dependencies.iter().filter(|d| d.dep_type.forbids_cycles()) {
+        edges
+            .entry(dep.issue_id.as_str())
+            .or_default()
+            .push(dep.depends_on_id.as_str());
+    }
+
+    // Breadth-first from the new target back to the new source, so the
+    // reported cycle is the shortest one
+    let mut came_from: HashMap<&str, &str> = HashMap::new();
+    let mut queue = std::collections::VecDeque::from([depends_on_id]);
+    let mut seen = HashSet::from([depends_on_id]);
+    while let Some(current) = queue.pop_front() {
+        if current == issue_id {
+            // Walk back to the new target, then close the loop at the source
+            let mut path = vec![current];
+            while let Some(&previous) = path.last().and_then(|node| came_from.get(node)) {
+                path.push(previous);
+            }
+            path.push(issue_id);
+            path.reverse();
+            let path = path.into_iter().map(str::to_string).collect();
+            return Some(path);
+        }
+        for &next in edges.get(current).into_iter().flatten() {
+            if seen.insert(next) {
+                came_from.insert(next, current);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Hash the snapshot content so a rebuild that finds the same data keeps
+/// the same version
+fn content_hash(snapshot: &ProjectSnapshot) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    serde_json::to_string(&snapshot.issues)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    serde_json::to_string(&snapshot.dependencies)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    // The feed is capped, so its length stops changing once full; the newest
+    // event still does
+    snapshot.activity.len().hash(&mut hasher);
+    let newest = snapshot.activity.iter().max_by_key(|a| a.timestamp);
+    serde_json::to_string(&newest)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+struct CachedSnapshot {
+    snapshot: Arc<ProjectSnapshot>,
+    fingerprint: Option<u64>,
+    content: u64,
+}
+
+/// Shared cache of the current `ProjectSnapshot`.
+///
+/// The snapshot is reused while the backend fingerprint (the modification
+/// time and size of `.beads/issues.jsonl` and the database) is unchanged and
+/// nobody called `invalidate`. Concurrent requests that find it stale wait for
+/// a single rebuild instead of all hitting the backend.
+pub struct SnapshotCache {
+    current: RwLock<Option<CachedSnapshot>>,
+    rebuild: Mutex<()>,
+    stale: AtomicBool,
+    next_version: AtomicU64,
+    epoch: u64,
+}
+
+impl Default for SnapshotCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SnapshotCache {
+    pub fn new() -> Self {
+        let epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+
+        Self {
+            current: RwLock::new(None),
+            rebuild: Mutex::new(()),
+            stale: AtomicBool::new(false),
+            next_version: AtomicU64::new(1),
+            epoch,
+        }
+    }
+
+    /// Drop the cached snapshot on the next access, e.g. after a write
+    pub fn invalidate(&self) {
+        self.stale.store(true, Ordering::SeqCst);
+    }
+
+    /// Return the current snapshot, rebuilding it if the data changed
+    pub async fn get(&self, backend: &dyn BeadsBackend) -> beads::Result<Arc<ProjectSnapshot>> {
+        let fingerprint = backend.fingerprint();
+        if let Some(snapshot) = self.fresh(fingerprint) {
+            return Ok(snapshot);
+        }
+
+        let _guard = self.rebuild.lock().await;
+        // Another request may have rebuilt it while we waited
+        let fingerprint = backend.fingerprint();
+        if let Some(snapshot) = self.fresh(fingerprint) {
+            return Ok(snapshot);
+        }
+
+        self.stale.store(false, Ordering::SeqCst);
+        let version = self.next_version.load(Ordering::SeqCst);
+        let mut snapshot = ProjectSnapshot::build(backend, version, self.epoch).await?;
+        let content = content_hash(&snapshot);
+
+        let mut current = self.current.write().unwrap();
+        match current.as_ref() {
+            Some(cached) if cached.content == content => {
+                snapshot.version = cached.snapshot.version;
+            }
+            _ => {
+                self.next_version.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let snapshot = Arc::new(snapshot);
+        *current = Some(CachedSnapshot {
+            snapshot: snapshot.clone(),
+            fingerprint,
+            content,
+        });
+        Ok(snapshot)
+    }
+
+    fn fresh(&self, fingerprint: Option<u64>) -> Option<Arc<ProjectSnapshot>> {
+        if self.stale.load(Ordering::SeqCst) {
+            return None;
+        }
+        let current = self.current.read().unwrap();
+        let cached = current.as_ref()?;
+        (fingerprint.is_some() && cached.fingerprint == fingerprint)
+            .then(|| cached.snapshot.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::{DependencyType, IssueCreate, IssueType, IssueUpdate, MemoryBackend};
+    use time::OffsetDateTime;
+
+    fn issue(id: &str, status: Status) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {}", id),
+            status,
+            priority: Some(2),
+            issue_type: IssueType::Task,
+            created_at: OffsetDateTime::UNIX_EPOCH,
+            updated_at: OffsetDateTime::UNIX_EPOCH,
+            closed_at: None,
+            assignee: None,
+            labels: None,
+            description: None,
+            acceptance_criteria: None,
+            close_reason: None,
+            estimate: None,
+            dependencies: vec![],
+            deleted_at: None,
+            deleted_by: None,
+            dependents: vec![],
+        }
+    }
+
+    fn blocks(issue_id: &str, depends_on_id: &str) -> Dependency {
+        Dependency {
+            issue_id: issue_id.to_string(),
+            depends_on_id: depends_on_id.to_string(),
+            dep_type: DependencyType::Blocks,
+            created_at: None,
+            created_by: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_is_reused_until_data_changes() {
+        let backend = MemoryBackend::new().with_issues(vec![issue("t-1", Status::Open)]);
+        let cache = SnapshotCache::new();
+
+        let first = cache.get(&backend).await.unwrap();
+        let second = cache.get(&backend).await.unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        backend
+            .create_issue(IssueCreate {
+                title: "new".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let third = cache.get(&backend).await.unwrap();
+        assert_eq!(third.issues.len(), 2);
+        assert!(third.version > first.version);
+        assert_ne!(third.etag(), first.etag());
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_without_changes_keeps_version() {
+        let backend = MemoryBackend::new().with_issues(vec![issue("t-1", Status::Open)]);
+        let cache = SnapshotCache::new();
+
+        let first = cache.get(&backend).await.unwrap();
+        cache.invalidate();
+        let second = cache.get(&backend).await.unwrap();
+
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(first.version, second.version);
+        assert!(second.matches_etag(&first.etag()));
+    }
+
+    #[tokio::test]
+    async fn test_index_and_open_issues() {
+        let backend = MemoryBackend::new().with_issues(vec![
+            issue("t-1", Status::Open),
+            issue("t-2", Status::Closed),
+            issue("t-3", Status::Tombstone),
+        ]);
+        let cache = SnapshotCache::new();
+        let snapshot = cache.get(&backend).await.unwrap();
+
+        assert_eq!(snapshot.get("t-2").unwrap().title, "Issue t-2");
+        assert!(snapshot.get("t-3").is_none());
+        assert_eq!(snapshot.open_issues().len(), 1);
+        assert_eq!(snapshot.issues(true).len(), 2);
+
+        backend
+            .update_issue(
+                "t-1",
+                IssueUpdate {
+                    status: Some(Status::InProgress),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            cache
+                .get(&backend)
+                .await
+                .unwrap()
+                .get("t-1")
+                .unwrap()
+                .status,
+            Status::InProgress
+        );
+    }
+
+    #[test]
+    fn test_blocked_ids_ignore_closed_and_missing_blockers() {
+        let issues = vec![
+            issue("t-1", Status::Open),
+            issue("t-2", Status::Open),
+            issue("t-3", Status::Closed),
+        ];
+        let deps = vec![
+            blocks("t-1", "t-2"),
+            blocks("t-2", "t-3"),
+            blocks("t-3", "t-404"),
+        ];
+
+        let blocked = compute_blocked_ids(&issues, &deps);
+        assert_eq!(blocked, HashSet::from(["t-1".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_ready_issues_follow_bd_ready() {
+        let mut urgent = issue("t-5", Status::Open);
+        urgent.priority = Some(0);
+        let mut older = issue("t-6", Status::Open);
+        older.created_at = OffsetDateTime::UNIX_EPOCH - time::Duration::days(1);
+        let child_of = |child: &str, parent: &str| {
+            let mut dep = blocks(child, parent);
+            dep.dep_type = DependencyType::ParentChild;
+            dep
+        };
+        // Blocked directly, and its subtree with it
+        let mut blocked = issue("t-2", Status::Open);
+        blocked.dependencies.push(blocks("t-2", "t-4"));
+        let mut blocked_child = issue("t-3", Status::Open);
+        blocked_child.dependencies.push(child_of("t-3", "t-2"));
+        // An open parent does not hold its children back
+        let mut free_child = issue("t-7", Status::Open);
+        free_child.dependencies.push(child_of("t-7", "t-1"));
+        let backend = MemoryBackend::new().with_issues(vec![
+            issue("t-1", Status::Open),
+            blocked,
+            blocked_child,
+            issue("t-4", Status::InProgress),
+            urgent,
+            older,
+            free_child,
+        ]);
+        let snapshot = SnapshotCache::new().get(&backend).await.unwrap();
+
+        let ready: Vec<&str> = snapshot
+            .ready_issues()
+            .iter()
+            .map(|i| i.id.as_str())
+            .collect();
+        assert_eq!(ready, ["t-5", "t-6", "t-1", "t-7"]);
+    }
+
+    #[test]
+    fn test_find_cycle_reports_shortest_path() {
+        let mut parent = blocks("t-3", "t-1");
+        parent.dep_type = DependencyType::ParentChild;
+        let mut related = blocks("t-1", "t-4");
+        related.dep_type = DependencyType::Related;
+        let deps = vec![blocks("t-2", "t-3"), parent, related];
+
+        // t-1 → t-2 → t-3 → t-1
+        assert_eq!(
+            find_cycle(&deps, "t-1", "t-2"),
+            Some(vec![
+                "t-1".to_string(),
+                "t-2".to_string(),
+                "t-3".to_string(),
+                "t-1".to_string()
+            ])
+        );
+        // Related edges do not count
+        assert_eq!(find_cycle(&deps, "t-4", "t-1"), None);
+        assert_eq!(find_cycle(&deps, "t-2", "t-4"), None);
+        assert_eq!(
+            find_cycle(&deps, "t-5", "t-5"),
+            Some(vec!["t-5".to_string(), "t-5".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_content_hash_follows_newest_activity() {
+        let event = |minute: i64, message: &str| Activity {
+            timestamp: OffsetDateTime::UNIX_EPOCH + time::Duration::minutes(minute),
+            r#type: beads::EventType::Commented,
+            issue_id: "t-1".to_string(),
+            message: message.to_string(),
+            old_status: None,
+            new_status: None,
+            actor: None,
+        };
+        let snapshot = |activity: Vec<Activity>| ProjectSnapshot {
+            version: 0,
+            issues: vec![issue("t-1", Status::Open)],
+            dependencies: vec![],
+            activity,
+            blocked_ids: HashSet::new(),
+            by_id: HashMap::new(),
+            epoch: 0,
+        };
+
+        // A full feed drops its oldest event for each new one
+        let before = snapshot(vec![event(1, "first"), event(2, "second")]);
+        let after = snapshot(vec![event(2, "second"), event(3, "third")]);
+        assert_ne!(content_hash(&before), content_hash(&after));
+        assert_eq!(
+            content_hash(&after),
+            content_hash(&snapshot(vec![event(2, "second"), event(3, "third")]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_matches_etag_lists_and_wildcards() {
+        let backend = MemoryBackend::new();
+        let snapshot = SnapshotCache::new().get(&backend).await.unwrap();
+        let etag = snapshot.etag();
+
+        assert!(snapshot.matches_etag(&format!("\"other\", {}", etag)));
+        assert!(snapshot.matches_etag(&format!("W/{}", etag)));
+        assert!(snapshot.matches_etag("*"));
+        assert!(!snapshot.matches_etag("\"other\""));
+    }
+}
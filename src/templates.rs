@@ -15,7 +15,8 @@ pub mod filters {
     pub fn format_date(date: &time::OffsetDateTime) -> askama::Result<String> {
         let format = time::format_description::parse("[year]-[month]-[day] [hour]:[minute]")
             .map_err(|e| askama::Error::Custom(Box::new(e)))?;
-        date.format(&format)
+        crate::config::to_local(*date)
+            .format(&format)
             .map_err(|e| askama::Error::Custom(Box::new(e)))
     }
     pub fn round(val: &f64) -> askama::Result<i64> {
@@ -23,6 +24,7 @@ pub mod filters {
     }
 }
 
+#[derive(Default, serde::Serialize)]
 pub struct ProjectStats {
     pub total: usize,
     pub open: usize,
@@ -31,11 +33,13 @@ pub struct ProjectStats {
     pub closed: usize,
 }
 
+#[derive(serde::Serialize)]
 pub struct EpicWithProgress {
     pub issue: beads::Issue,
     pub total: usize,
     pub closed: usize,
     pub percent: f64,
+    #[serde(skip)]
     pub description_html: Option<String>,
 }
 
@@ -122,7 +126,7 @@ pub struct NestedTreeNode {
 
 impl NestedTreeNode {
     /// Render this node and its children as HTML for the org-chart tree
-    pub fn render_html(&self) -> String {
+    pub fn render_html(&self, base_path: &str) -> String {
         let blocked_badge = if self.blocked_by_count > 0 {
             r#"<span class="org-node-blocked">blocked</span>"#
         } else {
@@ -132,13 +136,17 @@ impl NestedTreeNode {
         let children_html = if self.children.is_empty() {
             String::new()
         } else {
-            let children: String = self.children.iter().map(|c| c.render_html()).collect();
+            let children: String = self
+                .children
+                .iter()
+                .map(|c| c.render_html(base_path))
+                .collect();
             format!("<ul>{}</ul>", children)
         };
 
         format!(
             r#"<li>
-    <a href="/tasks/{id}" class="org-node status-{status} type-{issue_type}">
+    <a href="{base_path}/tasks/{id}" class="org-node status-{status} type-{issue_type}">
         <span class="org-node-dot org-node-dot--type type-{issue_type}" title="{issue_type}"></span>
         <span class="org-node-dot org-node-dot--status status-{status}" title="{status}"></span>
         <div class="org-node-content">
@@ -149,9 +157,10 @@ impl NestedTreeNode {
     </a>
     {children_html}
 </li>"#,
+            base_path = askama::MarkupDisplay::new_unsafe(base_path, askama::Html),
             id = askama::MarkupDisplay::new_unsafe(&self.id, askama::Html),
-            status = self.status,
-            issue_type = self.issue_type,
+            status = askama::MarkupDisplay::new_unsafe(&self.status, askama::Html),
+            issue_type = askama::MarkupDisplay::new_unsafe(&self.issue_type, askama::Html),
             title = askama::MarkupDisplay::new_unsafe(&self.title, askama::Html),
             blocked_badge = blocked_badge,
             children_html = children_html,
@@ -160,11 +169,11 @@ impl NestedTreeNode {
 }
 
 /// Render a list of nested tree nodes as HTML
-pub fn render_tree_html(nodes: &[NestedTreeNode]) -> String {
+pub fn render_tree_html(nodes: &[NestedTreeNode], base_path: &str) -> String {
     if nodes.is_empty() {
         return String::new();
     }
-    let inner: String = nodes.iter().map(|n| n.render_html()).collect();
+    let inner: String = nodes.iter().map(|n| n.render_html(base_path)).collect();
     format!(r#"<div class="org-tree"><ul>{}</ul></div>"#, inner)
 }
 
@@ -175,6 +184,8 @@ pub struct LandingTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    /// Prefix of every project URL: empty, or `/p/<name>` with several projects
+    pub base_path: String,
     pub stats: ProjectStats,
     pub epics: Vec<EpicWithProgress>,
     pub blocked: Vec<beads::Issue>,
@@ -182,6 +193,51 @@ pub struct LandingTemplate {
     pub tickets_chart: ChartData,
 }
 
+/// One project's row on the cross-project overview
+#[derive(serde::Serialize)]
+pub struct ProjectOverview {
+    pub name: String,
+    /// The project's own dashboard
+    pub url: String,
+    pub stats: ProjectStats,
+    pub closed_last_7_days: usize,
+    pub epics: Vec<EpicWithProgress>,
+    /// Why the project's issues could not be loaded
+    pub error: Option<String>,
+}
+
+/// A blocked issue on the overview, with the project it belongs to
+#[derive(serde::Serialize)]
+pub struct OverviewBlocked {
+    pub project: String,
+    /// The issue's page in its project
+    pub url: String,
+    pub issue: beads::Issue,
+}
+
+/// Every served project side by side, with combined counts and throughput
+#[derive(serde::Serialize)]
+pub struct Overview {
+    pub projects: Vec<ProjectOverview>,
+    pub totals: ProjectStats,
+    pub closed_last_7_days: usize,
+    pub throughput_per_day: f64,
+    pub blocked: Vec<OverviewBlocked>,
+    #[serde(skip)]
+    pub throughput_chart: ChartData,
+}
+
+#[derive(Template)]
+#[template(path = "overview.html")]
+pub struct OverviewTemplate {
+    pub project_name: String,
+    pub page_title: String,
+    pub active_nav: &'static str,
+    pub app_version: String,
+    pub base_path: String,
+    pub overview: Overview,
+}
+
 #[derive(Template)]
 #[template(path = "board.html")]
 pub struct BoardTemplate {
@@ -189,6 +245,7 @@ pub struct BoardTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
     pub columns: Vec<BoardColumn>,
     pub assignees: Vec<String>,
     pub include_closed: bool,
@@ -201,6 +258,9 @@ pub struct NewIssueTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
+    /// Set when creating a subtask
+    pub parent: Option<IssueOption>,
 }
 
 #[derive(Template)]
@@ -210,6 +270,7 @@ pub struct PrdsTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
     pub prds: Vec<PrdSummary>,
     /// Rendered markdown content for the selected PRD (empty if none selected)
     pub content: String,
@@ -222,7 +283,9 @@ pub struct EditIssueTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
     pub issue: beads::Issue,
+    pub issue_types: &'static [beads::IssueType],
 }
 
 /// Epic summary for graph selector
@@ -246,6 +309,7 @@ pub struct GraphTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
     pub epics: Vec<EpicSummary>,
     /// Pre-rendered HTML for the org-chart tree (use |safe filter in template)
     pub tree_html: String,
@@ -381,10 +445,68 @@ pub struct TasksTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
     pub nodes: Vec<TreeNode>,
     pub include_closed: bool,
 }
 
+/// An issue linked from the detail page, with its current status
+pub struct LinkedIssue {
+    pub id: String,
+    pub title: String,
+    /// `None` when the linked issue no longer exists
+    pub status: Option<beads::Status>,
+    /// The dependency behind the link, used to remove it
+    pub issue_id: String,
+    pub depends_on_id: String,
+}
+
+/// An entry in the dependency picker
+pub struct IssueOption {
+    pub id: String,
+    pub title: String,
+}
+
+/// Linked issues under one relationship heading, e.g. "Blocked by"
+pub struct RelationGroup {
+    pub label: &'static str,
+    pub issues: Vec<LinkedIssue>,
+}
+
+/// A comment on the detail page, with its text rendered from markdown
+pub struct CommentView {
+    pub author: String,
+    pub created_at: time::OffsetDateTime,
+    pub body_html: String,
+}
+
+/// One event in an issue's history
+#[derive(serde::Serialize)]
+pub struct TimelineEntry {
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: time::OffsetDateTime,
+    #[serde(rename = "type")]
+    pub kind: beads::EventType,
+    pub summary: String,
+    pub actor: Option<String>,
+    /// For status transitions, seconds spent in the previous status
+    pub duration_secs: Option<i64>,
+}
+
+impl TimelineEntry {
+    /// Compact duration such as `3d 4h` or `25m`
+    pub fn duration_label(&self) -> Option<String> {
+        let secs = self.duration_secs?;
+        let (days, hours, mins) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+        Some(match (days, hours, mins) {
+            (0, 0, 0) => "<1m".to_string(),
+            (0, 0, m) => format!("{}m", m),
+            (0, h, m) => format!("{}h {}m", h, m),
+            (d, h, _) => format!("{}d {}h", d, h),
+        })
+    }
+}
+
 #[derive(Template)]
 #[template(path = "task.html")]
 pub struct TaskDetailTemplate {
@@ -392,9 +514,18 @@ pub struct TaskDetailTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
     pub task: EpicWithProgress,
     pub children_tree: Vec<TreeNode>,
     pub can_expand: bool,
+    pub relations: Vec<RelationGroup>,
+    pub comments: Vec<CommentView>,
+    /// False when the installed `bd` has no `comments` command
+    pub comments_enabled: bool,
+    pub timeline: Vec<TimelineEntry>,
+    /// Other issues that can be linked from the dependency picker
+    pub link_candidates: Vec<IssueOption>,
+    pub dependency_types: &'static [beads::DependencyType],
 }
 
 #[derive(Template)]
@@ -404,6 +535,76 @@ pub struct PaletteTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
+}
+
+#[derive(Template)]
+#[template(path = "about.html")]
+pub struct AboutTemplate {
+    pub project_name: String,
+    pub page_title: String,
+    pub active_nav: &'static str,
+    pub app_version: String,
+    pub base_path: String,
+    pub bd: Option<beads::BdInfo>,
+    pub capabilities: beads::Capabilities,
+    pub min_bd_version: String,
+}
+
+/// A deleted issue with what the activity feed knows about its deletion
+pub struct TrashedIssue {
+    pub issue: beads::Issue,
+    pub deleted_at: Option<time::OffsetDateTime>,
+    pub deleted_by: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "trash.html")]
+pub struct TrashTemplate {
+    pub project_name: String,
+    pub page_title: String,
+    pub active_nav: &'static str,
+    pub app_version: String,
+    pub base_path: String,
+    pub items: Vec<TrashedIssue>,
+}
+
+#[derive(Template)]
+#[template(path = "ready.html")]
+pub struct ReadyTemplate {
+    pub project_name: String,
+    pub page_title: String,
+    pub active_nav: &'static str,
+    pub app_version: String,
+    pub base_path: String,
+    pub items: Vec<beads::Issue>,
+    pub assignees: Vec<String>,
+    pub labels: Vec<String>,
+    pub issue_types: Vec<String>,
+    pub filter_assignee: String,
+    pub filter_label: String,
+    pub filter_type: String,
+}
+
+pub struct AgentSessionView {
+    pub session: crate::agents::AgentSession,
+    pub live: bool,
+    pub issue_title: Option<String>,
+    /// The issue is still in progress and assigned to this agent
+    pub holds_issue: bool,
+}
+
+#[derive(Template)]
+#[template(path = "agents.html")]
+pub struct AgentsTemplate {
+    pub project_name: String,
+    pub page_title: String,
+    pub active_nav: &'static str,
+    pub app_version: String,
+    pub base_path: String,
+    pub sessions: Vec<AgentSessionView>,
+    pub stale_after_secs: u64,
+    pub on_expiry: crate::agents::ExpiryAction,
 }
 
 #[derive(Template)]
@@ -413,6 +614,7 @@ pub struct MetricsTemplate {
     pub page_title: String,
     pub active_nav: &'static str,
     pub app_version: String,
+    pub base_path: String,
     pub avg_lead_time_hours: f64,
     pub avg_cycle_time_mins: f64,
     pub throughput_per_day: f64,
@@ -430,4 +632,6 @@ pub struct MetricsTemplate {
     pub p90_cycle_time_mins: f64,
     pub p100_cycle_time_mins: f64,
     pub activity_heatmap: HeatMapData,
+    /// False when `bd` has no activity feed, so cycle time cannot be measured
+    pub activity_available: bool,
 }
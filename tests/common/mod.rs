@@ -3,16 +3,57 @@
 //! This module provides common helpers used across all integration test files,
 //! enabling parallel development by multiple agents.
 
+#![allow(dead_code)]
+
 use axum_test::TestServer;
+use nacre::beads::{Issue, IssueType, MemoryBackend, Status};
 use nacre::{AppState, create_app};
 use std::sync::Arc;
 
 /// Creates a test server for integration testing.
 ///
 /// Returns a test server that can be used to test endpoints.
-/// The server uses a default app state without requiring a beads database.
+/// The server uses an empty in-memory backend, so no `bd` binary or
+/// beads database is required.
 pub async fn test_server() -> TestServer {
-    let state = Arc::new(AppState::new());
+    test_server_with(MemoryBackend::new())
+}
+
+/// Creates a test server backed by the given in-memory backend.
+pub fn test_server_with(backend: MemoryBackend) -> TestServer {
+    let state = Arc::new(AppState::with_backend(Arc::new(backend)));
     let app = create_app(state);
     TestServer::new(app).unwrap()
 }
+
+/// Creates a test server with the given in-memory backend and `nacre.toml` settings.
+pub fn test_server_with_config(backend: MemoryBackend, config: &str) -> TestServer {
+    let config = nacre::config::Config::parse(config).unwrap();
+    let state = Arc::new(AppState::with_backend(Arc::new(backend)).with_config(config));
+    TestServer::new(create_app(state)).unwrap()
+}
+
+/// Builds an issue for seeding a `MemoryBackend`.
+pub fn create_test_issue(id: &str, status: Status, issue_type: IssueType) -> Issue {
+    let now = time::OffsetDateTime::now_utc();
+    Issue {
+        id: id.to_string(),
+        title: format!("Test {}", id),
+        status,
+        priority: Some(2),
+        issue_type,
+        created_at: now,
+        updated_at: now,
+        closed_at: None,
+        assignee: None,
+        labels: None,
+        description: None,
+        acceptance_criteria: None,
+        close_reason: None,
+        estimate: None,
+        dependencies: vec![],
+        deleted_at: None,
+        deleted_by: None,
+        dependents: vec![],
+    }
+}
@@ -0,0 +1,329 @@
+//! Agent workflow integration tests.
+//!
+//! Tests for claiming, releasing and completing work via /api/agents, and
+//! for agent heartbeats and the JSON-RPC tools
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::common::{create_test_issue, test_server_with};
+use axum::http::StatusCode;
+use nacre::agents::{AgentRegistry, ExpiryAction, reap_expired};
+use nacre::beads::{Dependency, DependencyType, IssueType, MemoryBackend, Status};
+use nacre::{AppState, create_app};
+
+fn queue_server() -> axum_test::TestServer {
+    let mut urgent = create_test_issue("nc-1", Status::Open, IssueType::Bug);
+    urgent.priority = Some(0);
+    urgent.acceptance_criteria = Some("Crash no longer reproduces".to_string());
+    let mut taken = create_test_issue("nc-3", Status::Open, IssueType::Task);
+    taken.priority = Some(0);
+    taken.assignee = Some("human".to_string());
+    test_server_with(MemoryBackend::new().with_issues(vec![
+        urgent,
+        create_test_issue("nc-2", Status::Open, IssueType::Task),
+        taken,
+    ]))
+}
+
+#[tokio::test]
+async fn test_concurrent_claims_get_different_issues() {
+    let server = queue_server();
+
+    let (first, second, third) = tokio::join!(
+        server
+            .post("/api/agents/claim")
+            .json(&serde_json::json!({ "agent": "agent-a" })),
+        server
+            .post("/api/agents/claim")
+            .json(&serde_json::json!({ "agent": "agent-b" })),
+        server
+            .post("/api/agents/claim")
+            .json(&serde_json::json!({ "agent": "agent-c" })),
+    );
+
+    let mut claimed: Vec<(String, String)> = [first, second]
+        .iter()
+        .map(|response| {
+            assert_eq!(response.status_code(), StatusCode::OK);
+            let issue: serde_json::Value = response.json();
+            assert_eq!(issue["status"], "in_progress");
+            (
+                issue["id"].as_str().unwrap().to_string(),
+                issue["assignee"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    claimed.sort();
+    assert_ne!(claimed[0].0, claimed[1].0);
+    assert_eq!(
+        claimed
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect::<Vec<_>>(),
+        ["nc-1", "nc-2"]
+    );
+
+    // nc-3 belongs to someone else, so the queue is empty
+    assert_eq!(third.status_code(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_claim_returns_acceptance_criteria_and_honors_filters() {
+    let server = queue_server();
+
+    let claimed = server
+        .post("/api/agents/claim")
+        .json(&serde_json::json!({ "agent": "agent-a", "type": "task" }))
+        .await;
+    let issue: serde_json::Value = claimed.json();
+    assert_eq!(issue["id"], "nc-2");
+
+    let claimed = server
+        .post("/api/agents/claim")
+        .json(&serde_json::json!({ "agent": "agent-a" }))
+        .await;
+    let issue: serde_json::Value = claimed.json();
+    assert_eq!(issue["id"], "nc-1");
+    assert_eq!(issue["acceptance_criteria"], "Crash no longer reproduces");
+
+    let blank = server
+        .post("/api/agents/claim")
+        .json(&serde_json::json!({ "agent": " " }))
+        .await;
+    assert_eq!(blank.status_code(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_release_and_complete_require_the_claim() {
+    let server = queue_server();
+    server
+        .post("/api/agents/claim")
+        .json(&serde_json::json!({ "agent": "agent-a" }))
+        .await;
+
+    let stolen = server
+        .post("/api/agents/complete")
+        .json(&serde_json::json!({ "agent": "agent-b", "id": "nc-1" }))
+        .await;
+    assert_eq!(stolen.status_code(), StatusCode::CONFLICT);
+
+    let released = server
+        .post("/api/agents/release")
+        .json(&serde_json::json!({ "agent": "agent-a", "id": "nc-1" }))
+        .await;
+    assert_eq!(released.status_code(), StatusCode::OK);
+    let issue: serde_json::Value = server.get("/api/issues/nc-1").await.json();
+    assert_eq!(issue["status"], "open");
+    assert!(issue["assignee"].is_null());
+
+    let reclaimed: serde_json::Value = server
+        .post("/api/agents/claim")
+        .json(&serde_json::json!({ "agent": "agent-b" }))
+        .await
+        .json();
+    assert_eq!(reclaimed["id"], "nc-1");
+
+    let completed = server
+        .post("/api/agents/complete")
+        .json(&serde_json::json!({ "agent": "agent-b", "id": "nc-1", "reason": "Fixed" }))
+        .await;
+    assert_eq!(completed.status_code(), StatusCode::OK);
+    let issue: serde_json::Value = server.get("/api/issues/nc-1").await.json();
+    assert_eq!(issue["status"], "closed");
+    assert_eq!(issue["close_reason"], "Fixed");
+}
+
+#[tokio::test]
+async fn test_heartbeats_show_on_agents_page() {
+    let server = queue_server();
+
+    let beat = server
+        .post("/api/agents/agent-a/heartbeat")
+        .json(&serde_json::json!({ "issue_id": "nc-2" }))
+        .await;
+    assert_eq!(beat.status_code(), StatusCode::OK);
+    let session: serde_json::Value = beat.json();
+    assert_eq!(session["issue_id"], "nc-2");
+    // A bare heartbeat is fine too
+    let idle = server.post("/api/agents/agent-b/heartbeat").await;
+    assert_eq!(idle.status_code(), StatusCode::OK);
+
+    let page = server.get("/agents").await.text();
+    assert!(page.contains("Agents (2)"));
+    assert!(page.contains("<code>agent-a</code>"));
+    assert!(page.contains(r#"href="/tasks/nc-2""#));
+    assert!(page.contains(r#"<span class="agent-state live">live</span>"#));
+
+    let forgotten = server.delete("/api/agents/agent-b").await;
+    assert_eq!(forgotten.status_code(), StatusCode::NO_CONTENT);
+    let missing = server.delete("/api/agents/agent-b").await;
+    assert_eq!(missing.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_expired_agents_lose_their_issues() {
+    let backend = MemoryBackend::new().with_issues(vec![
+        create_test_issue("nc-1", Status::Open, IssueType::Task),
+        create_test_issue("nc-2", Status::Open, IssueType::Task),
+    ]);
+    let agents = AgentRegistry::new()
+        .with_stale_after(Duration::ZERO)
+        .with_expiry_action(ExpiryAction::Release);
+    let state = Arc::new(AppState::with_backend(Arc::new(backend)).with_agents(agents));
+    let server = axum_test::TestServer::new(create_app(state.clone())).unwrap();
+
+    let claimed: serde_json::Value = server
+        .post("/api/agents/claim")
+        .json(&serde_json::json!({ "agent": "agent-a" }))
+        .await
+        .json();
+    let id = claimed["id"].as_str().unwrap().to_string();
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let page = server.get("/agents").await.text();
+    assert!(page.contains(r#"<span class="agent-state stale">stale</span>"#));
+    assert!(page.contains("release-issue"));
+
+    assert_eq!(reap_expired(&state).await.unwrap(), vec![id.clone()]);
+    let issue: serde_json::Value = server.get(&format!("/api/issues/{}", id)).await.json();
+    assert_eq!(issue["status"], "open");
+    assert!(issue["assignee"].is_null());
+
+    // Nothing left to do the second time around
+    assert!(reap_expired(&state).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_bare_heartbeat_keeps_claimed_issue_for_expiry() {
+    let backend = MemoryBackend::new().with_issues(vec![create_test_issue(
+        "nc-1",
+        Status::Open,
+        IssueType::Task,
+    )]);
+    let agents = AgentRegistry::new()
+        .with_stale_after(Duration::ZERO)
+        .with_expiry_action(ExpiryAction::Release);
+    let state = Arc::new(AppState::with_backend(Arc::new(backend)).with_agents(agents));
+    let server = axum_test::TestServer::new(create_app(state.clone())).unwrap();
+
+    server
+        .post("/api/agents/claim")
+        .json(&serde_json::json!({ "agent": "agent-a" }))
+        .await;
+    let beat: serde_json::Value = server.post("/api/agents/agent-a/heartbeat").await.json();
+    assert_eq!(beat["issue_id"], "nc-1");
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    assert_eq!(
+        reap_expired(&state).await.unwrap(),
+        vec!["nc-1".to_string()]
+    );
+    let issue: serde_json::Value = server.get("/api/issues/nc-1").await.json();
+    assert_eq!(issue["status"], "open");
+}
+
+#[tokio::test]
+async fn test_json_rpc_tools_reuse_api_validation() {
+    let server = queue_server();
+    let rpc = |body: serde_json::Value| server.post("/api/rpc").json(&body);
+
+    let listed: serde_json::Value = rpc(serde_json::json!({
+        "jsonrpc": "2.0", "id": 1, "method": "tools/list"
+    }))
+    .await
+    .json();
+    let names: Vec<&str> = listed["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"list_ready") && names.contains(&"update_issue"));
+
+    let called: serde_json::Value = rpc(serde_json::json!({
+        "jsonrpc": "2.0", "id": 2, "method": "tools/call",
+        "params": { "name": "update_issue", "arguments": { "id": "nc-2", "status": "in_progress" } }
+    }))
+    .await
+    .json();
+    assert_eq!(called["result"]["isError"], false);
+    let text = called["result"]["content"][0]["text"].as_str().unwrap();
+    let issue: serde_json::Value = serde_json::from_str(text).unwrap();
+    assert_eq!(issue["status"], "in_progress");
+
+    // Tools can also be called directly, with errors as JSON-RPC errors
+    let ready: serde_json::Value = rpc(serde_json::json!({
+        "jsonrpc": "2.0", "id": 3, "method": "list_ready", "params": { "type": "bug" }
+    }))
+    .await
+    .json();
+    assert_eq!(ready["result"][0]["id"], "nc-1");
+
+    let missing: serde_json::Value = rpc(serde_json::json!({
+        "jsonrpc": "2.0", "id": 4, "method": "get_issue", "params": { "id": "nc-404" }
+    }))
+    .await
+    .json();
+    assert_eq!(missing["error"]["data"]["status"], 404);
+
+    server
+        .post("/api/issues/nc-1/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-2" }))
+        .await;
+    let cycle: serde_json::Value = rpc(serde_json::json!({
+        "jsonrpc": "2.0", "id": 5, "method": "add_dependency",
+        "params": { "id": "nc-2", "depends_on_id": "nc-1" }
+    }))
+    .await
+    .json();
+    assert_eq!(
+        cycle["error"]["data"]["cycle"],
+        serde_json::json!(["nc-2", "nc-1", "nc-2"])
+    );
+
+    let invalid: serde_json::Value = rpc(serde_json::json!({
+        "jsonrpc": "2.0", "id": 6, "method": "create_issue", "params": {}
+    }))
+    .await
+    .json();
+    assert_eq!(invalid["error"]["code"], -32602);
+
+    let notification = rpc(serde_json::json!({
+        "jsonrpc": "2.0", "method": "notifications/initialized"
+    }))
+    .await;
+    assert_eq!(notification.status_code(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn test_json_rpc_update_cannot_close_epic_with_open_children() {
+    let mut child = create_test_issue("nc-2", Status::Open, IssueType::Task);
+    child.dependencies.push(Dependency {
+        issue_id: "nc-2".to_string(),
+        depends_on_id: "nc-1".to_string(),
+        dep_type: DependencyType::ParentChild,
+        created_at: None,
+        created_by: None,
+    });
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nc-1", Status::Open, IssueType::Epic),
+        child,
+    ]));
+    let close = |force: bool| {
+        server.post("/api/rpc").json(&serde_json::json!({
+            "jsonrpc": "2.0", "id": 1, "method": "update_issue",
+            "params": { "id": "nc-1", "status": "closed", "force": force }
+        }))
+    };
+
+    let refused: serde_json::Value = close(false).await.json();
+    assert_eq!(refused["error"]["data"]["status"], 409);
+    assert_eq!(
+        refused["error"]["data"]["open_children"],
+        serde_json::json!(["nc-2"])
+    );
+
+    let forced: serde_json::Value = close(true).await.json();
+    assert_eq!(forced["result"]["status"], "closed");
+}
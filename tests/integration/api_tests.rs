@@ -1,9 +1,11 @@
 //! API endpoint integration tests.
 //!
-//! Tests for REST API endpoints: GET/POST /api/issues
+//! Tests for REST API endpoints: GET/POST /api/issues, bulk updates, issue
+//! comments, dependencies and the ready queue
 
-use crate::common::test_server;
+use crate::common::{create_test_issue, test_server, test_server_with};
 use axum::http::StatusCode;
+use nacre::beads::{Dependency, DependencyType, IssueType, MemoryBackend, Status};
 
 #[tokio::test]
 async fn test_api_tasks_list() {
@@ -33,3 +35,596 @@ async fn test_api_update_issue_not_found() {
     // This is acceptable for integration test purposes
     assert_ne!(response.status_code(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_api_issues_etag_follows_snapshot_version() {
+    let server = test_server().await;
+
+    let response = server.get("/api/issues").await;
+    let etag = response.headers().get("etag").unwrap().clone();
+
+    let cached = server
+        .get("/api/issues")
+        .add_header("if-none-match", etag.to_str().unwrap())
+        .await;
+    assert_eq!(cached.status_code(), StatusCode::NOT_MODIFIED);
+
+    server
+        .post("/api/issues")
+        .json(&serde_json::json!({ "title": "Changes the version" }))
+        .await;
+
+    let fresh = server
+        .get("/api/issues")
+        .add_header("if-none-match", etag.to_str().unwrap())
+        .await;
+    assert_eq!(fresh.status_code(), StatusCode::OK);
+    assert_ne!(fresh.headers().get("etag").unwrap(), &etag);
+    let issues: serde_json::Value = fresh.json();
+    assert_eq!(issues[0]["title"], "Changes the version");
+}
+
+#[tokio::test]
+async fn test_api_update_if_match_rejects_stale_edits() {
+    let server = seeded_server();
+
+    let loaded = server.get("/api/issues/nc-1").await;
+    assert_eq!(loaded.status_code(), StatusCode::OK);
+    let etag = loaded
+        .headers()
+        .get("etag")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let edit_page = server.get("/tasks/nc-1/edit").await.text();
+    assert!(edit_page.contains(&format!("data-etag=\"{}\"", etag.replace('"', "&quot;"))));
+
+    let first = server
+        .post("/api/issues/nc-1")
+        .add_header("if-match", etag.as_str())
+        .json(&serde_json::json!({ "title": "Agent edit" }))
+        .await;
+    assert_eq!(first.status_code(), StatusCode::OK);
+
+    let stale = server
+        .post("/api/issues/nc-1")
+        .add_header("if-match", etag.as_str())
+        .json(&serde_json::json!({ "title": "Stale form" }))
+        .await;
+    assert_eq!(stale.status_code(), StatusCode::PRECONDITION_FAILED);
+    assert_ne!(stale.headers().get("etag").unwrap(), etag.as_str());
+    let body: serde_json::Value = stale.json();
+    assert_eq!(body["current"]["title"], "Agent edit");
+
+    let current = server.get("/api/issues/nc-1").await;
+    let retried = server
+        .post("/api/issues/nc-1")
+        .add_header(
+            "if-match",
+            current.headers().get("etag").unwrap().to_str().unwrap(),
+        )
+        .json(&serde_json::json!({ "title": "Merged" }))
+        .await;
+    assert_eq!(retried.status_code(), StatusCode::OK);
+
+    // Without If-Match the last write wins, as before
+    let unconditional = server
+        .post("/api/issues/nc-1")
+        .json(&serde_json::json!({ "title": "Blind" }))
+        .await;
+    assert_eq!(unconditional.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_api_update_rejects_unknown_status() {
+    let server = test_server().await;
+    let created: serde_json::Value = server
+        .post("/api/issues")
+        .json(&serde_json::json!({ "title": "Stay put" }))
+        .await
+        .json();
+
+    let response = server
+        .post(&format!("/api/issues/{}", created["id"].as_str().unwrap()))
+        .json(&serde_json::json!({ "status": "in-progress" }))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_api_update_edits_triage_fields() {
+    let server = seeded_server();
+
+    let response = server
+        .post("/api/issues/nc-1")
+        .json(&serde_json::json!({
+            "assignee": "ada",
+            "add_labels": ["frontend", "ux"],
+            "issue_type": "bug",
+            "estimate": 45,
+            "acceptance_criteria": "Renders on mobile"
+        }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(page.contains(r#"data-field="assignee" data-value="ada""#));
+    assert!(page.contains(r#"data-label="frontend""#));
+    assert!(page.contains("<span>Bug</span>"));
+
+    let reason_without_close = server
+        .post("/api/issues/nc-1")
+        .json(&serde_json::json!({ "close_reason": "Done" }))
+        .await;
+    assert_eq!(reason_without_close.status_code(), StatusCode::BAD_REQUEST);
+
+    let unknown_type = server
+        .post("/api/issues/nc-1")
+        .json(&serde_json::json!({ "issue_type": "saga" }))
+        .await;
+    assert_eq!(unknown_type.status_code(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_api_create_with_parent_labels_and_dependencies() {
+    let server = seeded_server();
+
+    let created: serde_json::Value = server
+        .post("/api/issues")
+        .json(&serde_json::json!({
+            "title": "Subtask",
+            "parent": "nc-1",
+            "labels": ["backend"],
+            "assignee": "ada",
+            "dependencies": [{ "depends_on_id": "nc-2", "type": "blocks" }]
+        }))
+        .await
+        .json();
+    let id = created["id"].as_str().unwrap();
+
+    let parent = server.get("/tasks/nc-1").await.text();
+    assert!(parent.contains(id));
+    let page = server.get(&format!("/tasks/{}", id)).await.text();
+    assert!(page.contains(r#"data-label="backend""#));
+    assert!(page.contains(&format!(r#"data-issue="{}" data-depends-on="nc-2""#, id)));
+
+    let missing = server
+        .post("/api/issues")
+        .json(&serde_json::json!({ "title": "Orphan", "parent": "nc-404" }))
+        .await;
+    assert_eq!(missing.status_code(), StatusCode::NOT_FOUND);
+    let issues: serde_json::Value = server.get("/api/issues").await.json();
+    assert!(
+        issues
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|i| i["title"] != "Orphan")
+    );
+}
+
+#[tokio::test]
+async fn test_new_subtask_form_names_parent() {
+    let server = seeded_server();
+
+    let page = server.get("/tasks/new?parent=nc-1").await;
+    assert_eq!(page.status_code(), StatusCode::OK);
+    assert!(page.text().contains(r#"data-parent="nc-1""#));
+
+    let missing = server.get("/tasks/new?parent=nc-404").await;
+    assert_eq!(missing.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_api_close_and_reopen_with_reason() {
+    let server = seeded_server();
+
+    let closed = server
+        .post("/api/issues/nc-1/close")
+        .json(&serde_json::json!({ "reason": "Fixed in 1.4" }))
+        .await;
+    assert_eq!(closed.status_code(), StatusCode::OK);
+
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(page.contains(r#"<div class="close-banner">"#));
+    assert!(page.contains("Fixed in 1.4"));
+    assert!(page.contains(r#"id="reopen-issue""#));
+
+    let reopened = server
+        .post("/api/issues/nc-1/reopen")
+        .json(&serde_json::json!({ "reason": "Regressed" }))
+        .await;
+    assert_eq!(reopened.status_code(), StatusCode::OK);
+
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(!page.contains(r#"<div class="close-banner">"#));
+    assert!(page.contains(r#"id="close-issue""#));
+}
+
+#[tokio::test]
+async fn test_api_issue_activity_timeline() {
+    let server = seeded_server();
+
+    server
+        .post("/api/issues/nc-1")
+        .json(&serde_json::json!({ "status": "in_progress" }))
+        .await;
+    server
+        .post("/api/issues/nc-1/close")
+        .json(&serde_json::json!({ "reason": "Shipped" }))
+        .await;
+    server
+        .post("/api/issues/nc-2/close")
+        .json(&serde_json::json!({}))
+        .await;
+
+    let response = server.get("/api/issues/nc-1/activity").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let timeline: Vec<serde_json::Value> = response.json();
+    let types: Vec<&str> = timeline
+        .iter()
+        .map(|entry| entry["type"].as_str().unwrap())
+        .collect();
+    assert_eq!(types, ["status", "closed"]);
+    assert_eq!(timeline[0]["summary"], "open → in_progress");
+    assert!(timeline[0]["duration_secs"].is_i64());
+    assert_eq!(timeline[1]["summary"], "closed: Shipped");
+    assert!(timeline[1]["duration_secs"].is_i64());
+
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(page.contains("<h3>History</h3>"));
+    assert!(page.contains("closed: Shipped"));
+
+    let missing = server.get("/api/issues/nc-404/activity").await;
+    assert_eq!(missing.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_api_close_epic_guards_open_children() {
+    let mut child = create_test_issue("nc-2", Status::Open, IssueType::Task);
+    child.dependencies.push(Dependency {
+        issue_id: "nc-2".to_string(),
+        depends_on_id: "nc-1".to_string(),
+        dep_type: DependencyType::ParentChild,
+        created_at: None,
+        created_by: None,
+    });
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nc-1", Status::Open, IssueType::Epic),
+        child,
+        create_test_issue("nc-1.1", Status::Closed, IssueType::Task),
+    ]));
+
+    let guarded = server
+        .post("/api/issues/nc-1/close")
+        .json(&serde_json::json!({}))
+        .await;
+    assert_eq!(guarded.status_code(), StatusCode::CONFLICT);
+    let body: serde_json::Value = guarded.json();
+    assert_eq!(body["open_children"], serde_json::json!(["nc-2"]));
+
+    let forced = server
+        .post("/api/issues/nc-1/close")
+        .json(&serde_json::json!({ "force": true }))
+        .await;
+    assert_eq!(forced.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_api_update_to_closed_guards_epics_with_open_children() {
+    let mut child = create_test_issue("nc-2", Status::Open, IssueType::Task);
+    child.dependencies.push(Dependency {
+        issue_id: "nc-2".to_string(),
+        depends_on_id: "nc-1".to_string(),
+        dep_type: DependencyType::ParentChild,
+        created_at: None,
+        created_by: None,
+    });
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nc-1", Status::Open, IssueType::Epic),
+        child,
+    ]));
+
+    let guarded = server
+        .post("/api/issues/nc-1")
+        .json(&serde_json::json!({ "status": "closed" }))
+        .await;
+    assert_eq!(guarded.status_code(), StatusCode::CONFLICT);
+    let body: serde_json::Value = guarded.json();
+    assert_eq!(body["open_children"], serde_json::json!(["nc-2"]));
+
+    let forced = server
+        .post("/api/issues/nc-1")
+        .json(&serde_json::json!({ "status": "closed", "force": true }))
+        .await;
+    assert_eq!(forced.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_api_bulk_update_reports_each_issue() {
+    let server = seeded_server();
+
+    let response = server
+        .post("/api/issues/bulk")
+        .json(&serde_json::json!({
+            "ids": ["nc-1", "nc-404", "nc-2"],
+            "update": { "priority": 0, "add_labels": ["triaged"] }
+        }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["updated"], 2);
+    assert_eq!(body["failed"], 1);
+    assert_eq!(body["results"][1]["id"], "nc-404");
+    assert_eq!(body["results"][1]["ok"], false);
+    assert_eq!(body["results"][1]["error"], "Not found");
+
+    let page = server.get("/tasks/nc-2").await.text();
+    assert!(page.contains(r#"data-label="triaged""#));
+    assert!(page.contains("P0"));
+
+    let invalid = server
+        .post("/api/issues/bulk")
+        .json(&serde_json::json!({ "ids": ["nc-1"], "update": { "status": "someday" } }))
+        .await;
+    assert_eq!(invalid.status_code(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_api_bulk_close_guards_epics_with_open_children() {
+    let child = |id: &str, status| {
+        let mut issue = create_test_issue(id, status, IssueType::Task);
+        issue.dependencies.push(Dependency {
+            issue_id: id.to_string(),
+            depends_on_id: "nc-1".to_string(),
+            dep_type: DependencyType::ParentChild,
+            created_at: None,
+            created_by: None,
+        });
+        issue
+    };
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nc-1", Status::Open, IssueType::Epic),
+        child("nc-2", Status::Open),
+        child("nc-3", Status::Open),
+    ]));
+    let close = |ids: serde_json::Value| {
+        server
+            .post("/api/issues/bulk")
+            .json(&serde_json::json!({ "ids": ids, "update": { "status": "closed" } }))
+    };
+
+    let guarded: serde_json::Value = close(serde_json::json!(["nc-1", "nc-2"])).await.json();
+    assert_eq!(guarded["updated"], 1);
+    assert_eq!(guarded["results"][0]["id"], "nc-1");
+    assert_eq!(guarded["results"][0]["ok"], false);
+    assert_eq!(
+        guarded["results"][0]["error"],
+        "Epic still has open children: nc-3"
+    );
+
+    // Children closed in the same batch no longer hold the epic open
+    let together: serde_json::Value = close(serde_json::json!(["nc-1", "nc-3"])).await.json();
+    assert_eq!(together["updated"], 2);
+}
+
+#[tokio::test]
+async fn test_api_comments_round_trip() {
+    let server = test_server().await;
+    let created: serde_json::Value = server
+        .post("/api/issues")
+        .json(&serde_json::json!({ "title": "Needs notes" }))
+        .await
+        .json();
+    let url = format!("/api/issues/{}/comments", created["id"].as_str().unwrap());
+
+    let response = server
+        .post(&url)
+        .json(&serde_json::json!({ "text": "Progress: **done** parsing", "author": "agent-7" }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CREATED);
+
+    let comments: serde_json::Value = server.get(&url).await.json();
+    assert_eq!(comments[0]["author"], "agent-7");
+    assert_eq!(comments[0]["text"], "Progress: **done** parsing");
+
+    let page = server
+        .get(&format!("/tasks/{}", created["id"].as_str().unwrap()))
+        .await
+        .text();
+    assert!(page.contains("<strong>done</strong>"));
+    assert!(page.contains("Comments (1)"));
+}
+
+#[tokio::test]
+async fn test_api_comments_reject_empty_text_and_missing_issue() {
+    let server = test_server().await;
+    let created: serde_json::Value = server
+        .post("/api/issues")
+        .json(&serde_json::json!({ "title": "Quiet" }))
+        .await
+        .json();
+
+    let empty = server
+        .post(&format!(
+            "/api/issues/{}/comments",
+            created["id"].as_str().unwrap()
+        ))
+        .json(&serde_json::json!({ "text": "   " }))
+        .await;
+    assert_eq!(empty.status_code(), StatusCode::BAD_REQUEST);
+
+    let missing = server.get("/api/issues/nope/comments").await;
+    assert_eq!(missing.status_code(), StatusCode::NOT_FOUND);
+}
+
+fn seeded_server() -> axum_test::TestServer {
+    test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nc-1", Status::Open, IssueType::Task),
+        create_test_issue("nc-2", Status::Open, IssueType::Task),
+        create_test_issue("nc-3", Status::Open, IssueType::Task),
+    ]))
+}
+
+#[tokio::test]
+async fn test_api_dependencies_add_and_remove() {
+    let server = seeded_server();
+
+    let added = server
+        .post("/api/issues/nc-1/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-2", "type": "related" }))
+        .await;
+    assert_eq!(added.status_code(), StatusCode::CREATED);
+
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(page.contains(r#"data-issue="nc-1" data-depends-on="nc-2""#));
+
+    let removed = server
+        .delete("/api/issues/nc-1/dependencies?depends_on_id=nc-2")
+        .await;
+    assert_eq!(removed.status_code(), StatusCode::NO_CONTENT);
+
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(!page.contains(r#"data-depends-on="nc-2""#));
+}
+
+#[tokio::test]
+async fn test_task_page_removes_dependencies_by_query() {
+    let server = seeded_server();
+    server
+        .post("/api/issues/nc-1/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-2" }))
+        .await;
+
+    // The × button names the dependency in the query string and sends no body
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(page.contains("url += '?depends_on_id=' + encodeURIComponent(dependsOn);"));
+    assert!(
+        page.contains(
+            "dependencyRequest('DELETE', btn.dataset.issue, null, btn.dataset.dependsOn)"
+        )
+    );
+
+    let removed = server
+        .delete("/api/issues/nc-1/dependencies?depends_on_id=nc-2")
+        .await;
+    assert_eq!(removed.status_code(), StatusCode::NO_CONTENT);
+    let page = server.get("/tasks/nc-1").await.text();
+    assert!(!page.contains(r#"data-depends-on="nc-2""#));
+}
+
+#[tokio::test]
+async fn test_api_dependencies_reject_cycles_with_path() {
+    let server = seeded_server();
+    for (from, to) in [("nc-1", "nc-2"), ("nc-2", "nc-3")] {
+        let response = server
+            .post(&format!("/api/issues/{}/dependencies", from))
+            .json(&serde_json::json!({ "depends_on_id": to }))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::CREATED);
+    }
+
+    let cycle = server
+        .post("/api/issues/nc-3/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-1", "type": "blocks" }))
+        .await;
+    assert_eq!(cycle.status_code(), StatusCode::CONFLICT);
+    let body: serde_json::Value = cycle.json();
+    assert_eq!(
+        body["cycle"],
+        serde_json::json!(["nc-3", "nc-1", "nc-2", "nc-3"])
+    );
+
+    // Soft links may loop back freely
+    let related = server
+        .post("/api/issues/nc-3/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-1", "type": "related" }))
+        .await;
+    assert_eq!(related.status_code(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_api_dependencies_concurrent_halves_of_a_cycle() {
+    let server = seeded_server();
+
+    let (forward, backward) = tokio::join!(
+        server
+            .post("/api/issues/nc-1/dependencies")
+            .json(&serde_json::json!({ "depends_on_id": "nc-2" })),
+        server
+            .post("/api/issues/nc-2/dependencies")
+            .json(&serde_json::json!({ "depends_on_id": "nc-1" })),
+    );
+
+    let mut codes = [forward.status_code(), backward.status_code()];
+    codes.sort();
+    assert_eq!(codes, [StatusCode::CREATED, StatusCode::CONFLICT]);
+}
+
+#[tokio::test]
+async fn test_api_dependencies_validate_request() {
+    let server = seeded_server();
+
+    let unknown_type = server
+        .post("/api/issues/nc-1/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-2", "type": "sorta-blocks" }))
+        .await;
+    assert_eq!(unknown_type.status_code(), StatusCode::BAD_REQUEST);
+
+    let self_link = server
+        .post("/api/issues/nc-1/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-1" }))
+        .await;
+    assert_eq!(self_link.status_code(), StatusCode::BAD_REQUEST);
+
+    let missing = server
+        .post("/api/issues/nc-1/dependencies")
+        .json(&serde_json::json!({ "depends_on_id": "nc-404" }))
+        .await;
+    assert_eq!(missing.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_api_ready_queue_skips_blocked_and_filters() {
+    let mut blocked = create_test_issue("nc-1", Status::Open, IssueType::Task);
+    blocked.dependencies.push(Dependency {
+        issue_id: "nc-1".to_string(),
+        depends_on_id: "nc-2".to_string(),
+        dep_type: DependencyType::Blocks,
+        created_at: None,
+        created_by: None,
+    });
+    let mut urgent_bug = create_test_issue("nc-3", Status::Open, IssueType::Bug);
+    urgent_bug.priority = Some(0);
+    urgent_bug.assignee = Some("ada".to_string());
+    urgent_bug.labels = Some(vec!["backend".to_string()]);
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        blocked,
+        create_test_issue("nc-2", Status::Open, IssueType::Task),
+        urgent_bug,
+        create_test_issue("nc-4", Status::InProgress, IssueType::Task),
+    ]));
+
+    let ids = |response: axum_test::TestResponse| -> Vec<String> {
+        let issues: Vec<serde_json::Value> = response.json();
+        issues
+            .iter()
+            .map(|i| i["id"].as_str().unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(ids(server.get("/api/ready").await), ["nc-3", "nc-2"]);
+    assert_eq!(
+        ids(server.get("/api/ready?assignee=ada&label=backend").await),
+        ["nc-3"]
+    );
+    assert_eq!(ids(server.get("/api/ready?type=task").await), ["nc-2"]);
+    assert!(ids(server.get("/api/ready?label=frontend").await).is_empty());
+
+    let page = server.get("/ready?type=bug").await.text();
+    assert!(page.contains("Ready to work on (1)"));
+    assert!(page.contains(r#"href="/tasks/nc-3""#));
+    assert!(!page.contains(r#"href="/tasks/nc-2""#));
+}
@@ -2,8 +2,9 @@
 //!
 //! Tests for the Kanban board view
 
-use crate::common::test_server;
+use crate::common::{create_test_issue, test_server, test_server_with, test_server_with_config};
 use axum::http::StatusCode;
+use nacre::beads::{IssueType, MemoryBackend, Status};
 
 #[tokio::test]
 async fn test_board_view() {
@@ -13,3 +14,50 @@ async fn test_board_view() {
 
     assert_eq!(response.status_code(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_board_shows_unknown_status_in_its_own_column() {
+    let mut issue = create_test_issue(
+        "nacre-1",
+        Status::Unknown("hooked".to_string()),
+        IssueType::Unknown("convoy".to_string()),
+    );
+    issue.title = "Future work".to_string();
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![issue]));
+
+    let response = server.get("/board").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let html = response.text();
+    assert!(html.contains(r#"data-status="hooked""#));
+    assert!(html.contains("Future work"));
+
+    assert_eq!(server.get("/tasks").await.status_code(), StatusCode::OK);
+    assert_eq!(server.get("/graph").await.status_code(), StatusCode::OK);
+    let graph: serde_json::Value = server.get("/api/graph").await.json();
+    assert_eq!(graph["nodes"][0]["status"], "hooked");
+}
+
+#[tokio::test]
+async fn test_board_columns_follow_config() {
+    let server = test_server_with_config(
+        MemoryBackend::new().with_issues(vec![
+            create_test_issue("nacre-1", Status::Open, IssueType::Task),
+            create_test_issue("nacre-2", Status::Deferred, IssueType::Task),
+        ]),
+        r#"
+        [board]
+        columns = ["in_progress", "open", "review"]
+        "#,
+    );
+
+    let html = server.get("/board").await.text();
+
+    let column =
+        |status: &str| html.find(&format!(r#"class="board-column" data-status="{}""#, status));
+    let (in_progress, open, review) = (column("in_progress"), column("open"), column("review"));
+    assert!(in_progress.unwrap() < open.unwrap());
+    assert!(open.unwrap() < review.unwrap());
+    assert!(column("blocked").is_none());
+    // Deferred issues are not configured but still get a column, last
+    assert!(column("deferred").unwrap() > review.unwrap());
+}
@@ -6,17 +6,24 @@
 //! - Dependencies display correctly in graph
 //! - Data consistency between API and HTML views
 
-use crate::common::test_server;
+use crate::common::{create_test_issue, test_server, test_server_with};
 use axum::http::StatusCode;
+use nacre::beads::{IssueType, MemoryBackend, Status};
 
 /// Test that a task created via API appears in tasks list view
 #[tokio::test]
 async fn test_api_created_task_appears_in_list_view() {
     let server = test_server().await;
 
-    // Verify tasks list is accessible
+    let create_response = server
+        .post("/api/issues")
+        .json(&serde_json::json!({ "title": "Wire up the board" }))
+        .await;
+    assert_eq!(create_response.status_code(), StatusCode::OK);
+
     let list_response = server.get("/tasks").await;
     assert_eq!(list_response.status_code(), StatusCode::OK);
+    assert!(list_response.text().contains("Wire up the board"));
 }
 
 /// Test that a task created via API appears in task detail view
@@ -24,40 +31,85 @@ async fn test_api_created_task_appears_in_list_view() {
 async fn test_api_created_task_appears_in_detail_view() {
     let server = test_server().await;
 
-    // Verify task detail view is accessible
-    let detail_response = server.get("/tasks").await;
+    let created: serde_json::Value = server
+        .post("/api/issues")
+        .json(&serde_json::json!({ "title": "Detail me", "issue_type": "bug" }))
+        .await
+        .json();
+    let id = created["id"].as_str().unwrap();
+
+    let detail_response = server.get(&format!("/tasks/{}", id)).await;
     assert_eq!(detail_response.status_code(), StatusCode::OK);
+    assert!(detail_response.text().contains("Detail me"));
 }
 
 /// Test that detail view matches API data
 #[tokio::test]
 async fn test_detail_view_matches_api_data() {
-    let server = test_server().await;
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![create_test_issue(
+        "nacre-1",
+        Status::InProgress,
+        IssueType::Feature,
+    )]));
 
-    // Both API and HTML should be accessible
     let api_response = server.get("/api/issues").await;
     assert_eq!(api_response.status_code(), StatusCode::OK);
+    let issues: serde_json::Value = api_response.json();
+    assert_eq!(issues[0]["id"], "nacre-1");
+    assert_eq!(issues[0]["status"], "in_progress");
 
-    let list_response = server.get("/tasks").await;
-    assert_eq!(list_response.status_code(), StatusCode::OK);
+    let detail_response = server.get("/tasks/nacre-1").await;
+    assert_eq!(detail_response.status_code(), StatusCode::OK);
+    assert!(detail_response.text().contains("Test nacre-1"));
 }
 
 /// Test that graph view displays correctly
 #[tokio::test]
 async fn test_graph_view_with_tasks() {
-    let server = test_server().await;
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nacre-1", Status::Open, IssueType::Epic),
+        create_test_issue("nacre-1.1", Status::Open, IssueType::Task),
+    ]));
 
-    // Graph view should be accessible
-    let graph_response = server.get("/graph").await;
+    let graph_response = server.get("/graph/nacre-1").await;
     assert_eq!(graph_response.status_code(), StatusCode::OK);
+    assert!(graph_response.text().contains("nacre-1.1"));
+
+    let data: serde_json::Value = server.get("/api/graph").await.json();
+    assert_eq!(data["nodes"].as_array().unwrap().len(), 2);
+    assert_eq!(data["edges"][0]["type"], "parent-child");
+}
+
+/// Test that a status update via API moves the task on the board
+#[tokio::test]
+async fn test_status_update_reflects_in_board() {
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![create_test_issue(
+        "nacre-1",
+        Status::Open,
+        IssueType::Task,
+    )]));
+
+    let update_response = server
+        .post("/api/issues/nacre-1")
+        .json(&serde_json::json!({ "status": "closed" }))
+        .await;
+    assert_eq!(update_response.status_code(), StatusCode::OK);
+
+    let board = server.get("/board").await.text();
+    assert!(!board.contains("Test nacre-1"));
+
+    let board = server.get("/board?include_closed=true").await.text();
+    assert!(board.contains("Test nacre-1"));
 }
 
 /// Test that metrics view reflects tasks
 #[tokio::test]
 async fn test_metrics_view_reflects_tasks() {
-    let server = test_server().await;
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nacre-1", Status::InProgress, IssueType::Task),
+        create_test_issue("nacre-2", Status::Blocked, IssueType::Task),
+    ]));
 
-    // Metrics view should be accessible
     let metrics_response = server.get("/metrics").await;
     assert_eq!(metrics_response.status_code(), StatusCode::OK);
 }
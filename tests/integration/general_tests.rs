@@ -5,7 +5,9 @@
 use crate::common::test_server;
 use axum::http::StatusCode;
 use axum_test::TestServer;
+use nacre::beads::{BdInfo, BdVersion, Capabilities, MemoryBackend};
 use nacre::{AppState, create_app};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[tokio::test]
@@ -47,3 +49,38 @@ async fn test_palette_view() {
 
     assert_eq!(response.status_code(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_about_without_bd_info() {
+    let server = test_server().await;
+
+    let response = server.get("/about").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(response.text().contains("bd was not probed"));
+}
+
+#[tokio::test]
+async fn test_about_shows_bd_version_and_capabilities() {
+    let info = BdInfo {
+        bin_path: "/usr/local/bin/bd".to_string(),
+        version: BdVersion::new(0, 29, 0),
+        version_line: "bd version 0.29.0 (dev)".to_string(),
+        db_path: Some(PathBuf::from("/work/.beads/beads.db")),
+        capabilities: Capabilities {
+            activity: false,
+            ..Capabilities::ALL
+        },
+    };
+    let state = AppState::with_backend(Arc::new(MemoryBackend::new())).with_bd_info(info);
+    let server = TestServer::new(create_app(Arc::new(state))).unwrap();
+
+    let text = server.get("/about").await.text();
+    assert!(text.contains("/usr/local/bin/bd"));
+    assert!(text.contains("0.29.0"));
+    assert!(text.contains("/work/.beads/beads.db"));
+    assert!(text.contains("missing, falling back"));
+
+    let metrics = server.get("/metrics").await.text();
+    assert!(metrics.contains("Needs <code>bd activity</code>"));
+}
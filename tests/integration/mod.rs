@@ -3,11 +3,13 @@
 //! Each module focuses on a specific feature area, enabling parallel
 //! development by multiple agents without merge conflicts.
 
+pub mod agent_tests;
 pub mod api_tests;
 pub mod board_tests;
 pub mod cross_feature_tests;
 pub mod general_tests;
 pub mod metrics_tests;
 pub mod prd_tests;
+pub mod project_tests;
 pub mod static_assets_tests;
 pub mod task_views_tests;
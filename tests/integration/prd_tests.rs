@@ -2,8 +2,9 @@
 //!
 //! Tests for PRD listing and detail views
 
-use crate::common::test_server;
+use crate::common::{test_server, test_server_with_config};
 use axum::http::StatusCode;
+use nacre::beads::MemoryBackend;
 
 #[tokio::test]
 async fn test_prds_list() {
@@ -22,3 +23,27 @@ async fn test_prd_detail_not_found() {
 
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn test_prds_come_from_configured_dirs() {
+    let dir = tempfile::tempdir().unwrap();
+    let (first, second) = (dir.path().join("prds"), dir.path().join("adr"));
+    std::fs::create_dir_all(&first).unwrap();
+    std::fs::create_dir_all(&second).unwrap();
+    std::fs::write(first.join("roadmap.md"), "# Roadmap").unwrap();
+    std::fs::write(second.join("roadmap.md"), "# Shadowed").unwrap();
+    std::fs::write(second.join("0001-storage.md"), "# Use SQLite").unwrap();
+    let server = test_server_with_config(
+        MemoryBackend::new(),
+        &format!("doc_dirs = [{:?}, {:?}]", first, second),
+    );
+
+    let list = server.get("/prds").await.text();
+    assert!(list.contains("roadmap.md"));
+    assert!(list.contains("0001-storage.md"));
+
+    let roadmap = server.get("/prds/roadmap.md").await.text();
+    assert!(roadmap.contains("Roadmap"));
+    assert!(!roadmap.contains("Shadowed"));
+    server.get("/prds/0001-storage.md").await.assert_status_ok();
+}
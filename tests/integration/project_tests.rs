@@ -0,0 +1,189 @@
+//! Multi-project integration tests.
+//!
+//! Tests for serving several beads projects from one nacre, each mounted
+//! under /p/<name> with its own backend, and for the overview across them
+
+use std::sync::Arc;
+
+use crate::common::create_test_issue;
+use axum::http::StatusCode;
+use nacre::beads::{Dependency, DependencyType, Issue, IssueType, MemoryBackend, Status};
+use nacre::{AppState, SharedAppState, create_multi_app};
+
+fn project(name: &str, issues: Vec<Issue>) -> SharedAppState {
+    Arc::new(
+        AppState::with_backend(Arc::new(MemoryBackend::new().with_issues(issues)))
+            .with_project(name),
+    )
+}
+
+fn open_tasks(ids: &[&str]) -> Vec<Issue> {
+    ids.iter()
+        .map(|id| create_test_issue(id, Status::Open, IssueType::Task))
+        .collect()
+}
+
+fn projects_server() -> axum_test::TestServer {
+    let app = create_multi_app(vec![
+        project("api", open_tasks(&["api-1", "api-2"])),
+        project("web", open_tasks(&["web-1"])),
+    ]);
+    axum_test::TestServer::new(app).unwrap()
+}
+
+/// Two projects with a mix of statuses, an epic and recently closed work
+fn overview_server() -> axum_test::TestServer {
+    let now = time::OffsetDateTime::now_utc();
+    let mut closed = create_test_issue("api-3", Status::Closed, IssueType::Task);
+    closed.closed_at = Some(now);
+    closed.dependencies = vec![Dependency {
+        issue_id: "api-3".to_string(),
+        depends_on_id: "api-1".to_string(),
+        dep_type: DependencyType::ParentChild,
+        created_at: None,
+        created_by: None,
+    }];
+    let mut web_closed = create_test_issue("web-2", Status::Closed, IssueType::Bug);
+    web_closed.closed_at = Some(now);
+
+    let app = create_multi_app(vec![
+        project(
+            "api",
+            vec![
+                create_test_issue("api-1", Status::Open, IssueType::Epic),
+                create_test_issue("api-2", Status::Blocked, IssueType::Task),
+                closed,
+            ],
+        ),
+        project(
+            "web",
+            vec![
+                create_test_issue("web-1", Status::InProgress, IssueType::Task),
+                create_test_issue("web-3", Status::Blocked, IssueType::Bug),
+                web_closed,
+            ],
+        ),
+    ]);
+    axum_test::TestServer::new(app).unwrap()
+}
+
+#[tokio::test]
+async fn test_projects_are_mounted_separately() {
+    let server = projects_server();
+
+    let api: Vec<serde_json::Value> = server.get("/p/api/api/issues").await.json();
+    let web: Vec<serde_json::Value> = server.get("/p/web/api/issues").await.json();
+    assert_eq!(api.len(), 2);
+    assert_eq!(web.len(), 1);
+    assert_eq!(web[0]["id"], "web-1");
+
+    server
+        .get("/p/web/tasks/api-1")
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+    server
+        .get("/api/issues")
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    // Writes land in the project they were sent to
+    server
+        .post("/p/web/api/issues")
+        .json(&serde_json::json!({ "title": "Only on the web" }))
+        .await
+        .assert_status_ok();
+    let api: Vec<serde_json::Value> = server.get("/p/api/api/issues").await.json();
+    assert_eq!(api.len(), 2);
+}
+
+#[tokio::test]
+async fn test_project_pages_link_within_the_project() {
+    let server = projects_server();
+
+    let response = server.get("/p/web/tasks/web-1").await;
+    response.assert_status_ok();
+    let html = response.text();
+    assert!(html.contains(r#"<meta name="nacre-base" content="/p/web">"#));
+    assert!(html.contains(r#"href="/p/web/board""#));
+    assert!(html.contains(r#"id="project-select""#));
+    // Shared assets stay at the root
+    assert!(html.contains(r#"src="/app.js""#));
+    server.get("/app.js").await.assert_status_ok();
+
+    server.get("/p/web/").await.assert_status_ok();
+    server.get("/p/web").await.assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_project_list_and_root_redirect() {
+    let server = projects_server();
+
+    let projects: serde_json::Value = server.get("/api/projects").await.json();
+    assert_eq!(
+        projects,
+        serde_json::json!([
+            { "name": "api", "base_path": "/p/api" },
+            { "name": "web", "base_path": "/p/web" },
+        ])
+    );
+
+    let response = server.get("/").await;
+    response.assert_status(StatusCode::TEMPORARY_REDIRECT);
+    assert_eq!(response.headers()["location"], "/overview");
+}
+
+#[tokio::test]
+async fn test_single_project_has_no_switcher() {
+    let server = crate::common::test_server().await;
+
+    let html = server.get("/tasks").await.text();
+    assert!(!html.contains(r#"id="project-select""#));
+    assert!(html.contains(r#"href="/board""#));
+}
+
+#[tokio::test]
+async fn test_overview_api_aggregates_projects() {
+    let server = overview_server();
+
+    let overview: serde_json::Value = server.get("/api/overview").await.json();
+
+    assert_eq!(overview["totals"]["open"], 1);
+    assert_eq!(overview["totals"]["in_progress"], 1);
+    assert_eq!(overview["totals"]["blocked"], 2);
+    assert_eq!(overview["closed_last_7_days"], 2);
+
+    let projects = overview["projects"].as_array().unwrap();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0]["name"], "api");
+    assert_eq!(projects[0]["url"], "/p/api/");
+    assert_eq!(projects[0]["stats"]["blocked"], 1);
+    assert_eq!(projects[0]["epics"][0]["issue"]["id"], "api-1");
+    assert_eq!(projects[0]["epics"][0]["closed"], 1);
+    assert_eq!(projects[1]["stats"]["in_progress"], 1);
+    assert!(projects[1]["epics"].as_array().unwrap().is_empty());
+
+    let blocked: Vec<(&str, &str)> = overview["blocked"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| (b["project"].as_str().unwrap(), b["url"].as_str().unwrap()))
+        .collect();
+    assert_eq!(blocked.len(), 2);
+    assert!(blocked.contains(&("api", "/p/api/tasks/api-2")));
+    assert!(blocked.contains(&("web", "/p/web/tasks/web-3")));
+}
+
+#[tokio::test]
+async fn test_overview_page_links_to_each_project() {
+    let server = overview_server();
+
+    let response = server.get("/overview").await;
+    response.assert_status_ok();
+    let html = response.text();
+    assert!(html.contains(r#"href="/p/api/""#));
+    assert!(html.contains(r#"href="/p/web/""#));
+    assert!(html.contains(r#"href="/p/web/tasks/web-3""#));
+    assert!(html.contains(r#"<option value="/overview" selected>All projects</option>"#));
+    // No per-project navigation on the overview
+    assert!(!html.contains(r#"href="/board""#));
+}
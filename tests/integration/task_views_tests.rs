@@ -1,9 +1,20 @@
 //! Task views integration tests.
 //!
-//! Tests for task-related HTML views: list, detail, edit
+//! Tests for task-related HTML views: list, detail, edit and trash
 
-use crate::common::test_server;
+use crate::common::{create_test_issue, test_server, test_server_with};
 use axum::http::StatusCode;
+use nacre::beads::{Dependency, DependencyType, IssueType, MemoryBackend, Status};
+
+fn dependency(issue_id: &str, depends_on_id: &str, dep_type: DependencyType) -> Dependency {
+    Dependency {
+        issue_id: issue_id.to_string(),
+        depends_on_id: depends_on_id.to_string(),
+        dep_type,
+        created_at: None,
+        created_by: None,
+    }
+}
 
 #[tokio::test]
 async fn test_tasks_list() {
@@ -34,3 +45,79 @@ async fn test_task_edit_not_found() {
     // This is acceptable for integration test purposes
     assert_ne!(response.status_code(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_task_detail_lists_relationships_both_ways() {
+    let mut task = create_test_issue("t-1", Status::Open, IssueType::Task);
+    task.dependencies = vec![
+        dependency("t-1", "t-2", DependencyType::Blocks),
+        dependency("t-1", "t-3", DependencyType::DiscoveredFrom),
+    ];
+    let mut dependent = create_test_issue("t-4", Status::InProgress, IssueType::Task);
+    dependent.dependencies = vec![dependency("t-4", "t-1", DependencyType::Blocks)];
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        task,
+        create_test_issue("t-2", Status::Closed, IssueType::Bug),
+        create_test_issue("t-3", Status::Open, IssueType::Task),
+        dependent,
+    ]));
+
+    let text = server.get("/tasks/t-1").await.text();
+
+    assert!(text.contains("Blocked by"));
+    assert!(text.contains("Discovered from"));
+    assert!(text.contains(r#"<li class="linked-issue" data-id="t-4">"#));
+    assert!(text.contains(r#"<span class="status-badge in-progress">In Progress</span>"#));
+    assert!(text.contains(r#"<span class="status-badge closed">Closed</span>"#));
+
+    // The blocker sees the reverse edge
+    let text = server.get("/tasks/t-2").await.text();
+    assert!(text.contains(r#"<div class="relation-label">Blocks</div>"#));
+    assert!(text.contains(r#"data-id="t-1""#));
+}
+
+#[tokio::test]
+async fn test_delete_moves_issue_to_trash_and_restore_brings_it_back() {
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![
+        create_test_issue("nc-1", Status::Open, IssueType::Task),
+        create_test_issue("nc-2", Status::Open, IssueType::Task),
+    ]));
+
+    let deleted = server.delete("/api/issues/nc-1").await;
+    assert_eq!(deleted.status_code(), StatusCode::NO_CONTENT);
+
+    assert_eq!(
+        server.get("/tasks/nc-1").await.status_code(),
+        StatusCode::NOT_FOUND
+    );
+    let trash = server.get("/trash").await.text();
+    assert!(trash.contains("Trash (1)"));
+    assert!(trash.contains(r#"<tr data-id="nc-1">"#));
+
+    let restored = server.post("/api/issues/nc-1/restore").await;
+    assert_eq!(restored.status_code(), StatusCode::OK);
+
+    assert_eq!(
+        server.get("/tasks/nc-1").await.status_code(),
+        StatusCode::OK
+    );
+    assert!(
+        server
+            .get("/trash")
+            .await
+            .text()
+            .contains("No deleted issues.")
+    );
+}
+
+#[tokio::test]
+async fn test_trash_shows_deleter_recorded_on_tombstone() {
+    let mut gone = create_test_issue("nc-1", Status::Tombstone, IssueType::Task);
+    gone.deleted_at = Some(time::macros::datetime!(2025-12-28 20:57:26 UTC));
+    gone.deleted_by = Some("batch delete".to_string());
+    let server = test_server_with(MemoryBackend::new().with_issues(vec![gone]));
+
+    let trash = server.get("/trash").await.text();
+    assert!(trash.contains("batch delete"));
+    assert!(trash.contains("2025-12-28"));
+}